@@ -0,0 +1,136 @@
+//! Criterion benchmarks for key loading, proving and verification.
+//!
+//! Everything runs on [`TestCircuit`] with keys from a fixed seed, so no fixtures
+//! are needed and numbers are comparable across runs and machines of the same kind:
+//!
+//!   cargo bench --features testing
+//!   cargo bench --features testing,parallel   # adds the batch group
+//!
+//! The circuit is tiny, so absolute times mostly measure fixed per-call overhead
+//! (deserialization checks, MSM setup, pairings). Use `bench-groth16` with a real
+//! key or `--synthetic` for end-to-end proving cost on larger circuits.
+
+use ark_bn254::{Bn254, Fr as Bn254Fr};
+use ark_groth16::{ProvingKey, VerifyingKey};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::rand::rngs::StdRng;
+use ark_std::rand::SeedableRng;
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use groth16_proofs::test_utils::{setup, to_hex_le, TestCircuit};
+use groth16_proofs::{constraint_matrices, verify, ProofGenerator, Verifier};
+
+struct Fixture {
+    pk_compressed: Vec<u8>,
+    pk_uncompressed: Vec<u8>,
+    vk: VerifyingKey<Bn254>,
+    vk_bytes: Vec<u8>,
+    generator: ProofGenerator,
+    witness: Vec<Bn254Fr>,
+}
+
+fn fixture() -> Fixture {
+    let (pk, vk) = setup(1);
+    let mut pk_compressed = Vec::new();
+    pk.serialize_compressed(&mut pk_compressed).unwrap();
+    let mut pk_uncompressed = Vec::new();
+    pk.serialize_uncompressed(&mut pk_uncompressed).unwrap();
+    let mut vk_bytes = Vec::new();
+    vk.serialize_compressed(&mut vk_bytes).unwrap();
+    let generator = ProofGenerator::from_bytes(&pk_compressed, 1)
+        .unwrap()
+        .with_constraint_matrices(constraint_matrices(TestCircuit { a: None, b: None }).unwrap())
+        .unwrap();
+    let (witness, _) = TestCircuit::random_witness(&mut StdRng::seed_from_u64(7));
+    Fixture {
+        pk_compressed,
+        pk_uncompressed,
+        vk,
+        vk_bytes,
+        generator,
+        witness,
+    }
+}
+
+fn bench_key_deserialization(c: &mut Criterion) {
+    let f = fixture();
+    let mut group = c.benchmark_group("key_deserialization");
+    group.bench_function("compressed_checked", |b| {
+        b.iter(|| ProvingKey::<Bn254>::deserialize_compressed(black_box(&f.pk_compressed[..])))
+    });
+    group.bench_function("uncompressed_checked", |b| {
+        b.iter(|| ProvingKey::<Bn254>::deserialize_uncompressed(black_box(&f.pk_uncompressed[..])))
+    });
+    group.bench_function("uncompressed_unchecked", |b| {
+        b.iter(|| {
+            ProvingKey::<Bn254>::deserialize_uncompressed_unchecked(black_box(
+                &f.pk_uncompressed[..],
+            ))
+        })
+    });
+    group.bench_function("proof_generator_from_bytes", |b| {
+        b.iter(|| ProofGenerator::from_bytes(black_box(&f.pk_compressed), 1))
+    });
+    group.finish();
+}
+
+fn bench_prove(c: &mut Criterion) {
+    let f = fixture();
+    c.bench_function("prove", |b| {
+        b.iter_batched(
+            || f.witness.clone(),
+            |witness| f.generator.prove(witness).unwrap(),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+#[cfg(feature = "parallel")]
+fn bench_prove_batch(c: &mut Criterion) {
+    const BATCH: usize = 16;
+    let f = fixture();
+    let mut rng = StdRng::seed_from_u64(7);
+    let witnesses: Vec<Vec<Bn254Fr>> = (0..BATCH)
+        .map(|_| TestCircuit::random_witness(&mut rng).0)
+        .collect();
+    let mut group = c.benchmark_group("prove_batch");
+    group.throughput(criterion::Throughput::Elements(BATCH as u64));
+    for threads in [Some(1), None] {
+        let name = threads.map_or("all_cores".to_string(), |t| format!("{t}_thread"));
+        group.bench_function(name, |b| {
+            b.iter_batched(
+                || witnesses.clone(),
+                |witnesses| f.generator.prove_batch(witnesses, threads).unwrap(),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+#[cfg(not(feature = "parallel"))]
+fn bench_prove_batch(_: &mut Criterion) {}
+
+fn bench_verify(c: &mut Criterion) {
+    let f = fixture();
+    let proof = f.generator.prove(f.witness.clone()).unwrap();
+    let signals = vec![to_hex_le(f.witness[1])];
+    let verifier = Verifier::from_bytes(&f.vk_bytes, 1).unwrap();
+
+    let mut group = c.benchmark_group("verify");
+    group.bench_function("verify", |b| {
+        b.iter(|| verify(black_box(&proof), &f.vk, &signals).unwrap())
+    });
+    group.bench_function("prepared_verifier", |b| {
+        b.iter(|| verifier.verify(black_box(&proof), &signals).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_key_deserialization,
+    bench_prove,
+    bench_prove_batch,
+    bench_verify
+);
+criterion_main!(benches);
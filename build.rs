@@ -0,0 +1,33 @@
+//! Record resolved arkworks versions for `build_info()`.
+//!
+//! Versions come from `Cargo.lock` when building from this repository; when the
+//! lockfile is unavailable (e.g. as a dependency of another workspace) they are
+//! reported as `unknown`.
+
+use std::{env, fs, path::Path};
+
+fn locked_version(lock: &str, package: &str) -> Option<String> {
+    let header = format!("name = \"{package}\"");
+    let mut lines = lock.lines();
+    while let Some(line) = lines.next() {
+        if line.trim() == header {
+            let version = lines.next()?.trim().strip_prefix("version = \"")?;
+            return version.strip_suffix('"').map(str::to_string);
+        }
+    }
+    None
+}
+
+fn main() {
+    let lock_path = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("Cargo.lock");
+    println!("cargo:rerun-if-changed={}", lock_path.display());
+    let lock = fs::read_to_string(&lock_path).unwrap_or_default();
+
+    for (package, var) in [
+        ("ark-groth16", "ARK_GROTH16_VERSION"),
+        ("ark-bn254", "ARK_BN254_VERSION"),
+    ] {
+        let version = locked_version(&lock, package).unwrap_or_else(|| "unknown".into());
+        println!("cargo:rustc-env={var}={version}");
+    }
+}
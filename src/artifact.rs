@@ -0,0 +1,214 @@
+use ark_bn254::Bn254;
+use ark_groth16::{ProvingKey, VerifyingKey};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+use crate::error::ProofError;
+use crate::prover::ProofGenerator;
+
+/// Leading bytes of a combined key artifact.
+pub const COMBINED_ARTIFACT_MAGIC: &[u8; 4] = b"ORBK";
+const COMBINED_ARTIFACT_VERSION: u8 = 1;
+
+/// Proving key, verifying key and public-input count from one combined artifact.
+///
+/// Container layout (all integers little-endian):
+///
+/// | bytes | field |
+/// |-------|-------|
+/// | 4     | magic `ORBK` |
+/// | 1     | format version (`1`) |
+/// | 4     | `num_public_inputs` (`u32`) |
+/// | 8 + n | proving key: `u64` length, then arkworks compressed bytes |
+/// | 8 + m | verifying key: `u64` length, then arkworks compressed bytes |
+///
+/// Trailing bytes are rejected.
+#[derive(Debug, Clone)]
+pub struct CombinedKeys {
+    pub proving_key: ProvingKey<Bn254>,
+    pub verifying_key: VerifyingKey<Bn254>,
+    pub num_public_inputs: usize,
+}
+
+impl CombinedKeys {
+    /// Bundle a proving key with its embedded verifying key.
+    pub fn new(proving_key: ProvingKey<Bn254>) -> Self {
+        let verifying_key = proving_key.vk.clone();
+        let num_public_inputs = verifying_key.gamma_abc_g1.len().saturating_sub(1);
+        Self {
+            proving_key,
+            verifying_key,
+            num_public_inputs,
+        }
+    }
+
+    /// Serialize to the container format read by [`parse_combined_artifact`].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = COMBINED_ARTIFACT_MAGIC.to_vec();
+        out.push(COMBINED_ARTIFACT_VERSION);
+        out.extend((self.num_public_inputs as u32).to_le_bytes());
+        for section in [serialize(&self.proving_key), serialize(&self.verifying_key)] {
+            out.extend((section.len() as u64).to_le_bytes());
+            out.extend(section);
+        }
+        out
+    }
+
+    /// Prover over the artifact's proving key.
+    pub fn into_generator(self) -> Result<ProofGenerator, ProofError> {
+        ProofGenerator::from_proving_key(self.proving_key)
+    }
+}
+
+/// Parse a combined key artifact (see [`CombinedKeys`] for the layout).
+///
+/// Fails if the header is wrong, a section does not deserialize, the verifying key
+/// differs from the one embedded in the proving key, or `num_public_inputs` does not
+/// match the key's IC points.
+pub fn parse_combined_artifact(bytes: &[u8]) -> Result<CombinedKeys, String> {
+    let mut reader = Reader(bytes);
+    if reader.take(4)? != COMBINED_ARTIFACT_MAGIC {
+        return Err("Not a combined key artifact (bad magic)".into());
+    }
+    let version = reader.take(1)?[0];
+    if version != COMBINED_ARTIFACT_VERSION {
+        return Err(format!("Unsupported combined artifact version {version}"));
+    }
+    let num_public_inputs = u32::from_le_bytes(reader.take(4)?.try_into().unwrap()) as usize;
+    let proving_key = ProvingKey::<Bn254>::deserialize_compressed(reader.section()?)
+        .map_err(|e| format!("Invalid proving key section: {e}"))?;
+    let verifying_key = VerifyingKey::<Bn254>::deserialize_compressed(reader.section()?)
+        .map_err(|e| format!("Invalid verifying key section: {e}"))?;
+    if !reader.0.is_empty() {
+        return Err(format!(
+            "{} trailing bytes after combined artifact",
+            reader.0.len()
+        ));
+    }
+
+    if verifying_key != proving_key.vk {
+        return Err("Verifying key does not match the proving key".into());
+    }
+    let ic_len = verifying_key.gamma_abc_g1.len();
+    if ic_len != num_public_inputs + 1 {
+        return Err(format!(
+            "Header declares {num_public_inputs} public inputs, key has {}",
+            ic_len.saturating_sub(1)
+        ));
+    }
+    Ok(CombinedKeys {
+        proving_key,
+        verifying_key,
+        num_public_inputs,
+    })
+}
+
+fn serialize<T: CanonicalSerialize>(value: &T) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    value
+        .serialize_compressed(&mut bytes)
+        .expect("serializing to a Vec cannot fail");
+    bytes
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], String> {
+        if self.0.len() < n {
+            return Err("Truncated combined artifact".into());
+        }
+        let (head, rest) = self.0.split_at(n);
+        self.0 = rest;
+        Ok(head)
+    }
+
+    /// A `u64`-length-prefixed section.
+    fn section(&mut self) -> Result<&'a [u8], String> {
+        let len = u64::from_le_bytes(self.take(8)?.try_into().unwrap());
+        let len = usize::try_from(len).map_err(|_| "Section length overflows usize")?;
+        self.take(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{setup, to_hex_le, TestCircuit};
+
+    #[test]
+    fn test_combined_artifact_round_trip() {
+        let (pk, vk) = setup(1);
+        let bytes = CombinedKeys::new(pk.clone()).to_bytes();
+        assert_eq!(&bytes[..4], COMBINED_ARTIFACT_MAGIC);
+
+        let keys = parse_combined_artifact(&bytes).unwrap();
+        assert_eq!(keys.proving_key, pk);
+        assert_eq!(keys.verifying_key, vk);
+        assert_eq!(keys.num_public_inputs, 1);
+        assert_eq!(keys.to_bytes(), bytes);
+    }
+
+    #[test]
+    fn test_prove_from_combined_artifact() {
+        let (pk, _) = setup(1);
+        let keys = parse_combined_artifact(&CombinedKeys::new(pk).to_bytes()).unwrap();
+        let vk = keys.verifying_key.clone();
+        let matrices = crate::constraint_matrices(TestCircuit { a: None, b: None }).unwrap();
+        let generator = keys
+            .into_generator()
+            .unwrap()
+            .with_constraint_matrices(matrices)
+            .unwrap();
+
+        let (a, b) = (ark_bn254::Fr::from(3u64), ark_bn254::Fr::from(5u64));
+        let proof = generator.prove(vec![1u64.into(), a * b, a, b]).unwrap();
+        assert!(crate::verify(&proof, &vk, &[to_hex_le(a * b)]).unwrap());
+    }
+
+    #[test]
+    fn test_rejects_malformed_artifacts() {
+        let (pk, _) = setup(1);
+        let bytes = CombinedKeys::new(pk).to_bytes();
+
+        let mut bad_magic = bytes.clone();
+        bad_magic[0] = b'X';
+        assert!(parse_combined_artifact(&bad_magic)
+            .unwrap_err()
+            .contains("magic"));
+
+        let mut bad_version = bytes.clone();
+        bad_version[4] = 2;
+        assert!(parse_combined_artifact(&bad_version)
+            .unwrap_err()
+            .contains("version"));
+
+        let mut bad_count = bytes.clone();
+        bad_count[5] = 2;
+        assert!(parse_combined_artifact(&bad_count)
+            .unwrap_err()
+            .contains("declares 2"));
+
+        assert!(parse_combined_artifact(&bytes[..bytes.len() - 1])
+            .unwrap_err()
+            .contains("Truncated"));
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(parse_combined_artifact(&trailing)
+            .unwrap_err()
+            .contains("trailing"));
+    }
+
+    #[test]
+    fn test_rejects_mismatched_verifying_key() {
+        let (pk, _) = setup(1);
+        let (_, other_vk) = setup(2);
+        let keys = CombinedKeys {
+            verifying_key: other_vk,
+            ..CombinedKeys::new(pk)
+        };
+        assert!(parse_combined_artifact(&keys.to_bytes())
+            .unwrap_err()
+            .contains("does not match"));
+    }
+}
@@ -5,6 +5,11 @@
 //!
 //! Usage:
 //!   bench-groth16 <circuit_name> <witness.wtns> <proving_key.ark> [iterations=5]
+//!   bench-groth16 --synthetic [constraints=16384] [iterations=5]
+//!
+//! `--synthetic` builds a squaring-chain circuit in-process and compares proving
+//! with per-proof constraint synthesis against a `ProofGenerator` with cached
+//! constraint matrices.
 //!
 //! Output (JSON to stdout, progress to stderr):
 //!   {
@@ -20,11 +25,13 @@
 use ark_bn254::{Bn254, Fr as Bn254Fr};
 use ark_ff::PrimeField;
 use ark_groth16::{Groth16, ProvingKey};
+use ark_relations::lc;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
 use ark_serialize::CanonicalDeserialize;
 use ark_snark::SNARK;
 use ark_std::rand::rngs::StdRng;
 use ark_std::rand::SeedableRng;
-use groth16_proofs::WitnessCircuit;
+use groth16_proofs::{constraint_matrices, ProofGenerator, WitnessCircuit};
 use std::time::Instant;
 
 // ── .wtns binary reader ──────────────────────────────────────────────────────
@@ -83,10 +90,118 @@ fn load_witness(path: &str) -> Vec<Bn254Fr> {
     witness
 }
 
+// ── synthetic circuit ────────────────────────────────────────────────────────
+
+/// Squaring chain: `x[i+1] = x[i]^2` for `n - 1` steps, then `x[n-1]^2 = y` (public).
+/// Witness layout: `[1, y, x[0], …, x[n-1]]`.
+#[derive(Clone)]
+struct SquaringChain {
+    x0: Option<Bn254Fr>,
+    n: usize,
+}
+
+impl SquaringChain {
+    fn witness(&self) -> Vec<Bn254Fr> {
+        let mut xs = vec![self.x0.unwrap()];
+        for _ in 1..self.n {
+            let last = *xs.last().unwrap();
+            xs.push(last * last);
+        }
+        let last = *xs.last().unwrap();
+        let mut witness = vec![Bn254Fr::from(1u64), last * last];
+        witness.extend(xs);
+        witness
+    }
+}
+
+impl ConstraintSynthesizer<Bn254Fr> for SquaringChain {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Bn254Fr>) -> Result<(), SynthesisError> {
+        let values = self.x0.map(|_| self.witness());
+        let value = |i: usize| {
+            values
+                .as_ref()
+                .map(|w| w[i])
+                .ok_or(SynthesisError::AssignmentMissing)
+        };
+        let y = cs.new_input_variable(|| value(1))?;
+        let mut prev = cs.new_witness_variable(|| value(2))?;
+        for i in 1..self.n {
+            let next = cs.new_witness_variable(|| value(i + 2))?;
+            cs.enforce_constraint(lc!() + prev, lc!() + prev, lc!() + next)?;
+            prev = next;
+        }
+        cs.enforce_constraint(lc!() + prev, lc!() + prev, lc!() + y)?;
+        Ok(())
+    }
+}
+
+fn bench_synthetic(constraints: usize, iterations: u32) {
+    let mut rng = StdRng::from_entropy();
+    let template = SquaringChain {
+        x0: None,
+        n: constraints,
+    };
+
+    eprintln!("Setting up {constraints}-constraint squaring chain...");
+    let (pk, _) = Groth16::<Bn254>::circuit_specific_setup(template.clone(), &mut rng)
+        .unwrap_or_else(|e| panic!("Setup failed: {e}"));
+    let mut pk_bytes = Vec::new();
+    ark_serialize::CanonicalSerialize::serialize_compressed(&pk, &mut pk_bytes).unwrap();
+
+    let circuit = SquaringChain {
+        x0: Some(Bn254Fr::from(3u64)),
+        n: constraints,
+    };
+    let witness = circuit.witness();
+    let generator = ProofGenerator::from_bytes(&pk_bytes, 1)
+        .and_then(|g| g.with_constraint_matrices(constraint_matrices(template)?))
+        .unwrap_or_else(|e| panic!("Failed to build ProofGenerator: {e}"));
+
+    let mut synth_ms = Vec::with_capacity(iterations as usize);
+    let mut cached_ms = Vec::with_capacity(iterations as usize);
+    for i in 0..iterations {
+        let t0 = Instant::now();
+        Groth16::<Bn254>::prove(&pk, circuit.clone(), &mut rng)
+            .unwrap_or_else(|e| panic!("Proof generation failed: {e}"));
+        synth_ms.push(t0.elapsed().as_secs_f64() * 1000.0);
+
+        let t0 = Instant::now();
+        generator
+            .prove(witness.clone())
+            .unwrap_or_else(|e| panic!("Cached proof generation failed: {e}"));
+        cached_ms.push(t0.elapsed().as_secs_f64() * 1000.0);
+        eprintln!(
+            "  iter {}/{}: synthesize {:.1}ms, cached matrices {:.1}ms",
+            i + 1,
+            iterations,
+            synth_ms[i as usize],
+            cached_ms[i as usize]
+        );
+    }
+
+    let avg = |v: &[f64]| v.iter().sum::<f64>() / v.len() as f64;
+    println!(
+        concat!(
+            r#"{{"circuit":"synthetic","constraints":{n},"#,
+            r#""synthesize_ms_avg":{s:.1},"cached_matrices_ms_avg":{c:.1},"iterations":{it}}}"#
+        ),
+        n = constraints,
+        s = avg(&synth_ms),
+        c = avg(&cached_ms),
+        it = iterations,
+    );
+}
+
 // ── main ─────────────────────────────────────────────────────────────────────
 
 fn main() {
     let args: Vec<String> = std::env::args().collect();
+    if args.get(1).map(String::as_str) == Some("--synthetic") {
+        let constraints: usize = args.get(2).and_then(|s| s.parse().ok()).unwrap_or(1 << 14);
+        let iterations: u32 = args.get(3).and_then(|s| s.parse().ok()).unwrap_or(5);
+        bench_synthetic(constraints, iterations);
+        return;
+    }
     if args.len() < 4 {
         eprintln!(
             "Usage: bench-groth16 <circuit_name> <witness.wtns> <proving_key.ark> [iterations=5] [num_public=5]"
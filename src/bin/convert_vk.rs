@@ -6,43 +6,10 @@
 //! If output is omitted, replaces .json with .bin.
 //! Outputs the byte count to stderr.
 
-use ark_bn254::{Bn254, Fq, Fq2, G1Affine, G2Affine};
-use ark_ff::PrimeField;
-use ark_groth16::VerifyingKey;
 use ark_serialize::CanonicalSerialize;
-use num_bigint::BigUint;
-use serde_json::Value;
+use groth16_proofs::vk_from_snarkjs_json;
 use std::{env, fs, process};
 
-fn parse_fq(s: &str) -> Fq {
-    let n =
-        BigUint::parse_bytes(s.as_bytes(), 10).unwrap_or_else(|| panic!("invalid Fq element: {s}"));
-    Fq::from_le_bytes_mod_order(&n.to_bytes_le())
-}
-
-/// Parse a G1 affine point from snarkjs projective form [x, y, z].
-/// For standard VK points z == "1" so affine == projective coordinates.
-fn parse_g1(v: &Value) -> G1Affine {
-    let x = parse_fq(v[0].as_str().expect("G1 x must be string"));
-    let y = parse_fq(v[1].as_str().expect("G1 y must be string"));
-    G1Affine::new(x, y)
-}
-
-/// Parse an Fq2 element from snarkjs array [c0, c1].
-fn parse_fq2(v: &Value) -> Fq2 {
-    let c0 = parse_fq(v[0].as_str().expect("Fq2 c0 must be string"));
-    let c1 = parse_fq(v[1].as_str().expect("Fq2 c1 must be string"));
-    Fq2::new(c0, c1)
-}
-
-/// Parse a G2 affine point from snarkjs projective form [[x.c0,x.c1],[y.c0,y.c1],[z.c0,z.c1]].
-/// For standard VK points z == [1,0] so affine == projective coordinates.
-fn parse_g2(v: &Value) -> G2Affine {
-    let x = parse_fq2(&v[0]);
-    let y = parse_fq2(&v[1]);
-    G2Affine::new(x, y)
-}
-
 fn main() {
     let args: Vec<String> = env::args().collect();
     if args.len() < 2 {
@@ -59,35 +26,24 @@ fn main() {
         format!("{in_path}.bin")
     };
 
-    let json_str =
-        fs::read_to_string(in_path).unwrap_or_else(|e| panic!("cannot read {in_path}: {e}"));
-
-    let json: Value = serde_json::from_str(&json_str)
-        .unwrap_or_else(|e| panic!("invalid JSON in {in_path}: {e}"));
-
-    let alpha_g1 = parse_g1(&json["vk_alpha_1"]);
-    let beta_g2 = parse_g2(&json["vk_beta_2"]);
-    let gamma_g2 = parse_g2(&json["vk_gamma_2"]);
-    let delta_g2 = parse_g2(&json["vk_delta_2"]);
-
-    let ic_arr = json["IC"]
-        .as_array()
-        .unwrap_or_else(|| panic!("missing IC field in {in_path}"));
-    let gamma_abc_g1: Vec<G1Affine> = ic_arr.iter().map(parse_g1).collect();
+    let json_str = fs::read_to_string(in_path).unwrap_or_else(|e| {
+        eprintln!("❌ Cannot read {in_path}: {e}");
+        process::exit(1);
+    });
 
-    let vk = VerifyingKey::<Bn254> {
-        alpha_g1,
-        beta_g2,
-        gamma_g2,
-        delta_g2,
-        gamma_abc_g1,
-    };
+    let vk = vk_from_snarkjs_json(&json_str).unwrap_or_else(|e| {
+        eprintln!("❌ Invalid snarkjs VK in {in_path}: {e}");
+        process::exit(1);
+    });
 
     let mut bytes = Vec::new();
     vk.serialize_compressed(&mut bytes)
-        .unwrap_or_else(|e| panic!("serialization failed: {e}"));
+        .expect("serializing to a Vec cannot fail");
 
-    fs::write(&out_path, &bytes).unwrap_or_else(|e| panic!("cannot write {out_path}: {e}"));
+    fs::write(&out_path, &bytes).unwrap_or_else(|e| {
+        eprintln!("❌ Cannot write {out_path}: {e}");
+        process::exit(1);
+    });
 
     eprintln!(
         "Converted {} → {} ({} bytes JSON → {} bytes binary)",
@@ -101,11 +57,12 @@ fn main() {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ark_bn254::{Bn254, G1Affine, G1Projective, G2Affine, G2Projective};
+    use ark_bn254::{Bn254, Fq, G1Affine, G1Projective, G2Affine, G2Projective};
     use ark_ec::{CurveGroup, PrimeGroup};
-    use ark_ff::BigInteger;
+    use ark_ff::{BigInteger, PrimeField};
     use ark_groth16::VerifyingKey;
-    use ark_serialize::CanonicalSerialize;
+    use num_bigint::BigUint;
+    use serde_json::Value;
 
     // ── helpers ──────────────────────────────────────────────────────────────
 
@@ -141,60 +98,32 @@ mod tests {
     }
 
     fn vk_from_json(json: &Value) -> VerifyingKey<Bn254> {
-        let ic_arr = json["IC"].as_array().unwrap();
-        VerifyingKey::<Bn254> {
-            alpha_g1: parse_g1(&json["vk_alpha_1"]),
-            beta_g2: parse_g2(&json["vk_beta_2"]),
-            gamma_g2: parse_g2(&json["vk_gamma_2"]),
-            delta_g2: parse_g2(&json["vk_delta_2"]),
-            gamma_abc_g1: ic_arr.iter().map(parse_g1).collect(),
-        }
-    }
-
-    // ── parse_fq ─────────────────────────────────────────────────────────────
-
-    #[test]
-    fn test_parse_fq_zero() {
-        assert_eq!(parse_fq("0"), Fq::from(0u64));
+        vk_from_snarkjs_json(&json.to_string()).unwrap()
     }
 
-    #[test]
-    fn test_parse_fq_one() {
-        assert_eq!(parse_fq("1"), Fq::from(1u64));
-    }
-
-    #[test]
-    fn test_parse_fq_large_value() {
-        // 2^64 — larger than u64, must survive mod-order reduction
-        let big = "18446744073709551616";
-        let result = std::panic::catch_unwind(|| parse_fq(big));
-        assert!(result.is_ok());
-    }
-
-    #[test]
-    fn test_parse_fq_invalid_panics() {
-        let result = std::panic::catch_unwind(|| parse_fq("not-a-number"));
-        assert!(result.is_err());
-    }
-
-    // ── parse_g1 ─────────────────────────────────────────────────────────────
+    // ── point parsing ────────────────────────────────────────────────────────
 
     #[test]
     fn test_parse_g1_roundtrip_generator() {
         let original: G1Affine = G1Projective::generator().into_affine();
-        let json = g1_gen_json();
-        let parsed = parse_g1(&json);
-        assert_eq!(parsed, original);
+        let vk = vk_from_json(&build_vk_json(1));
+        assert_eq!(vk.alpha_g1, original);
+        assert_eq!(vk.gamma_abc_g1, vec![original]);
     }
 
-    // ── parse_g2 ─────────────────────────────────────────────────────────────
-
     #[test]
     fn test_parse_g2_roundtrip_generator() {
         let original: G2Affine = G2Projective::generator().into_affine();
-        let json = g2_gen_json();
-        let parsed = parse_g2(&json);
-        assert_eq!(parsed, original);
+        let vk = vk_from_json(&build_vk_json(1));
+        assert_eq!(vk.beta_g2, original);
+        assert_eq!(vk.delta_g2, original);
+    }
+
+    #[test]
+    fn test_invalid_coordinate_is_an_error() {
+        let mut json = build_vk_json(1);
+        json["vk_alpha_1"][0] = "not-a-number".into();
+        assert!(vk_from_snarkjs_json(&json.to_string()).is_err());
     }
 
     // ── serialized VK size ────────────────────────────────────────────────────
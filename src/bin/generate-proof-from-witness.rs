@@ -1,42 +1,1433 @@
 //! Binary for generating Groth16 proofs from witness
 //!
-//! Usage: generate-proof-from-witness <witness.json> <proving_key.ark> [num_public_signals]
+//! Usage: generate-proof-from-witness [flags] <witness.json> <proving_key.ark> [num_public_signals]
+//!        generate-proof-from-witness --signals-only <witness.json> [num_public_signals]
+//!        generate-proof-from-witness verify <proof.json> <vk.bin> [--expect public.json]
+//!        generate-proof-from-witness verify --vk <vk.bin> <proof.json|->
+//!            Verify this CLI's output JSON (`-` reads it from stdin). Exit codes:
+//!            0 valid, 1 usage/input error, 2 invalid proof, 3 valid proof whose
+//!            signals differ from `--expect`
+//!        generate-proof-from-witness verify --request
+//!            Read `{"proof", "public_signals", "vk": "<vk.bin>"}` from stdin and
+//!            print `{"valid": bool}` (plus `"error"` for unusable requests) to
+//!            stdout, for running verification in a sandboxed child process.
+//!            Same exit codes as `verify`
+//!        generate-proof-from-witness diff-witness <a.json> <b.json>
+//!            Print the differing indices of two witness files as JSON. Exit codes:
+//!            0 identical, 1 usage/input error, 2 witnesses differ
+//!        generate-proof-from-witness batch <jobs.jsonl> --key <circuit>=<pk.ark>...
+//!                [--config <orbinum.toml>] [--split-by-circuit <out-dir>] [--compress zstd|gzip]
+//!            Prove one `{"circuit": "...", "witness": [...]}` job per line and emit one
+//!            `{"circuit", "proof", "public_signals"}` line per job, to stdout or to
+//!            `<out-dir>/<circuit>.jsonl` (`.jsonl.zst` / `.jsonl.gz` when compressed)
+//!        generate-proof-from-witness prove-dir <witness-dir> <pk.ark> <out-dir>
+//!            Prove every `.json` witness in the directory with one loaded key, write
+//!            each proof to the same file name in `<out-dir>`, and print
+//!            `{"proved": [...], "failed": [{"file", "error"}]}`. Exit codes: 0 all
+//!            proved, 1 usage/input error, 2 some witnesses failed
+//!        generate-proof-from-witness export-bundle <out-dir> --proof <proof.json|->
+//!                [--vk <vk.bin>]
+//!            Write this CLI's output as snarkjs `proof.json` and `public.json`, plus
+//!            `verification_key.json` with `--vk`, for any snarkjs verifier
+//!        generate-proof-from-witness info       Print crate/arkworks versions and curve as JSON
+//!        generate-proof-from-witness --version
+//!        generate-proof-from-witness --json-schema   Print the output JSON Schema
+//!            (requires the `json-schema` feature)
+//!
+//! Flags:
+//!   --canonical  Emit canonical JSON (sorted keys, no whitespace) for byte-stable hashing
+//!   --signals-dual  Emit each public signal as {"hex": "0x…", "dec": "…"}
+//!   --zstd       Decompress the witness file with zstd (implied by a `.zst` extension;
+//!                requires the `zstd` feature)
+//!   --signals-only  Skip proving and emit only {"public_signals": [...]}; no key needed
+//!   --witness-endian le|be
+//!                Byte order of hex witness entries (default: le)
+//!   --strict     Reject public signals that are not canonical field elements (>= modulus)
+//!                and witness entries longer than 64 hex digits
+//!   --compress zstd|gzip
+//!                Compress the whole output stream (requires the `zstd` / `gzip` feature)
+//!   --quiet      Suppress progress messages on stderr (errors are still reported)
+//!   --log-format plain|json
+//!                Progress and errors without emoji, or one JSON object per line
+//!                (default: emoji). This and --quiet are accepted by every subcommand
+//!   --public-outputs N
+//!                Number of circom public outputs preceding the public inputs (default: 0)
+//!   --constant-offset N
+//!                Witness index of the first public signal: 1 when index 0 is the
+//!                constant wire (circom, arkworks), 0 when it is absent and the
+//!                constant 1 is prepended before proving (default: 1)
+//!   --key-relative
+//!                Resolve a relative proving key path against the witness file's
+//!                directory instead of the working directory
+//!   --config <orbinum.toml>
+//!                Load key paths, default formats and limits (requires the `config`
+//!                feature); flags given on the command line override it
+//!   --circuit <name>
+//!                Prove with the key configured for this circuit; the proving key
+//!                argument is then omitted: [flags] <witness.json> [num_public_signals]
 //!
 //! Input format (JSON):
 //! {
-//!   "witness": ["0x01...", "0x02...", ...],
-//!   "num_public_signals": 5  // Optional: if not in JSON, use CLI arg
+//!   "witness": ["0x01...", "0x02...", ...],  // or [u64; 4] LE limb arrays, e.g. [123, 456, 0, 0]
+//!   "num_public_signals": 5,  // Optional: if not in JSON, use CLI arg
+//!   "public_outputs_count": 1  // Optional: circom public outputs, default 0
 //! }
 //!
+//! circom lays the witness out as `[1, outputs..., public inputs..., private...]`
+//! (`[1, public inputs..., private...]` without outputs), and the public signals are
+//! the outputs followed by the public inputs.
+//!
 //! Output format (JSON):
 //! {
 //!   "proof": "0xabcd...",
 //!   "public_signals": ["0x01...", "0x02...", ...]
 //! }
 
-use groth16_proofs::generate_proof_from_witness;
+use groth16_proofs::{
+    build_info, decimal_to_field, decode_hex, generate_proof_from_witness, hex_to_field,
+    hex_to_field_with_flag, proof_to_snarkjs_json, strip_hex_prefix, to_decimal_str,
+    vk_to_snarkjs_json, witness_diff, write_public_json, CircuitType, ProofError, ProofGenerator,
+    Verifier, WitnessEntry,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
 use std::env;
+use std::io::{BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
-#[derive(Debug, Deserialize)]
+#[derive(Deserialize)]
 struct WitnessInput {
-    witness: Vec<String>,
+    witness: Vec<WitnessEntry>,
     #[serde(default)]
     num_public_signals: Option<usize>,
+    #[serde(default)]
+    public_outputs_count: Option<usize>,
+}
+
+/// Only the witness length is shown; the values are secret.
+impl std::fmt::Debug for WitnessInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WitnessInput")
+            .field("witness_len", &self.witness.len())
+            .field("num_public_signals", &self.num_public_signals)
+            .field("public_outputs_count", &self.public_outputs_count)
+            .field("witness", &format_args!("<redacted>"))
+            .finish()
+    }
 }
 
 #[derive(Debug, Serialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 struct ProofOutput {
+    /// Absent with `--signals-only`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proof: Option<String>,
+    public_signals: PublicSignals,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+enum PublicSignals {
+    Hex(Vec<String>),
+    Dual(Vec<DualSignal>),
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+struct DualSignal {
+    hex: String,
+    dec: String,
+}
+
+/// `diff-witness` exit code: the witnesses differ.
+const EXIT_WITNESS_DIFF: i32 = 2;
+
+/// `verify` exit code: the proof does not verify.
+const EXIT_INVALID_PROOF: i32 = 2;
+/// `verify` exit code: the proof verifies, but not for the `--expect`ed signals.
+const EXIT_SIGNAL_MISMATCH: i32 = 3;
+
+#[derive(Debug, Deserialize)]
+struct VerifyInput {
+    proof: String,
+    public_signals: Vec<String>,
+}
+
+/// `verify --request` stdin body: a [`VerifyInput`] plus the VK path.
+#[derive(Debug, Deserialize)]
+struct VerifyRequest {
+    #[serde(flatten)]
+    input: VerifyInput,
+    vk: String,
+}
+
+/// `verify --request` stdout line. `error` is set for unusable requests, which
+/// are reported with `valid: false`.
+#[derive(Debug, Serialize)]
+struct VerifyResponse {
+    valid: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum VerifyOutcome {
+    Valid,
+    InvalidProof,
+    SignalMismatch(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum WitnessEndian {
+    #[default]
+    Le,
+    Be,
+}
+
+impl std::str::FromStr for WitnessEndian {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "le" => Ok(WitnessEndian::Le),
+            "be" => Ok(WitnessEndian::Be),
+            other => Err(format!(
+                "Unknown witness endianness: {other} (expected le or be)"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputCompression {
+    Zstd,
+    Gzip,
+}
+
+impl std::str::FromStr for OutputCompression {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "zstd" => Ok(OutputCompression::Zstd),
+            "gzip" => Ok(OutputCompression::Gzip),
+            other => Err(format!(
+                "Unknown compression: {other} (expected zstd or gzip)"
+            )),
+        }
+    }
+}
+
+impl OutputCompression {
+    /// Fail up front, before any proving, when the binary lacks the codec.
+    fn check_available(self) -> Result<(), String> {
+        match self {
+            OutputCompression::Zstd if !cfg!(feature = "zstd") => {
+                Err("zstd output requires building with `--features zstd`".into())
+            }
+            OutputCompression::Gzip if !cfg!(feature = "gzip") => {
+                Err("gzip output requires building with `--features gzip`".into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// File extension appended to compressed output files.
+    fn extension(self) -> &'static str {
+        match self {
+            OutputCompression::Zstd => "zst",
+            OutputCompression::Gzip => "gz",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum LogFormat {
+    #[default]
+    Emoji,
+    Plain,
+    Json,
+}
+
+impl std::str::FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "emoji" => Ok(LogFormat::Emoji),
+            "plain" => Ok(LogFormat::Plain),
+            "json" => Ok(LogFormat::Json),
+            other => Err(format!(
+                "Unknown log format: {other} (expected plain, json or emoji)"
+            )),
+        }
+    }
+}
+
+/// Progress reporting on stderr; stdout carries only the result.
+#[derive(Debug, Clone, Copy, Default)]
+struct Progress {
+    quiet: bool,
+    format: LogFormat,
+}
+
+impl Progress {
+    fn info(&self, event: &str, emoji: &str, message: &str) {
+        self.emit("info", event, emoji, message);
+    }
+
+    fn warn(&self, event: &str, message: &str) {
+        self.emit("warn", event, "⚠️ ", message);
+    }
+
+    /// Errors are reported even when quiet.
+    fn error(&self, event: &str, message: &str) {
+        self.emit("error", event, "❌", message);
+    }
+
+    /// Report `message` as an error and exit with status 1.
+    fn fail(&self, event: &str, message: &str) -> ! {
+        self.error(event, message);
+        std::process::exit(1)
+    }
+
+    fn emit(&self, level: &str, event: &str, emoji: &str, message: &str) {
+        if let Some(line) = self.line(level, event, emoji, message) {
+            eprintln!("{line}");
+        }
+    }
+
+    /// The stderr line for a progress event, or `None` when quiet and not an error.
+    fn line(&self, level: &str, event: &str, emoji: &str, message: &str) -> Option<String> {
+        if self.quiet && level != "error" {
+            return None;
+        }
+        Some(match self.format {
+            LogFormat::Emoji => format!("{emoji} {message}"),
+            LogFormat::Plain => format!("{level}: {message}"),
+            LogFormat::Json => serde_json::json!({
+                "level": level,
+                "event": event,
+                "message": message,
+            })
+            .to_string(),
+        })
+    }
+}
+
+/// Take `--quiet` and `--log-format` out of subcommand `args`, returning the
+/// resulting [`Progress`] and the remaining arguments.
+fn take_progress_flags(args: &[String]) -> Result<(Progress, Vec<String>), String> {
+    let mut progress = Progress::default();
+    let mut rest = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--quiet" => progress.quiet = true,
+            "--log-format" => {
+                let format = args.next().ok_or("--log-format requires plain or json")?;
+                progress.format = format.parse()?;
+            }
+            _ => rest.push(arg.clone()),
+        }
+    }
+    Ok((progress, rest))
+}
+
+#[derive(Debug, Default)]
+struct CliArgs {
+    witness_path: String,
+    /// `None` with `--signals-only`.
+    proving_key_path: Option<String>,
+    num_public_signals: Option<usize>,
+    public_outputs: Option<usize>,
+    /// Number of leading witness entries before the public signals; `None` means 1.
+    constant_offset: Option<usize>,
+    canonical: bool,
+    signals_dual: bool,
+    zstd: bool,
+    compress: Option<OutputCompression>,
+    strict: bool,
+    signals_only: bool,
+    key_relative: bool,
+    witness_endian: WitnessEndian,
+    progress: Progress,
+    circuit: Option<CircuitType>,
+    /// Proving keys from `--config`, used with `--circuit`.
+    config_keys: HashMap<CircuitType, String>,
+    max_witness_bytes: Option<u64>,
+}
+
+/// The parts of an `orbinum.toml` the CLI uses, parsed into its own option types.
+#[derive(Debug, Default)]
+struct FileConfig {
+    keys: HashMap<CircuitType, String>,
+    witness_endian: Option<WitnessEndian>,
+    compress: Option<OutputCompression>,
+    log_format: Option<LogFormat>,
+    strict: bool,
+    max_witness_bytes: Option<u64>,
+}
+
+#[cfg(feature = "config")]
+fn load_config(path: &str) -> Result<FileConfig, String> {
+    let config = groth16_proofs::Config::from_file(path)?;
+    let keys = config
+        .keys
+        .iter()
+        .map(|(circuit, path)| Ok((circuit.parse()?, path.display().to_string())))
+        .collect::<Result<_, String>>()?;
+    let defaults = &config.defaults;
+    Ok(FileConfig {
+        keys,
+        witness_endian: defaults
+            .witness_endian
+            .as_deref()
+            .map(str::parse)
+            .transpose()?,
+        compress: defaults.compress.as_deref().map(str::parse).transpose()?,
+        log_format: defaults.log_format.as_deref().map(str::parse).transpose()?,
+        strict: defaults.strict,
+        max_witness_bytes: config.limits.max_witness_bytes,
+    })
+}
+
+#[cfg(not(feature = "config"))]
+fn load_config(_path: &str) -> Result<FileConfig, String> {
+    Err("--config requires building with `--features config`".into())
+}
+
+/// Value of `--config` in `args`, if given. The config is loaded before any other
+/// flag is applied, so flags override it regardless of their position.
+fn config_flag(args: &[String]) -> Result<Option<&str>, String> {
+    match args.iter().position(|a| a == "--config") {
+        Some(i) => args
+            .get(i + 1)
+            .map(|path| Some(path.as_str()))
+            .ok_or_else(|| "--config requires a path".to_string()),
+        None => Ok(None),
+    }
+}
+
+/// Split `args` (without the program name) into flags and positional arguments.
+fn parse_args(args: &[String]) -> Result<CliArgs, String> {
+    let mut cli = CliArgs::default();
+    if let Some(path) = config_flag(args)? {
+        let config = load_config(path)?;
+        cli.config_keys = config.keys;
+        cli.witness_endian = config.witness_endian.unwrap_or_default();
+        cli.compress = config.compress;
+        cli.progress.format = config.log_format.unwrap_or_default();
+        cli.strict = config.strict;
+        cli.max_witness_bytes = config.max_witness_bytes;
+    }
+    let mut positional = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--config" => {
+                args.next();
+            }
+            "--circuit" => {
+                let name = args.next().ok_or("--circuit requires a circuit name")?;
+                cli.circuit = Some(name.parse()?);
+            }
+            "--canonical" => cli.canonical = true,
+            "--signals-dual" => cli.signals_dual = true,
+            "--zstd" => cli.zstd = true,
+            "--strict" => cli.strict = true,
+            "--signals-only" => cli.signals_only = true,
+            "--key-relative" => cli.key_relative = true,
+            "--quiet" => cli.progress.quiet = true,
+            "--log-format" => {
+                let format = args.next().ok_or("--log-format requires plain or json")?;
+                cli.progress.format = format.parse()?;
+            }
+            "--witness-endian" => {
+                let endian = args.next().ok_or("--witness-endian requires le or be")?;
+                cli.witness_endian = endian.parse()?;
+            }
+            "--compress" => {
+                let kind = args.next().ok_or("--compress requires zstd or gzip")?;
+                cli.compress = Some(kind.parse()?);
+            }
+            "--public-outputs" => {
+                let count = args.next().ok_or("--public-outputs requires a count")?;
+                cli.public_outputs = Some(
+                    count
+                        .parse()
+                        .map_err(|_| format!("Invalid --public-outputs count: {count}"))?,
+                );
+            }
+            "--constant-offset" => {
+                let offset = args.next().ok_or("--constant-offset requires 0 or 1")?;
+                cli.constant_offset = Some(match offset.as_str() {
+                    "0" => 0,
+                    "1" => 1,
+                    _ => {
+                        return Err(format!(
+                            "Invalid --constant-offset {offset}: expected 0 or 1"
+                        ))
+                    }
+                });
+            }
+            flag if flag.starts_with("--") => return Err(format!("Unknown flag: {flag}")),
+            _ => positional.push(arg.clone()),
+        }
+    }
+    if let Some(kind) = cli.compress {
+        kind.check_available()?;
+    }
+    if cli.signals_only {
+        if positional.is_empty() || positional.len() > 2 {
+            return Err("Expected --signals-only <witness.json> [num_public_signals]".into());
+        }
+        cli.witness_path = positional[0].clone();
+        cli.num_public_signals = positional.get(1).and_then(|s| s.parse().ok());
+        return Ok(cli);
+    }
+    if let Some(circuit) = cli.circuit {
+        if positional.is_empty() || positional.len() > 2 {
+            return Err("Expected --circuit <name> <witness.json> [num_public_signals]".into());
+        }
+        let key_path = cli
+            .config_keys
+            .get(&circuit)
+            .ok_or_else(|| format!("No proving key configured for circuit {}", circuit.as_str()))?;
+        cli.proving_key_path = Some(key_path.clone());
+        cli.witness_path = positional[0].clone();
+        cli.num_public_signals = positional.get(1).and_then(|s| s.parse().ok());
+        return Ok(cli);
+    }
+    if positional.len() < 2 || positional.len() > 3 {
+        return Err("Expected <witness.json> <proving_key.ark> [num_public_signals]".into());
+    }
+    cli.witness_path = positional[0].clone();
+    cli.proving_key_path = Some(if cli.key_relative {
+        key_relative_to_witness(&positional[0], &positional[1])
+    } else {
+        positional[1].clone()
+    });
+    cli.num_public_signals = positional.get(2).and_then(|s| s.parse().ok());
+    Ok(cli)
+}
+
+/// `key_path` joined onto the directory holding `witness_path`, for per-circuit
+/// folders that keep the key next to its witnesses. Absolute key paths are kept.
+fn key_relative_to_witness(witness_path: &str, key_path: &str) -> String {
+    if Path::new(key_path).is_absolute() {
+        return key_path.to_string();
+    }
+    Path::new(witness_path)
+        .parent()
+        .unwrap_or(Path::new(""))
+        .join(key_path)
+        .display()
+        .to_string()
+}
+
+/// Enforce the config's `max_witness_bytes` on the file as stored (before any
+/// decompression).
+fn check_witness_size(path: &str, limit: u64) -> Result<(), String> {
+    let size = std::fs::metadata(path)
+        .map_err(|e| format!("Failed to read witness file: {e}"))?
+        .len();
+    if size > limit {
+        return Err(format!(
+            "Witness file is {size} bytes, over the configured limit of {limit}"
+        ));
+    }
+    Ok(())
+}
+
+/// Read the witness file, decompressing it when `zstd` is set or the path ends in `.zst`.
+fn read_witness_file(path: &str, zstd: bool) -> Result<String, String> {
+    if zstd || path.ends_with(".zst") {
+        return read_zstd_file(path);
+    }
+    std::fs::read_to_string(path).map_err(|e| e.to_string())
+}
+
+#[cfg(feature = "zstd")]
+fn read_zstd_file(path: &str) -> Result<String, String> {
+    let file = std::fs::File::open(path).map_err(|e| e.to_string())?;
+    let mut decoder = zstd::stream::read::Decoder::new(file).map_err(|e| e.to_string())?;
+    let mut json = String::new();
+    decoder
+        .read_to_string(&mut json)
+        .map_err(|e| format!("zstd decompression failed: {e}"))?;
+    Ok(json)
+}
+
+#[cfg(not(feature = "zstd"))]
+fn read_zstd_file(_path: &str) -> Result<String, String> {
+    Err("zstd witness support requires building with `--features zstd`".into())
+}
+
+/// Write `data` to `out`, compressing the stream as a whole when requested.
+fn write_output<W: Write>(
+    out: W,
+    data: &[u8],
+    compress: Option<OutputCompression>,
+) -> Result<(), String> {
+    let mut writer = OutputWriter::new(out, compress)?;
+    writer.write_all(data).map_err(|e| e.to_string())?;
+    writer.finish().map(drop)
+}
+
+/// An output stream, compressed as a whole when requested. Call
+/// [`finish`](Self::finish) to write the compression trailer.
+enum OutputWriter<W: Write> {
+    Plain(W),
+    #[cfg(feature = "zstd")]
+    Zstd(zstd::stream::write::Encoder<'static, W>),
+    #[cfg(feature = "gzip")]
+    Gzip(flate2::write::GzEncoder<W>),
+}
+
+impl<W: Write> OutputWriter<W> {
+    fn new(out: W, compress: Option<OutputCompression>) -> Result<Self, String> {
+        let Some(kind) = compress else {
+            return Ok(OutputWriter::Plain(out));
+        };
+        kind.check_available()?;
+        match kind {
+            #[cfg(feature = "zstd")]
+            OutputCompression::Zstd => zstd::stream::write::Encoder::new(out, 0)
+                .map(OutputWriter::Zstd)
+                .map_err(|e| e.to_string()),
+            #[cfg(feature = "gzip")]
+            OutputCompression::Gzip => Ok(OutputWriter::Gzip(flate2::write::GzEncoder::new(
+                out,
+                flate2::Compression::default(),
+            ))),
+            #[allow(unreachable_patterns)]
+            _ => unreachable!("checked by check_available"),
+        }
+    }
+
+    fn finish(self) -> Result<W, String> {
+        match self {
+            OutputWriter::Plain(mut out) => out.flush().map(|()| out).map_err(|e| e.to_string()),
+            #[cfg(feature = "zstd")]
+            OutputWriter::Zstd(encoder) => encoder
+                .finish()
+                .map_err(|e| format!("zstd compression failed: {e}")),
+            #[cfg(feature = "gzip")]
+            OutputWriter::Gzip(encoder) => encoder
+                .finish()
+                .map_err(|e| format!("gzip compression failed: {e}")),
+        }
+    }
+}
+
+impl<W: Write> Write for OutputWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            OutputWriter::Plain(out) => out.write(buf),
+            #[cfg(feature = "zstd")]
+            OutputWriter::Zstd(encoder) => encoder.write(buf),
+            #[cfg(feature = "gzip")]
+            OutputWriter::Gzip(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            OutputWriter::Plain(out) => out.flush(),
+            #[cfg(feature = "zstd")]
+            OutputWriter::Zstd(encoder) => encoder.flush(),
+            #[cfg(feature = "gzip")]
+            OutputWriter::Gzip(encoder) => encoder.flush(),
+        }
+    }
+}
+
+/// JSON Schema of the proving output, for generating bindings in other languages.
+#[cfg(feature = "json-schema")]
+fn output_schema() -> Result<String, String> {
+    serde_json::to_string_pretty(&schemars::schema_for!(ProofOutput)).map_err(|e| e.to_string())
+}
+
+#[cfg(not(feature = "json-schema"))]
+fn output_schema() -> Result<String, String> {
+    Err("--json-schema requires building with `--features json-schema`".into())
+}
+
+/// Normalize a witness entry to hex-LE. Big-endian hex entries are byte-reversed,
+/// which keeps their integer value, so `--strict` still sees unreduced inputs.
+fn entry_to_le_hex(entry: WitnessEntry, endian: WitnessEndian) -> Result<String, String> {
+    match (entry, endian) {
+        (WitnessEntry::Hex(hex), WitnessEndian::Be) => {
+            let mut bytes = decode_hex(&hex).map_err(|e| format!("Invalid hex {hex}: {e}"))?;
+            bytes.reverse();
+            Ok(format!("0x{}", hex::encode(bytes)))
+        }
+        (entry, _) => entry.into_hex(),
+    }
+}
+
+/// Strict-mode length check: a field element is at most 32 bytes, so a hex entry with
+/// more than 64 digits is most likely two values pasted together. Leniently it would
+/// be reduced modulo the field order into an unrelated element.
+fn check_hex_lengths(witness: &[String]) -> Result<(), String> {
+    for (i, entry) in witness.iter().enumerate() {
+        let digits = strip_hex_prefix(entry);
+        if digits.len() > 64 {
+            return Err(format!(
+                "Witness entry {i} has {} hex digits, at most 64 (32 bytes) allowed",
+                digits.len()
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Number of hex-LE witness entries at or above the field modulus, which the prover
+/// silently reduces.
+fn count_reduced(witness: &[String]) -> Result<usize, String> {
+    witness.iter().enumerate().try_fold(0, |count, (i, entry)| {
+        let (_, reduced) =
+            hex_to_field_with_flag(entry).map_err(|e| format!("witness[{i}]: {e}"))?;
+        Ok(count + usize::from(reduced))
+    })
+}
+
+/// Public signals are the `public_outputs + num_public_inputs` witness entries
+/// starting at `constant_offset`: circom places public outputs first, then public
+/// inputs, so both ranges are captured in that order.
+///
+/// `constant_offset` is 1 for circom `.wtns` exports and arkworks full assignments,
+/// where index 0 is the constant wire `1`. Witnesses that already drop that wire,
+/// such as an arkworks instance assignment without its leading one, start the
+/// public signals at index 0 and need an offset of 0.
+///
+/// In `strict` mode every signal must be a canonical field element: an input at or
+/// above the modulus would be silently reduced by the prover, so it is rejected
+/// before proving instead.
+fn extract_public_signals(
+    witness: &[String],
+    constant_offset: usize,
+    public_outputs: usize,
+    num_public_inputs: usize,
+    strict: bool,
+) -> Result<Vec<String>, String> {
+    let signals: Vec<String> = witness
+        .iter()
+        .skip(constant_offset)
+        .take(public_outputs + num_public_inputs)
+        .cloned()
+        .collect();
+    if strict {
+        for (i, signal) in signals.iter().enumerate() {
+            let (_, reduced) = hex_to_field_with_flag(signal)
+                .map_err(|e| format!("Public signal {i} is not valid hex: {e}"))?;
+            if reduced {
+                return Err(format!(
+                    "Public signal {i} ({signal}) is not a canonical field element (>= modulus)"
+                ));
+            }
+        }
+    }
+    Ok(signals)
+}
+
+/// Parse an expected signal: `0x` hex-LE (this CLI) or decimal (snarkjs `public.json`).
+fn parse_expected_signal(s: &str) -> Result<ark_bn254::Fr, String> {
+    if s.starts_with("0x") || s.starts_with("0X") {
+        hex_to_field(s)
+    } else {
+        decimal_to_field(s)
+    }
+}
+
+/// Compare the signals a proof commits to against the expected ones, as field elements.
+fn check_expected_signals(actual: &[String], expected: &[String]) -> Result<(), String> {
+    if actual.len() != expected.len() {
+        return Err(format!(
+            "expected {} public signals, proof has {}",
+            expected.len(),
+            actual.len()
+        ));
+    }
+    for (i, (a, e)) in actual.iter().zip(expected).enumerate() {
+        if hex_to_field(a)? != parse_expected_signal(e)? {
+            return Err(format!("public signal {i}: proof has {a}, expected {e}"));
+        }
+    }
+    Ok(())
+}
+
+/// Read `path`, or all of stdin when `path` is `-`.
+fn read_path_or_stdin(path: &str) -> Result<String, String> {
+    if path == "-" {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| format!("Failed to read stdin: {e}"))?;
+        return Ok(input);
+    }
+    std::fs::read_to_string(path).map_err(|e| format!("Failed to read {path}: {e}"))
+}
+
+/// `verify <proof.json> <vk.bin> [--expect public.json]`, or
+/// `verify --vk <vk.bin> <proof.json>`. A proof path of `-` reads the proof from
+/// stdin, so the generate command's output can be piped straight in.
+fn run_verify(args: &[String]) -> Result<VerifyOutcome, String> {
+    let mut positional = Vec::new();
+    let mut expect_path = None;
+    let mut vk_flag = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--expect" => expect_path = Some(args.next().ok_or("--expect requires a path")?),
+            "--vk" => vk_flag = Some(args.next().ok_or("--vk requires a path")?),
+            flag if flag.starts_with("--") => return Err(format!("Unknown flag: {flag}")),
+            _ => positional.push(arg),
+        }
+    }
+    let (proof_path, vk_path) = match (&positional[..], vk_flag) {
+        ([proof_path], Some(vk_path)) => (*proof_path, vk_path),
+        ([proof_path, vk_path], None) => (*proof_path, *vk_path),
+        _ => {
+            return Err(
+                "Expected verify <proof.json|-> <vk.bin> or verify --vk <vk.bin> <proof.json|-> [--expect public.json]"
+                    .into(),
+            )
+        }
+    };
+
+    let proof_json = read_path_or_stdin(proof_path)?;
+    let input: VerifyInput = serde_json::from_str(&proof_json)
+        .map_err(|e| format!("Failed to parse {proof_path}: {e}"))?;
+    if !verify_input(&input, vk_path)? {
+        return Ok(VerifyOutcome::InvalidProof);
+    }
+
+    if let Some(expect_path) = expect_path {
+        let expected_json = std::fs::read_to_string(expect_path)
+            .map_err(|e| format!("Failed to read {expect_path}: {e}"))?;
+        let expected: Vec<String> = serde_json::from_str(&expected_json)
+            .map_err(|e| format!("Failed to parse {expect_path}: {e}"))?;
+        if let Err(e) = check_expected_signals(&input.public_signals, &expected) {
+            return Ok(VerifyOutcome::SignalMismatch(e));
+        }
+    }
+    Ok(VerifyOutcome::Valid)
+}
+
+/// Check `input` against the compressed VK at `vk_path`. A proof that fails to
+/// decode is as invalid as one that fails the pairing check; any other error
+/// (signal count or encoding, curve tag, key) is malformed input and returned.
+fn verify_input(input: &VerifyInput, vk_path: &str) -> Result<bool, String> {
+    let proof_bytes = hex::decode(input.proof.trim_start_matches("0x"))
+        .map_err(|e| format!("Invalid proof hex: {e}"))?;
+    let verifier =
+        Verifier::from_file(vk_path, input.public_signals.len()).map_err(|e| e.to_string())?;
+    match verifier.verify(&proof_bytes, &input.public_signals) {
+        Ok(valid) => Ok(valid),
+        Err(ProofError::ProofParse(_)) => Ok(false),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// `verify --request`: one [`VerifyRequest`] JSON on stdin, one [`VerifyResponse`]
+/// line on stdout, so a parent process can run verification in a sandboxed child.
+fn verify_request_command(progress: Progress) -> i32 {
+    let result = read_path_or_stdin("-").and_then(|json| {
+        let request: VerifyRequest = serde_json::from_str(&json)
+            .map_err(|e| format!("Failed to parse verify request: {e}"))?;
+        verify_input(&request.input, &request.vk)
+    });
+    let response = match result {
+        Ok(valid) => VerifyResponse { valid, error: None },
+        Err(e) => VerifyResponse {
+            valid: false,
+            error: Some(e),
+        },
+    };
+    let code = match (&response.error, response.valid) {
+        (Some(_), _) => 1,
+        (None, true) => 0,
+        (None, false) => EXIT_INVALID_PROOF,
+    };
+    match serde_json::to_string(&response) {
+        Ok(line) => {
+            println!("{line}");
+            code
+        }
+        Err(e) => {
+            progress.error(
+                "verify_failed",
+                &format!("Failed to serialize response: {e}"),
+            );
+            1
+        }
+    }
+}
+
+fn verify_command(args: &[String], progress: Progress) -> i32 {
+    if let [flag] = args {
+        if flag == "--request" {
+            return verify_request_command(progress);
+        }
+    }
+    match run_verify(args) {
+        Ok(VerifyOutcome::Valid) => {
+            progress.info("proof_valid", "✅", "Proof is valid");
+            0
+        }
+        Ok(VerifyOutcome::InvalidProof) => {
+            progress.error("proof_invalid", "Proof is invalid");
+            EXIT_INVALID_PROOF
+        }
+        Ok(VerifyOutcome::SignalMismatch(e)) => {
+            progress.error("signals_mismatch", &format!("Public signals mismatch: {e}"));
+            EXIT_SIGNAL_MISMATCH
+        }
+        Err(e) => {
+            progress.error("verify_failed", &e);
+            1
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct WitnessDifference {
+    index: usize,
+    a: String,
+    b: String,
+}
+
+fn read_witness_fields(path: &str) -> Result<Vec<ark_bn254::Fr>, String> {
+    let json = read_witness_file(path, false).map_err(|e| format!("Failed to read {path}: {e}"))?;
+    let input: WitnessInput =
+        serde_json::from_str(&json).map_err(|e| format!("Failed to parse {path}: {e}"))?;
+    input
+        .witness
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| entry.to_field().map_err(|e| format!("{path}[{i}]: {e}")))
+        .collect()
+}
+
+/// `diff-witness <a.json> <b.json>`.
+fn run_diff_witness(args: &[String]) -> Result<Vec<WitnessDifference>, String> {
+    let [a_path, b_path] = args else {
+        return Err("Expected diff-witness <a.json> <b.json>".into());
+    };
+    let (a, b) = (read_witness_fields(a_path)?, read_witness_fields(b_path)?);
+    Ok(witness_diff(&a, &b)
+        .into_iter()
+        .map(|(index, a, b)| WitnessDifference { index, a, b })
+        .collect())
+}
+
+/// `export-bundle <out-dir> --proof <proof.json|-> [--vk <vk.bin>]`, returning the
+/// files written.
+fn run_export_bundle(args: &[String]) -> Result<Vec<PathBuf>, String> {
+    let mut positional = Vec::new();
+    let (mut proof_path, mut vk_path) = (None, None);
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--proof" => proof_path = Some(args.next().ok_or("--proof requires a path")?),
+            "--vk" => vk_path = Some(args.next().ok_or("--vk requires a path")?),
+            flag if flag.starts_with("--") => return Err(format!("Unknown flag: {flag}")),
+            _ => positional.push(arg),
+        }
+    }
+    let ([out_dir], Some(proof_path)) = (&positional[..], proof_path) else {
+        return Err(
+            "Expected export-bundle <out-dir> --proof <proof.json|-> [--vk <vk.bin>]".into(),
+        );
+    };
+
+    let proof_json = read_path_or_stdin(proof_path)?;
+    let input: VerifyInput = serde_json::from_str(&proof_json)
+        .map_err(|e| format!("Failed to parse {proof_path}: {e}"))?;
+    let proof_bytes = hex::decode(input.proof.trim_start_matches("0x"))
+        .map_err(|e| format!("Invalid proof hex: {e}"))?;
+    let signals = input
+        .public_signals
+        .iter()
+        .enumerate()
+        .map(|(i, s)| hex_to_field(s).map_err(|e| format!("public_signals[{i}]: {e}")))
+        .collect::<Result<Vec<_>, _>>()?;
+    // Render everything before touching the output directory.
+    let snarkjs_proof = proof_to_snarkjs_json(&proof_bytes)?;
+    let snarkjs_vk = vk_path
+        .map(|path| {
+            let bytes = std::fs::read(path).map_err(|e| format!("Failed to read {path}: {e}"))?;
+            vk_to_snarkjs_json(&bytes)
+        })
+        .transpose()?;
+
+    let out_dir = Path::new(out_dir);
+    std::fs::create_dir_all(out_dir)
+        .map_err(|e| format!("Failed to create {}: {e}", out_dir.display()))?;
+    let write = |name: &str, json: String| {
+        let path = out_dir.join(name);
+        std::fs::write(&path, json + "\n")
+            .map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+        Ok::<_, String>(path)
+    };
+    let mut written = vec![write("proof.json", snarkjs_proof)?];
+    let public_path = out_dir.join("public.json");
+    write_public_json(&signals, &public_path.to_string_lossy())?;
+    written.push(public_path);
+    if let Some(vk) = snarkjs_vk {
+        written.push(write("verification_key.json", vk)?);
+    }
+    Ok(written)
+}
+
+fn export_bundle_command(args: &[String], progress: Progress) -> i32 {
+    match run_export_bundle(args) {
+        Ok(written) => {
+            for path in written {
+                progress.info("file_written", "✅", &format!("Wrote {}", path.display()));
+            }
+            0
+        }
+        Err(e) => {
+            progress.error("export_failed", &e);
+            1
+        }
+    }
+}
+
+fn diff_witness_command(args: &[String], progress: Progress) -> i32 {
+    match run_diff_witness(args) {
+        Ok(diff) => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&diff).expect("differences serialize")
+            );
+            if diff.is_empty() {
+                progress.info("witnesses_identical", "✅", "Witnesses are identical");
+                0
+            } else {
+                progress.error(
+                    "witnesses_differ",
+                    &format!("Witnesses differ at {} indices", diff.len()),
+                );
+                EXIT_WITNESS_DIFF
+            }
+        }
+        Err(e) => {
+            progress.error("diff_failed", &e);
+            1
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchJob {
+    circuit: String,
+    witness: Vec<WitnessEntry>,
+}
+
+#[derive(Debug, Serialize)]
+struct BatchProof {
+    circuit: String,
     proof: String,
     public_signals: Vec<String>,
 }
 
+#[derive(Debug, Default)]
+struct BatchArgs {
+    jobs_path: String,
+    keys: HashMap<CircuitType, String>,
+    split_dir: Option<PathBuf>,
+    compress: Option<OutputCompression>,
+}
+
+fn parse_batch_args(args: &[String]) -> Result<BatchArgs, String> {
+    let mut batch = BatchArgs::default();
+    if let Some(path) = config_flag(args)? {
+        let config = load_config(path)?;
+        batch.keys = config.keys;
+        batch.compress = config.compress;
+    }
+    let mut positional = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--config" => {
+                args.next();
+            }
+            "--key" => {
+                let spec = args.next().ok_or("--key requires <circuit>=<pk.ark>")?;
+                let (circuit, path) = spec
+                    .split_once('=')
+                    .ok_or_else(|| format!("Invalid --key {spec} (expected <circuit>=<pk.ark>)"))?;
+                batch
+                    .keys
+                    .insert(CircuitType::from_str(circuit)?, path.to_string());
+            }
+            "--split-by-circuit" => {
+                let dir = args
+                    .next()
+                    .ok_or("--split-by-circuit requires a directory")?;
+                batch.split_dir = Some(PathBuf::from(dir));
+            }
+            "--compress" => {
+                let kind = args.next().ok_or("--compress requires zstd or gzip")?;
+                batch.compress = Some(kind.parse()?);
+            }
+            flag if flag.starts_with("--") => return Err(format!("Unknown flag: {flag}")),
+            _ => positional.push(arg.clone()),
+        }
+    }
+    if let Some(kind) = batch.compress {
+        kind.check_available()?;
+    }
+    let [jobs_path] = &positional[..] else {
+        return Err(
+            "Expected batch <jobs.jsonl> --key <circuit>=<pk.ark>... [--config <orbinum.toml>] [--split-by-circuit <out-dir>] [--compress zstd|gzip]"
+                .into(),
+        );
+    };
+    batch.jobs_path = jobs_path.clone();
+    Ok(batch)
+}
+
+/// Destination of batch output lines: stdout, or one `<circuit>.jsonl` per circuit
+/// (`.jsonl.zst` / `.jsonl.gz` when compressed).
+enum BatchSink {
+    Stdout(OutputWriter<std::io::Stdout>),
+    Split {
+        dir: PathBuf,
+        compress: Option<OutputCompression>,
+        files: HashMap<CircuitType, OutputWriter<BufWriter<std::fs::File>>>,
+    },
+}
+
+impl BatchSink {
+    fn new(split_dir: Option<&Path>, compress: Option<OutputCompression>) -> Result<Self, String> {
+        match split_dir {
+            None => Ok(BatchSink::Stdout(OutputWriter::new(
+                std::io::stdout(),
+                compress,
+            )?)),
+            Some(dir) => {
+                std::fs::create_dir_all(dir)
+                    .map_err(|e| format!("Failed to create {}: {e}", dir.display()))?;
+                Ok(BatchSink::Split {
+                    dir: dir.to_path_buf(),
+                    compress,
+                    files: HashMap::new(),
+                })
+            }
+        }
+    }
+
+    fn write_line(&mut self, circuit: CircuitType, line: &str) -> Result<(), String> {
+        let out: &mut dyn Write = match self {
+            BatchSink::Stdout(out) => out,
+            BatchSink::Split {
+                dir,
+                compress,
+                files,
+            } => match files.entry(circuit) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    let mut name = format!("{}.jsonl", circuit.as_str());
+                    if let Some(kind) = compress {
+                        name = format!("{name}.{}", kind.extension());
+                    }
+                    let path = dir.join(name);
+                    let file = std::fs::File::create(&path)
+                        .map_err(|e| format!("Failed to create {}: {e}", path.display()))?;
+                    entry.insert(OutputWriter::new(BufWriter::new(file), *compress)?)
+                }
+            },
+        };
+        writeln!(out, "{line}").map_err(|e| e.to_string())
+    }
+
+    fn finish(self) -> Result<(), String> {
+        match self {
+            BatchSink::Stdout(out) => out.finish().map(drop),
+            BatchSink::Split { files, .. } => {
+                for file in files.into_values() {
+                    file.finish()?.flush().map_err(|e| e.to_string())?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// `batch <jobs.jsonl> --key <circuit>=<pk.ark>... [--split-by-circuit <out-dir>]
+/// [--compress zstd|gzip]`.
+///
+/// Each proving key is loaded once, on the first job for its circuit. Returns the
+/// number of proofs generated.
+fn run_batch(args: &[String]) -> Result<usize, String> {
+    let batch = parse_batch_args(args)?;
+    let jobs = std::fs::read_to_string(&batch.jobs_path)
+        .map_err(|e| format!("Failed to read {}: {e}", batch.jobs_path))?;
+    let mut generators: HashMap<CircuitType, ProofGenerator> = HashMap::new();
+    let mut sink = BatchSink::new(batch.split_dir.as_deref(), batch.compress)?;
+    let mut proved = 0;
+    for (i, line) in jobs.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let at = |e: String| format!("line {}: {e}", i + 1);
+        let job: BatchJob = serde_json::from_str(line).map_err(|e| at(e.to_string()))?;
+        let circuit = CircuitType::from_str(&job.circuit).map_err(at)?;
+        let generator = match generators.entry(circuit) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let path = batch
+                    .keys
+                    .get(&circuit)
+                    .ok_or_else(|| at(format!("no --key given for {}", circuit.as_str())))?;
+                let generator = ProofGenerator::from_file(path, circuit.num_public_signals())
+                    .map_err(|e| at(e.to_string()))?;
+                entry.insert(generator)
+            }
+        };
+        let witness = job
+            .witness
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| entry.to_field().map_err(|e| format!("witness[{i}]: {e}")))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(at)?;
+        let (proof, public_signals) = generator
+            .prove_with_public_signals(witness)
+            .map_err(|e| at(e.to_string()))?;
+        let output = BatchProof {
+            circuit: circuit.as_str().to_string(),
+            proof: format!("0x{}", hex::encode(proof)),
+            public_signals,
+        };
+        let line = serde_json::to_string(&output).map_err(|e| e.to_string())?;
+        sink.write_line(circuit, &line)?;
+        proved += 1;
+    }
+    sink.finish()?;
+    Ok(proved)
+}
+
+fn batch_command(args: &[String], progress: Progress) -> i32 {
+    match run_batch(args) {
+        Ok(proved) => {
+            progress.info("batch_done", "✅", &format!("Generated {proved} proofs"));
+            0
+        }
+        Err(e) => {
+            progress.error("batch_failed", &e);
+            1
+        }
+    }
+}
+
+/// `prove-dir` exit code: at least one witness failed to prove.
+const EXIT_PROVE_DIR_FAILURES: i32 = 2;
+
+#[derive(Debug, Default, Serialize)]
+struct ProveDirSummary {
+    proved: Vec<String>,
+    failed: Vec<ProveDirFailure>,
+}
+
+#[derive(Debug, Serialize)]
+struct ProveDirFailure {
+    file: String,
+    error: String,
+}
+
+/// Prove one witness file and write `{"proof", "public_signals"}` to `out_path`.
+fn prove_witness_file(
+    generator: &ProofGenerator,
+    witness_path: &Path,
+    out_path: &Path,
+) -> Result<(), String> {
+    let json = std::fs::read_to_string(witness_path).map_err(|e| e.to_string())?;
+    let input: WitnessInput = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+    if let Some(n) = input.num_public_signals {
+        if n != generator.num_public_signals() {
+            return Err(format!(
+                "num_public_signals is {n}, the key has {}",
+                generator.num_public_signals()
+            ));
+        }
+    }
+    let witness = input
+        .witness
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| entry.to_field().map_err(|e| format!("witness[{i}]: {e}")))
+        .collect::<Result<Vec<_>, _>>()?;
+    let (proof, public_signals) = generator
+        .prove_with_public_signals(witness)
+        .map_err(|e| e.to_string())?;
+    let output = ProofOutput {
+        proof: Some(format!("0x{}", hex::encode(proof))),
+        public_signals: PublicSignals::Hex(public_signals),
+    };
+    let rendered = render_output(&output, false).map_err(|e| e.to_string())?;
+    std::fs::write(out_path, rendered)
+        .map_err(|e| format!("Failed to write {}: {e}", out_path.display()))
+}
+
+/// `prove-dir <witness-dir> <pk.ark> <out-dir>`: prove every `.json` witness in
+/// `witness-dir` (in file-name order) with one loaded key, writing each proof to
+/// the same file name in `out-dir`, which must be a different directory. The
+/// public-signal count comes from the key.
+/// A failing witness is recorded in the summary and does not stop the run.
+fn run_prove_dir(args: &[String]) -> Result<ProveDirSummary, String> {
+    let [witness_dir, pk_path, out_dir] = args else {
+        return Err("Expected prove-dir <witness-dir> <pk.ark> <out-dir>".into());
+    };
+    let pk_bytes = std::fs::read(pk_path).map_err(|e| format!("Failed to read {pk_path}: {e}"))?;
+    let generator = ProofGenerator::from_key_bytes(&pk_bytes).map_err(|e| e.to_string())?;
+    let mut witness_paths: Vec<PathBuf> = std::fs::read_dir(witness_dir)
+        .map_err(|e| format!("Failed to read {witness_dir}: {e}"))?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<_, _>>()
+        .map_err(|e| format!("Failed to read {witness_dir}: {e}"))?;
+    witness_paths.retain(|p| p.is_file() && p.extension().is_some_and(|ext| ext == "json"));
+    witness_paths.sort();
+    std::fs::create_dir_all(out_dir).map_err(|e| format!("Failed to create {out_dir}: {e}"))?;
+    // Proofs take the witness file names, so writing them into the witness
+    // directory would overwrite every witness.
+    let canonical =
+        |dir: &str| std::fs::canonicalize(dir).map_err(|e| format!("Failed to resolve {dir}: {e}"));
+    if canonical(witness_dir)? == canonical(out_dir)? {
+        return Err(format!(
+            "<out-dir> {out_dir} is the witness directory; proofs would overwrite the witnesses"
+        ));
+    }
+
+    let mut summary = ProveDirSummary::default();
+    for witness_path in witness_paths {
+        let file_name = witness_path.file_name().unwrap_or_default();
+        let file = file_name.to_string_lossy().into_owned();
+        let out_path = Path::new(out_dir).join(file_name);
+        match prove_witness_file(&generator, &witness_path, &out_path) {
+            Ok(()) => summary.proved.push(file),
+            Err(error) => summary.failed.push(ProveDirFailure { file, error }),
+        }
+    }
+    Ok(summary)
+}
+
+fn prove_dir_command(args: &[String], progress: Progress) -> i32 {
+    let summary = match run_prove_dir(args) {
+        Ok(summary) => summary,
+        Err(e) => {
+            progress.error("prove_dir_failed", &e);
+            return 1;
+        }
+    };
+    for failure in &summary.failed {
+        progress.error(
+            "prove_failed",
+            &format!("{}: {}", failure.file, failure.error),
+        );
+    }
+    progress.info(
+        "prove_dir_done",
+        "✅",
+        &format!(
+            "Proved {}, failed {}",
+            summary.proved.len(),
+            summary.failed.len()
+        ),
+    );
+    match serde_json::to_string(&summary) {
+        Ok(json) => println!("{json}"),
+        Err(e) => {
+            progress.error(
+                "prove_dir_failed",
+                &format!("Failed to serialize summary: {e}"),
+            );
+            return 1;
+        }
+    }
+    if summary.failed.is_empty() {
+        0
+    } else {
+        EXIT_PROVE_DIR_FAILURES
+    }
+}
+
+/// Pair each hex-LE signal with its decimal (snarkjs) encoding.
+fn dual_signals(signals: Vec<String>) -> Result<Vec<DualSignal>, String> {
+    signals
+        .into_iter()
+        .map(|hex| {
+            let dec = to_decimal_str(&hex_to_field(&hex)?);
+            Ok(DualSignal { hex, dec })
+        })
+        .collect()
+}
+
+/// Serialize `output` as JSON. Canonical output has lexicographically sorted keys
+/// and no insignificant whitespace, so equal outputs are byte-identical.
+fn render_output(output: &ProofOutput, canonical: bool) -> Result<String, serde_json::Error> {
+    if canonical {
+        // `serde_json::Value` objects are backed by a `BTreeMap`, which sorts keys.
+        serde_json::to_string(&serde_json::to_value(output)?)
+    } else {
+        serde_json::to_string(output)
+    }
+}
+
 fn main() {
     let args: Vec<String> = env::args().collect();
 
-    if args.len() < 3 || args.len() > 4 {
+    match args.get(1).map(String::as_str) {
+        Some("info") => {
+            let info = serde_json::to_string_pretty(&build_info()).expect("BuildInfo serializes");
+            println!("{info}");
+            return;
+        }
+        Some(command @ ("verify" | "diff-witness" | "batch" | "prove-dir" | "export-bundle")) => {
+            let (progress, rest) = take_progress_flags(&args[2..])
+                .unwrap_or_else(|e| Progress::default().fail("usage", &e));
+            let code = match command {
+                "verify" => verify_command(&rest, progress),
+                "diff-witness" => diff_witness_command(&rest, progress),
+                "batch" => batch_command(&rest, progress),
+                "prove-dir" => prove_dir_command(&rest, progress),
+                _ => export_bundle_command(&rest, progress),
+            };
+            std::process::exit(code)
+        }
+        Some("--version") => {
+            println!("{} {}", env!("CARGO_PKG_NAME"), build_info().version);
+            return;
+        }
+        Some("--json-schema") => {
+            let schema =
+                output_schema().unwrap_or_else(|e| Progress::default().fail("schema_failed", &e));
+            println!("{schema}");
+            return;
+        }
+        _ => {}
+    }
+
+    let cli = parse_args(&args[1..]).unwrap_or_else(|e| {
+        // The flags may be what failed to parse; report with whatever they give.
+        let progress = take_progress_flags(&args[1..])
+            .map(|(progress, _)| progress)
+            .unwrap_or_default();
+        progress.error("usage", &e);
+        if progress.format == LogFormat::Json {
+            std::process::exit(1);
+        }
         eprintln!(
-            "Usage: {} <witness.json> <proving_key.ark> [num_public_signals]",
+            "Usage: {} [--canonical] [--signals-dual] [--zstd] [--witness-endian le|be] [--strict] [--signals-only] [--compress zstd|gzip] [--quiet] [--log-format plain|json] [--public-outputs N] [--constant-offset N] [--key-relative] [--config orbinum.toml] [--circuit name] <witness.json> <proving_key.ark> [num_public_signals]",
             args[0]
         );
         eprintln!("\nnum_public_signals can be specified either:");
@@ -44,71 +1435,762 @@ fn main() {
         eprintln!("  2. As 3rd CLI argument");
         eprintln!("  3. Defaults to 5 if not specified");
         std::process::exit(1);
-    }
+    });
 
-    let witness_path = &args[1];
-    let proving_key_path = &args[2];
-    let cli_num_public: Option<usize> = args.get(3).and_then(|s| s.parse().ok());
+    let witness_path = &cli.witness_path;
+    let cli_num_public = cli.num_public_signals;
+    let progress = cli.progress;
+
+    if let Some(limit) = cli.max_witness_bytes {
+        check_witness_size(witness_path, limit)
+            .unwrap_or_else(|e| progress.fail("witness_too_large", &e));
+    }
 
     // Read witness JSON
-    let witness_json = std::fs::read_to_string(witness_path).unwrap_or_else(|e| {
-        eprintln!("❌ Failed to read witness file: {e}");
-        std::process::exit(1);
+    let witness_json = read_witness_file(witness_path, cli.zstd).unwrap_or_else(|e| {
+        progress.fail(
+            "witness_read_failed",
+            &format!("Failed to read witness file: {e}"),
+        )
     });
 
     let input: WitnessInput = serde_json::from_str(&witness_json).unwrap_or_else(|e| {
-        eprintln!("❌ Failed to parse witness JSON: {e}");
-        std::process::exit(1);
+        progress.fail(
+            "witness_parse_failed",
+            &format!("Failed to parse witness JSON: {e}"),
+        )
     });
 
-    // Priority: CLI arg > JSON field > default (5)
-    let num_public_signals = cli_num_public.or(input.num_public_signals).unwrap_or(5);
+    let witness: Vec<String> = input
+        .witness
+        .into_iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            entry_to_le_hex(entry, cli.witness_endian)
+                .map_err(|e| format!("{witness_path}[{i}]: {e}"))
+        })
+        .collect::<Result<_, _>>()
+        .unwrap_or_else(|e| {
+            progress.fail("invalid_witness", &format!("Invalid witness entry: {e}"))
+        });
 
-    eprintln!(
-        "🔐 Generating proof from {} witness elements...",
-        input.witness.len()
-    );
+    if cli.strict {
+        check_hex_lengths(&witness).unwrap_or_else(|e| progress.fail("invalid_witness", &e));
+    }
 
-    // Generate proof
-    let proof_bytes =
-        generate_proof_from_witness(&input.witness, proving_key_path, num_public_signals)
-            .unwrap_or_else(|e| {
-                eprintln!("❌ Proof generation failed: {e}");
-                std::process::exit(1);
-            });
+    let reduced = count_reduced(&witness).unwrap_or_else(|e| {
+        progress.fail("invalid_witness", &format!("Invalid witness entry: {e}"))
+    });
+    if reduced > 0 {
+        progress.warn(
+            "witness_reduced",
+            &format!("{reduced} witness values were reduced modulo the field order"),
+        );
+    }
 
-    eprintln!("✅ Proof generated: {} bytes", proof_bytes.len());
+    // Priority: CLI arg > JSON field > default (5)
+    let num_public_inputs = cli_num_public.or(input.num_public_signals).unwrap_or(5);
+    let public_outputs = cli
+        .public_outputs
+        .or(input.public_outputs_count)
+        .unwrap_or(0);
+    let num_public_signals = public_outputs + num_public_inputs;
 
-    eprintln!("📊 Extracting {num_public_signals} public signals");
+    progress.info(
+        "extract_signals",
+        "📊",
+        &if public_outputs > 0 {
+            format!(
+                "Extracting {num_public_signals} public signals ({public_outputs} outputs + {num_public_inputs} inputs)"
+            )
+        } else {
+            format!("Extracting {num_public_signals} public signals")
+        },
+    );
 
-    // Extract public signals (indices 1..n from witness)
-    // Index 0 is always 1 (constant), indices 1..n are public inputs
-    let public_signals: Vec<String> = input
-        .witness
-        .iter()
-        .skip(1) // Skip index 0 (always 1)
-        .take(num_public_signals)
-        .cloned()
-        .collect();
+    let constant_offset = cli.constant_offset.unwrap_or(1);
+    let public_signals = extract_public_signals(
+        &witness,
+        constant_offset,
+        public_outputs,
+        num_public_inputs,
+        cli.strict,
+    )
+    .unwrap_or_else(|e| progress.fail("extract_signals_failed", &e));
 
     if public_signals.len() != num_public_signals {
-        eprintln!(
-            "⚠️  Warning: Expected {} public signals, got {}",
-            num_public_signals,
-            public_signals.len()
+        progress.warn(
+            "signal_count_mismatch",
+            &format!(
+                "Expected {} public signals, got {}",
+                num_public_signals,
+                public_signals.len()
+            ),
         );
     }
 
+    // The prover always reads index 0 as the constant wire, so restore it for
+    // witnesses exported without one; the proof then commits to the signals above.
+    let witness = if constant_offset == 0 {
+        std::iter::once(format!("0x01{}", "00".repeat(31)))
+            .chain(witness)
+            .collect()
+    } else {
+        witness
+    };
+
+    let proof = cli.proving_key_path.as_deref().map(|proving_key_path| {
+        progress.info(
+            "prove_start",
+            "🔐",
+            &format!(
+                "Generating proof from {} witness elements...",
+                witness.len()
+            ),
+        );
+
+        let proof_bytes =
+            generate_proof_from_witness(&witness, proving_key_path, num_public_signals)
+                .unwrap_or_else(|e| {
+                    progress.fail("prove_failed", &format!("Proof generation failed: {e}"))
+                });
+
+        progress.info(
+            "prove_done",
+            "✅",
+            &format!("Proof generated: {} bytes", proof_bytes.len()),
+        );
+        format!("0x{}", hex::encode(&proof_bytes))
+    });
+
     // Output result as JSON
+    let public_signals = if cli.signals_dual {
+        PublicSignals::Dual(dual_signals(public_signals).unwrap_or_else(|e| {
+            progress.fail(
+                "convert_signals_failed",
+                &format!("Failed to convert public signals: {e}"),
+            )
+        }))
+    } else {
+        PublicSignals::Hex(public_signals)
+    };
+
     let output = ProofOutput {
-        proof: format!("0x{}", hex::encode(&proof_bytes)),
+        proof,
         public_signals,
     };
 
-    let output_json = serde_json::to_string(&output).unwrap_or_else(|e| {
-        eprintln!("❌ Failed to serialize output: {e}");
-        std::process::exit(1);
+    let output_json = render_output(&output, cli.canonical).unwrap_or_else(|e| {
+        progress.fail("output_failed", &format!("Failed to serialize output: {e}"))
+    });
+
+    let stdout = std::io::stdout().lock();
+    write_output(stdout, format!("{output_json}\n").as_bytes(), cli.compress).unwrap_or_else(|e| {
+        progress.fail("output_failed", &format!("Failed to write output: {e}"))
     });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn sample_output() -> ProofOutput {
+        ProofOutput {
+            proof: Some("0xabcd".into()),
+            public_signals: PublicSignals::Hex(vec!["0x01".into(), "0x02".into()]),
+        }
+    }
 
-    println!("{output_json}");
+    #[test]
+    fn test_parse_args_positional() {
+        let cli = parse_args(&args(&["w.json", "pk.ark", "4"])).unwrap();
+        assert_eq!(cli.witness_path, "w.json");
+        assert_eq!(cli.proving_key_path.as_deref(), Some("pk.ark"));
+        assert_eq!(cli.num_public_signals, Some(4));
+        assert!(!cli.canonical);
+    }
+
+    #[test]
+    fn test_parse_args_canonical_flag_anywhere() {
+        let cli = parse_args(&args(&["w.json", "--canonical", "pk.ark"])).unwrap();
+        assert!(cli.canonical);
+        assert_eq!(cli.proving_key_path.as_deref(), Some("pk.ark"));
+    }
+
+    #[test]
+    fn test_parse_args_rejects_unknown_flag() {
+        assert!(parse_args(&args(&["--bogus", "w.json", "pk.ark"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_rejects_missing_key() {
+        assert!(parse_args(&args(&["w.json"])).is_err());
+    }
+
+    #[test]
+    fn test_dual_signals_are_consistent() {
+        let hex = format!("0x3930{}", "00".repeat(30));
+        let dual = dual_signals(vec![hex.clone()]).unwrap();
+        assert_eq!(dual[0].hex, hex);
+        assert_eq!(dual[0].dec, "12345");
+
+        let output = ProofOutput {
+            proof: Some("0xabcd".into()),
+            public_signals: PublicSignals::Dual(dual),
+        };
+        let json: serde_json::Value =
+            serde_json::from_str(&render_output(&output, false).unwrap()).unwrap();
+        assert_eq!(json["public_signals"][0]["hex"], hex);
+        assert_eq!(json["public_signals"][0]["dec"], "12345");
+    }
+
+    #[test]
+    fn test_dual_signals_rejects_invalid_hex() {
+        assert!(dual_signals(vec!["0xZZ".into()]).is_err());
+    }
+
+    #[test]
+    fn test_read_plain_witness_file() {
+        let path = "/tmp/test_cli_plain_witness.json";
+        std::fs::write(path, r#"{"witness":["0x01"]}"#).unwrap();
+        let json = read_witness_file(path, false).unwrap();
+        let _ = std::fs::remove_file(path);
+        assert_eq!(json, r#"{"witness":["0x01"]}"#);
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_read_zstd_witness_roundtrip() {
+        let json = r#"{"witness":["0x01","0x02","0x03"],"num_public_signals":1}"#;
+        let path = "/tmp/test_cli_witness.json.zst";
+        let compressed = zstd::stream::encode_all(json.as_bytes(), 3).unwrap();
+        std::fs::write(path, compressed).unwrap();
+
+        let loaded = read_witness_file(path, false).unwrap();
+        let _ = std::fs::remove_file(path);
+        assert_eq!(loaded, json);
+        let input: WitnessInput = serde_json::from_str(&loaded).unwrap();
+        assert_eq!(input.witness.len(), 3);
+    }
+
+    #[cfg(not(feature = "zstd"))]
+    #[test]
+    fn test_zstd_without_feature_errors() {
+        let err = read_witness_file("/tmp/whatever.json.zst", false).unwrap_err();
+        assert!(err.contains("zstd"));
+    }
+
+    #[test]
+    fn test_parse_args_compress() {
+        let parsed = parse_args(&args(&["--compress", "gzip", "w.json", "pk.ark"]));
+        if cfg!(feature = "gzip") {
+            let cli = parsed.unwrap();
+            assert_eq!(cli.compress, Some(OutputCompression::Gzip));
+            assert_eq!(cli.witness_path, "w.json");
+        } else {
+            // Rejected while parsing, before any proving work.
+            assert!(parsed.unwrap_err().contains("--features gzip"));
+        }
+        let batch = parse_batch_args(&args(&["jobs.jsonl", "--compress", "zstd"]));
+        if cfg!(feature = "zstd") {
+            assert_eq!(batch.unwrap().compress, Some(OutputCompression::Zstd));
+        } else {
+            assert!(batch.unwrap_err().contains("--features zstd"));
+        }
+        assert!(parse_args(&args(&["--compress", "lz4", "w.json", "pk.ark"])).is_err());
+        assert!(parse_args(&args(&["w.json", "pk.ark", "--compress"])).is_err());
+    }
+
+    #[test]
+    fn test_uncompressed_output_passthrough() {
+        let mut out = Vec::new();
+        write_output(&mut out, b"{}\n", None).unwrap();
+        assert_eq!(out, b"{}\n");
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_zstd_output_roundtrip() {
+        let lines = render_output(&sample_output(), false).unwrap() + "\n";
+        let stream = lines.repeat(3);
+        let mut out = Vec::new();
+        write_output(&mut out, stream.as_bytes(), Some(OutputCompression::Zstd)).unwrap();
+        let decoded = zstd::stream::decode_all(out.as_slice()).unwrap();
+        assert_eq!(decoded, stream.as_bytes());
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_gzip_output_roundtrip() {
+        use std::io::Read;
+        let stream = (render_output(&sample_output(), false).unwrap() + "\n").repeat(3);
+        let mut out = Vec::new();
+        write_output(&mut out, stream.as_bytes(), Some(OutputCompression::Gzip)).unwrap();
+        let mut decoded = String::new();
+        flate2::read::GzDecoder::new(out.as_slice())
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, stream);
+    }
+
+    #[cfg(not(feature = "gzip"))]
+    #[test]
+    fn test_gzip_without_feature_errors() {
+        let err = write_output(Vec::new(), b"{}", Some(OutputCompression::Gzip)).unwrap_err();
+        assert!(err.contains("gzip"));
+    }
+
+    #[test]
+    fn test_witness_input_debug_is_redacted() {
+        let input: WitnessInput =
+            serde_json::from_str(r#"{"witness":["0x01","0xdeadbeef"]}"#).unwrap();
+        let debug = format!("{input:?}");
+        assert!(!debug.contains("deadbeef"));
+        assert!(debug.contains("witness_len: 2"));
+    }
+
+    #[test]
+    fn test_extract_public_signals() {
+        let w = args(&["0x01", "0x0a", "0x0b", "0x0c"]);
+        assert_eq!(
+            extract_public_signals(&w, 1, 0, 2, true).unwrap(),
+            args(&["0x0a", "0x0b"])
+        );
+    }
+
+    #[test]
+    fn test_key_relative_resolves_against_witness_dir() {
+        let cli = parse_args(&args(&[
+            "--key-relative",
+            "circuits/transfer/inputs/w.json",
+            "../transfer_pk.ark",
+        ]))
+        .unwrap();
+        assert_eq!(
+            Path::new(cli.proving_key_path.as_deref().unwrap()),
+            Path::new("circuits/transfer/inputs/../transfer_pk.ark")
+        );
+        assert_eq!(cli.witness_path, "circuits/transfer/inputs/w.json");
+
+        // A bare witness name and an absolute key are left as given.
+        let cli = parse_args(&args(&["--key-relative", "w.json", "pk.ark"])).unwrap();
+        assert_eq!(cli.proving_key_path.as_deref(), Some("pk.ark"));
+        let absolute = std::env::temp_dir().join("pk.ark").display().to_string();
+        assert_eq!(key_relative_to_witness("a/b/w.json", &absolute), absolute);
+
+        let cli = parse_args(&args(&["circuits/transfer/w.json", "pk.ark"])).unwrap();
+        assert_eq!(cli.proving_key_path.as_deref(), Some("pk.ark"));
+    }
+
+    #[test]
+    fn test_extract_public_signals_constant_offset() {
+        let w = args(&["0x01", "0x0a", "0x0b", "0x0c"]);
+        assert_eq!(
+            extract_public_signals(&w, 1, 0, 2, false).unwrap(),
+            args(&["0x0a", "0x0b"])
+        );
+        // Without the constant wire the window starts at index 0.
+        assert_eq!(
+            extract_public_signals(&w, 0, 0, 2, false).unwrap(),
+            args(&["0x01", "0x0a"])
+        );
+        let cli = parse_args(&args(&["--constant-offset", "0", "w.json", "pk.ark"])).unwrap();
+        assert_eq!(cli.constant_offset, Some(0));
+        assert!(parse_args(&args(&["--constant-offset", "x", "w.json", "pk.ark"])).is_err());
+        assert!(parse_args(&args(&["--constant-offset", "2", "w.json", "pk.ark"])).is_err());
+    }
+
+    #[test]
+    fn test_extract_public_outputs_then_inputs() {
+        // circom layout: [1, out0, out1, in0, private]
+        let w = args(&["0x01", "0x0a", "0x0b", "0x0c", "0x0d"]);
+        assert_eq!(
+            extract_public_signals(&w, 1, 2, 1, false).unwrap(),
+            args(&["0x0a", "0x0b", "0x0c"])
+        );
+        let cli = parse_args(&args(&["--public-outputs", "2", "w.json", "pk.ark", "1"])).unwrap();
+        assert_eq!(cli.public_outputs, Some(2));
+        assert!(parse_args(&args(&["--public-outputs", "x", "w.json", "pk.ark"])).is_err());
+        let input: WitnessInput =
+            serde_json::from_str(r#"{"witness":[],"public_outputs_count":2}"#).unwrap();
+        assert_eq!(input.public_outputs_count, Some(2));
+    }
+
+    #[test]
+    fn test_count_reduced_witness_values() {
+        let over = format!("0x02{}", "ff".repeat(31));
+        let w = args(&["0x01", &over, "0x0a", &over]);
+        assert_eq!(count_reduced(&w).unwrap(), 2);
+        assert_eq!(count_reduced(&w[..1]).unwrap(), 0);
+        assert!(count_reduced(&args(&["0x01", "0xzz"]))
+            .unwrap_err()
+            .starts_with("witness[1]: "));
+    }
+
+    #[test]
+    fn test_strict_rejects_overlong_hex_entry() {
+        let doubled = format!("0x{}", "0a".repeat(64));
+        let err = check_hex_lengths(&args(&["0x01", &doubled])).unwrap_err();
+        assert!(err.contains("entry 1 has 128 hex digits"), "{err}");
+        assert!(check_hex_lengths(&args(&["0x01", &format!("0x{}", "ff".repeat(32))])).is_ok());
+    }
+
+    #[test]
+    fn test_strict_rejects_over_modulus_signal() {
+        // r + 1 in little-endian: parses (reduced) to 1.
+        let mut r_plus_one =
+            hex::decode("30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000002")
+                .unwrap();
+        r_plus_one.reverse();
+        let over = format!("0x{}", hex::encode(r_plus_one));
+        let w = vec!["0x01".to_string(), over.clone()];
+
+        assert_eq!(
+            extract_public_signals(&w, 1, 0, 1, false).unwrap(),
+            vec![over]
+        );
+        let err = extract_public_signals(&w, 1, 0, 1, true).unwrap_err();
+        assert!(err.contains("not a canonical field element"));
+    }
+
+    #[cfg(feature = "config")]
+    #[test]
+    fn test_config_supplies_key_and_flags_override_defaults() {
+        let dir = std::env::temp_dir().join(format!("cli-config-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let config = dir.join("orbinum.toml");
+        std::fs::write(
+            &config,
+            "[keys]\ntransfer = \"transfer_pk.ark\"\n\n[defaults]\nwitness_endian = \"be\"\nstrict = true\n",
+        )
+        .unwrap();
+        let config = config.to_str().unwrap();
+
+        let cli = parse_args(&args(&[
+            "--circuit",
+            "transfer",
+            "w.json",
+            "--config",
+            config,
+        ]))
+        .unwrap();
+        assert_eq!(
+            cli.proving_key_path.as_deref(),
+            dir.join("transfer_pk.ark").to_str()
+        );
+        assert_eq!(cli.witness_endian, WitnessEndian::Be);
+        assert!(cli.strict);
+
+        let cli = parse_args(&args(&[
+            "--config",
+            config,
+            "--witness-endian",
+            "le",
+            "w.json",
+            "pk.ark",
+        ]))
+        .unwrap();
+        assert_eq!(cli.proving_key_path.as_deref(), Some("pk.ark"));
+        assert_eq!(cli.witness_endian, WitnessEndian::Le);
+
+        let err = parse_args(&args(&[
+            "--config",
+            config,
+            "--circuit",
+            "unshield",
+            "w.json",
+        ]))
+        .unwrap_err();
+        assert!(err.contains("No proving key configured for circuit unshield"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(not(feature = "config"))]
+    #[test]
+    fn test_config_flag_requires_feature() {
+        let err = parse_args(&args(&["--config", "orbinum.toml", "w.json", "pk.ark"])).unwrap_err();
+        assert!(err.contains("--features config"));
+    }
+
+    #[test]
+    fn test_signals_only_needs_no_key_and_emits_no_proof() {
+        let cli = parse_args(&args(&["--signals-only", "w.json", "2"])).unwrap();
+        assert!(cli.proving_key_path.is_none());
+        assert_eq!(cli.num_public_signals, Some(2));
+        assert!(parse_args(&args(&["--signals-only"])).is_err());
+
+        let output = ProofOutput {
+            proof: None,
+            public_signals: PublicSignals::Hex(vec!["0x0a".into()]),
+        };
+        let json = render_output(&output, true).unwrap();
+        assert_eq!(json, r#"{"public_signals":["0x0a"]}"#);
+    }
+
+    #[test]
+    fn test_witness_input_accepts_limb_arrays() {
+        let input: WitnessInput =
+            serde_json::from_str(r#"{"witness":["0x01",[15,0,0,0]]}"#).unwrap();
+        let hex: Vec<String> = input
+            .witness
+            .into_iter()
+            .map(|e| e.into_hex().unwrap())
+            .collect();
+        assert_eq!(hex[1], format!("0x0f{}", "00".repeat(31)));
+    }
+
+    #[test]
+    fn test_check_expected_signals_accepts_hex_and_decimal() {
+        let actual = vec![format!("0x0f{}", "00".repeat(31))];
+        check_expected_signals(&actual, &args(&["15"])).unwrap();
+        check_expected_signals(&actual, &args(&["0x0f"])).unwrap();
+        assert!(check_expected_signals(&actual, &args(&["16"])).is_err());
+        assert!(check_expected_signals(&actual, &args(&["15", "1"])).is_err());
+    }
+
+    #[test]
+    fn test_verify_usage_errors() {
+        assert!(run_verify(&args(&["proof.json"])).is_err());
+        assert!(run_verify(&args(&["p.json", "vk.bin", "--expect"])).is_err());
+    }
+
+    #[test]
+    fn test_diff_witness_reports_differing_index() {
+        let (a, b) = (
+            "/tmp/test_cli_diff_witness_a.json",
+            "/tmp/test_cli_diff_witness_b.json",
+        );
+        std::fs::write(a, r#"{"witness":["0x01","0x21","0x03","0x0b"]}"#).unwrap();
+        std::fs::write(b, r#"{"witness":["0x01","0x21",[4,0,0,0],"0x0b"]}"#).unwrap();
+        let diff = run_diff_witness(&args(&[a, b])).unwrap();
+        let same = run_diff_witness(&args(&[a, a])).unwrap();
+        let _ = std::fs::remove_file(a);
+        let _ = std::fs::remove_file(b);
+
+        assert_eq!(diff.len(), 1);
+        assert_eq!(diff[0].index, 2);
+        assert_eq!(diff[0].a, format!("0x03{}", "00".repeat(31)));
+        assert_eq!(diff[0].b, format!("0x04{}", "00".repeat(31)));
+        assert!(same.is_empty());
+        assert!(run_diff_witness(&args(&[a])).is_err());
+    }
+
+    #[test]
+    fn test_parse_batch_args() {
+        let batch = parse_batch_args(&args(&[
+            "jobs.jsonl",
+            "--key",
+            "unshield=u.ark",
+            "--split-by-circuit",
+            "out",
+        ]))
+        .unwrap();
+        assert_eq!(batch.jobs_path, "jobs.jsonl");
+        assert_eq!(batch.keys[&CircuitType::Unshield], "u.ark");
+        assert_eq!(batch.split_dir, Some(PathBuf::from("out")));
+        assert!(parse_batch_args(&args(&["jobs.jsonl", "--key", "unshield"])).is_err());
+        assert!(parse_batch_args(&args(&["jobs.jsonl", "--key", "../x=u.ark"])).is_err());
+        assert!(parse_batch_args(&args(&[])).is_err());
+    }
+
+    #[test]
+    fn test_witness_endian_changes_interpretation() {
+        let cli = parse_args(&args(&["--witness-endian", "be", "w.json", "pk.ark"])).unwrap();
+        assert_eq!(cli.witness_endian, WitnessEndian::Be);
+        assert!(parse_args(&args(&["--witness-endian", "middle", "w.json", "pk.ark"])).is_err());
+
+        // 0x0100 is 1 read little-endian, 256 read big-endian.
+        let expected = ark_bn254::Fr::from(256u64);
+        let raw = || WitnessEntry::Hex("0x0100".into());
+        let le = hex_to_field(&entry_to_le_hex(raw(), WitnessEndian::Le).unwrap()).unwrap();
+        let be = hex_to_field(&entry_to_le_hex(raw(), WitnessEndian::Be).unwrap()).unwrap();
+        assert_ne!(le, be);
+        assert_eq!(be, expected);
+        assert_eq!(be, groth16_proofs::hex_to_field_be("0x0100").unwrap());
+        assert_ne!(le, expected);
+    }
+
+    #[test]
+    fn test_canonical_output_is_byte_stable() {
+        let a = render_output(&sample_output(), true).unwrap();
+        let b = render_output(&sample_output(), true).unwrap();
+        assert_eq!(a.as_bytes(), b.as_bytes());
+        assert_eq!(a, r#"{"proof":"0xabcd","public_signals":["0x01","0x02"]}"#);
+
+        // `DualSignal` declares `hex` before `dec`; canonical output sorts them.
+        let hex = format!("0x0a{}", "00".repeat(31));
+        let dual = ProofOutput {
+            proof: Some("0xabcd".into()),
+            public_signals: PublicSignals::Dual(dual_signals(vec![hex.clone()]).unwrap()),
+        };
+        assert_eq!(
+            render_output(&dual, false).unwrap(),
+            format!(r#"{{"proof":"0xabcd","public_signals":[{{"hex":"{hex}","dec":"10"}}]}}"#)
+        );
+        assert_eq!(
+            render_output(&dual, true).unwrap(),
+            format!(r#"{{"proof":"0xabcd","public_signals":[{{"dec":"10","hex":"{hex}"}}]}}"#)
+        );
+    }
+
+    #[test]
+    fn test_log_format_flags() {
+        let cli = parse_args(&args(&[
+            "--quiet",
+            "--log-format",
+            "json",
+            "w.json",
+            "pk.ark",
+        ]))
+        .unwrap();
+        assert!(cli.progress.quiet);
+        assert_eq!(cli.progress.format, LogFormat::Json);
+        assert_eq!(
+            parse_args(&args(&["w.json", "pk.ark"]))
+                .unwrap()
+                .progress
+                .format,
+            LogFormat::Emoji
+        );
+        let err = parse_args(&args(&["--log-format", "xml", "w.json", "pk.ark"])).unwrap_err();
+        assert!(err.contains("expected plain, json or emoji"), "{err}");
+        assert!(parse_args(&args(&["w.json", "pk.ark", "--log-format"])).is_err());
+    }
+
+    #[test]
+    fn test_progress_lines_per_format() {
+        let line = |quiet, format| {
+            Progress { quiet, format }.line(
+                "info",
+                "prove_done",
+                "✅",
+                "Proof generated: 128 bytes",
+            )
+        };
+        assert_eq!(
+            line(false, LogFormat::Emoji).unwrap(),
+            "✅ Proof generated: 128 bytes"
+        );
+        assert_eq!(
+            line(false, LogFormat::Plain).unwrap(),
+            "info: Proof generated: 128 bytes"
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&line(false, LogFormat::Json).unwrap()).unwrap();
+        assert_eq!(json["level"], "info");
+        assert_eq!(json["event"], "prove_done");
+        assert_eq!(json["message"], "Proof generated: 128 bytes");
+        assert!(line(true, LogFormat::Json).is_none());
+
+        let error = Progress {
+            quiet: true,
+            format: LogFormat::Json,
+        }
+        .line("error", "prove_failed", "❌", "Proof generation failed")
+        .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&error).unwrap();
+        assert_eq!(json["level"], "error");
+    }
+
+    #[test]
+    fn test_take_progress_flags() {
+        let (progress, rest) = take_progress_flags(&args(&[
+            "a.json",
+            "--quiet",
+            "--log-format",
+            "json",
+            "b.json",
+        ]))
+        .unwrap();
+        assert!(progress.quiet);
+        assert_eq!(progress.format, LogFormat::Json);
+        assert_eq!(rest, args(&["a.json", "b.json"]));
+        assert!(take_progress_flags(&args(&["--log-format"])).is_err());
+        assert!(take_progress_flags(&args(&["--log-format", "xml"])).is_err());
+    }
+
+    /// Checks `value` against the keywords `schemars` emits for [`ProofOutput`]:
+    /// `type`, `properties`, `required`, `items`, `anyOf` and local `$ref`s.
+    #[cfg(feature = "json-schema")]
+    fn conforms(
+        schema: &serde_json::Value,
+        node: &serde_json::Value,
+        value: &serde_json::Value,
+    ) -> bool {
+        use serde_json::Value;
+        if let Some(Value::String(r)) = node.get("$ref") {
+            let name = r.trim_start_matches("#/$defs/");
+            return conforms(schema, &schema["$defs"][name], value);
+        }
+        if let Some(Value::Array(options)) = node.get("anyOf") {
+            return options.iter().any(|o| conforms(schema, o, value));
+        }
+        let type_ok = |t: &Value| match t.as_str().unwrap() {
+            "object" => value.is_object(),
+            "array" => value.is_array(),
+            "string" => value.is_string(),
+            "null" => value.is_null(),
+            other => panic!("unhandled schema type {other}"),
+        };
+        match node.get("type") {
+            Some(Value::Array(types)) if !types.iter().any(type_ok) => return false,
+            Some(t @ Value::String(_)) if !type_ok(t) => return false,
+            _ => {}
+        }
+        if let Some(required) = node.get("required").and_then(Value::as_array) {
+            if required
+                .iter()
+                .any(|k| value.get(k.as_str().unwrap()).is_none())
+            {
+                return false;
+            }
+        }
+        if let (Some(props), Some(obj)) = (node.get("properties"), value.as_object()) {
+            if !obj
+                .iter()
+                .all(|(k, v)| props.get(k).is_some_and(|p| conforms(schema, p, v)))
+            {
+                return false;
+            }
+        }
+        if let (Some(items), Some(arr)) = (node.get("items"), value.as_array()) {
+            return arr.iter().all(|v| conforms(schema, items, v));
+        }
+        true
+    }
+
+    #[cfg(feature = "json-schema")]
+    #[test]
+    fn test_json_schema_validates_real_output() {
+        let schema: serde_json::Value = serde_json::from_str(&output_schema().unwrap()).unwrap();
+        let check = |output: &ProofOutput| {
+            let value = serde_json::from_str(&render_output(output, false).unwrap()).unwrap();
+            conforms(&schema, &schema, &value)
+        };
+        assert!(check(&sample_output()));
+        assert!(check(&ProofOutput {
+            proof: None,
+            public_signals: PublicSignals::Dual(
+                dual_signals(vec![format!("0x0f{}", "00".repeat(31))]).unwrap()
+            ),
+        }));
+
+        let bad = serde_json::json!({"proof": "0xabcd", "public_signals": [1, 2]});
+        assert!(!conforms(&schema, &schema, &bad));
+        assert!(!conforms(
+            &schema,
+            &schema,
+            &serde_json::json!({"proof": "0xabcd"})
+        ));
+    }
+
+    #[cfg(not(feature = "json-schema"))]
+    #[test]
+    fn test_json_schema_requires_feature() {
+        assert!(output_schema().unwrap_err().contains("json-schema"));
+    }
 }
@@ -0,0 +1,65 @@
+//! Minimal HTTP proving service.
+//!
+//! Usage:
+//!   proof-server <config.json> [listen_addr=127.0.0.1:8080]
+//!
+//! Config (JSON): `{"keys": {"unshield": "unshield_pk.ark", ...}, "allowed_circuits": [...]}`
+//! — every key is deserialized once at startup. `allowed_circuits` is optional; requests
+//! for other circuits get `403`. Optional `"canary_witnesses": {"unshield": ["0x01…", ...]}`
+//! replaces the all-zero witness in a circuit's readiness self-test. Key paths ending
+//! in `.zkey` load a snarkjs zkey with its constraint matrices; see
+//! `ServerConfig::load_generators` for what `.ark` keys can prove.
+//!
+//! Endpoint:
+//!   POST /prove  {"circuit": "unshield", "witness": ["0x01...", ...]}
+//!             →  {"proof": "0x…", "public_signals": ["0x…", ...]}
+//!   GET  /ready  →  200 {"ready": true} once every key passes a prove-and-verify
+//!                   self-test, 503 with the failing circuit otherwise (retried on
+//!                   the next call)
+
+use groth16_proofs::server::{router_with_canaries, ServerConfig};
+
+#[tokio::main]
+async fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() < 2 {
+        eprintln!("Usage: proof-server <config.json> [listen_addr=127.0.0.1:8080]");
+        std::process::exit(1);
+    }
+    let addr = args
+        .get(2)
+        .cloned()
+        .unwrap_or_else(|| "127.0.0.1:8080".into());
+
+    let config_json = std::fs::read_to_string(&args[1]).unwrap_or_else(|e| {
+        eprintln!("❌ Failed to read config {}: {e}", args[1]);
+        std::process::exit(1);
+    });
+    let (generators, allowed, canaries) = ServerConfig::from_json(&config_json)
+        .and_then(|c| {
+            Ok((
+                c.load_generators()?,
+                c.allowed_circuits()?,
+                c.canary_witnesses()?,
+            ))
+        })
+        .unwrap_or_else(|e| {
+            eprintln!("❌ {e}");
+            std::process::exit(1);
+        });
+    eprintln!("🔑 Loaded {} proving key(s)", generators.len());
+
+    let listener = tokio::net::TcpListener::bind(&addr)
+        .await
+        .unwrap_or_else(|e| {
+            eprintln!("❌ Failed to bind {addr}: {e}");
+            std::process::exit(1);
+        });
+    eprintln!("🚀 Listening on http://{addr}");
+    axum::serve(
+        listener,
+        router_with_canaries(generators, allowed, canaries),
+    )
+    .await
+    .unwrap_or_else(|e| eprintln!("❌ Server error: {e}"));
+}
@@ -0,0 +1,33 @@
+//! Print the SHA-256 fingerprint of arkworks compressed verifying keys.
+//!
+//! Usage:
+//!   vk-fingerprint <vk.bin> [vk.bin ...]
+//!
+//! Prints one `<fingerprint>  <path>` line per key.
+
+use ark_bn254::Bn254;
+use ark_groth16::VerifyingKey;
+use ark_serialize::CanonicalDeserialize;
+use groth16_proofs::vk_fingerprint;
+use std::{env, fs, process};
+
+fn main() {
+    let paths: Vec<String> = env::args().skip(1).collect();
+    if paths.is_empty() {
+        eprintln!("Usage: vk-fingerprint <vk.bin> [vk.bin ...]");
+        process::exit(1);
+    }
+
+    for path in &paths {
+        let bytes = fs::read(path).unwrap_or_else(|e| {
+            eprintln!("❌ Failed to read {path}: {e}");
+            process::exit(1);
+        });
+        let vk =
+            VerifyingKey::<Bn254>::deserialize_compressed(bytes.as_slice()).unwrap_or_else(|e| {
+                eprintln!("❌ Invalid verifying key {path}: {e}");
+                process::exit(1);
+            });
+        println!("{}  {path}", vk_fingerprint(&vk));
+    }
+}
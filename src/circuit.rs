@@ -1,6 +1,10 @@
+use std::fmt;
+
 use ark_bn254::Fr as Bn254Fr;
 use ark_relations::r1cs::ConstraintSynthesizer;
 
+use crate::field::to_le_bytes_32;
+
 /// Arkworks `ConstraintSynthesizer` wrapper for a pre-computed Circom witness.
 ///
 /// The proving key already encodes all constraints from the Circom compilation.
@@ -16,6 +20,26 @@ pub struct WitnessCircuit {
     pub num_public_signals: usize,
 }
 
+/// Shows the witness length and public signals; private entries are `<redacted>`
+/// so logging a circuit never leaks secrets.
+impl fmt::Debug for WitnessCircuit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let public: Vec<String> = self
+            .witness
+            .iter()
+            .skip(1)
+            .take(self.num_public_signals)
+            .map(|v| format!("0x{}", hex::encode(to_le_bytes_32(v))))
+            .collect();
+        f.debug_struct("WitnessCircuit")
+            .field("len", &self.witness.len())
+            .field("num_public_signals", &self.num_public_signals)
+            .field("public_signals", &public)
+            .field("private", &format_args!("<redacted>"))
+            .finish()
+    }
+}
+
 impl ConstraintSynthesizer<Bn254Fr> for WitnessCircuit {
     fn generate_constraints(
         self,
@@ -52,6 +76,26 @@ mod tests {
         assert_eq!(circuit.num_public_signals, 1);
     }
 
+    #[test]
+    fn test_debug_redacts_private_witness() {
+        let secret = 987_654_321u64;
+        let circuit = WitnessCircuit {
+            witness: vec![
+                Bn254Fr::from(1u64),
+                Bn254Fr::from(15u64),
+                Bn254Fr::from(secret),
+            ],
+            num_public_signals: 1,
+        };
+        let debug = format!("{circuit:?}");
+        let secret_hex = hex::encode(to_le_bytes_32(&Bn254Fr::from(secret)));
+        assert!(!debug.contains(&secret_hex));
+        assert!(!debug.contains(&secret.to_string()));
+        assert!(debug.contains("<redacted>"));
+        assert!(debug.contains("len: 3"));
+        assert!(debug.contains(&format!("0x0f{}", "00".repeat(31))));
+    }
+
     #[test]
     fn test_circuit_empty_witness() {
         let circuit = WitnessCircuit {
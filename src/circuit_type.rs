@@ -0,0 +1,145 @@
+use std::fmt;
+use std::str::FromStr;
+
+use ark_bn254::Fr as Bn254Fr;
+
+use crate::field::to_le_bytes_32;
+
+/// Orbinum circuits and their public-signal arity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CircuitType {
+    Unshield,
+    Transfer,
+    Disclosure,
+}
+
+impl CircuitType {
+    /// Every supported circuit type.
+    pub fn all() -> &'static [CircuitType] {
+        &[
+            CircuitType::Unshield,
+            CircuitType::Transfer,
+            CircuitType::Disclosure,
+        ]
+    }
+
+    /// Lowercase circuit name, as used in artifact file names.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CircuitType::Unshield => "unshield",
+            CircuitType::Transfer => "transfer",
+            CircuitType::Disclosure => "disclosure",
+        }
+    }
+
+    /// Number of public signals (witness indices `1..=n`) the circuit exposes.
+    pub fn num_public_signals(&self) -> usize {
+        match self {
+            CircuitType::Unshield => 5,
+            CircuitType::Transfer => 5,
+            CircuitType::Disclosure => 4,
+        }
+    }
+
+    /// Hex-encoded (`0x`, 32-byte LE) public signals for this circuit, read from
+    /// witness indices `1..=num_public_signals()`.
+    pub fn public_signals(&self, witness: &[Bn254Fr]) -> Result<Vec<String>, String> {
+        let n = self.num_public_signals();
+        if witness.len() <= n {
+            return Err(format!(
+                "{} witness needs more than {n} elements, got {}",
+                self.as_str(),
+                witness.len()
+            ));
+        }
+        Ok(witness[1..=n]
+            .iter()
+            .map(|f| format!("0x{}", hex::encode(to_le_bytes_32(f))))
+            .collect())
+    }
+}
+
+/// Writes [`as_str`](CircuitType::as_str), exactly what [`FromStr`] accepts.
+impl fmt::Display for CircuitType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for CircuitType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CircuitType::all()
+            .iter()
+            .copied()
+            .find(|ct| ct.as_str() == s)
+            .ok_or_else(|| format!("Unknown circuit type: {s}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str_known_types() {
+        assert_eq!(
+            CircuitType::from_str("unshield").unwrap(),
+            CircuitType::Unshield
+        );
+        assert_eq!(
+            CircuitType::from_str("transfer").unwrap(),
+            CircuitType::Transfer
+        );
+        assert_eq!(
+            CircuitType::from_str("disclosure").unwrap(),
+            CircuitType::Disclosure
+        );
+    }
+
+    #[test]
+    fn test_display_round_trips_with_from_str() {
+        for &ct in CircuitType::all() {
+            assert_eq!(CircuitType::from_str(&ct.to_string()), Ok(ct));
+        }
+        assert_eq!(CircuitType::Unshield.to_string(), "unshield");
+    }
+
+    #[test]
+    fn test_from_str_unknown_type() {
+        let err = CircuitType::from_str("shield").unwrap_err();
+        assert!(err.contains("Unknown circuit type"));
+    }
+
+    #[test]
+    fn test_public_signal_counts() {
+        assert_eq!(CircuitType::Unshield.num_public_signals(), 5);
+        assert_eq!(CircuitType::Transfer.num_public_signals(), 5);
+        assert_eq!(CircuitType::Disclosure.num_public_signals(), 4);
+    }
+
+    #[test]
+    fn test_public_signals_per_circuit() {
+        for &ct in CircuitType::all() {
+            let n = ct.num_public_signals();
+            let witness: Vec<Bn254Fr> = (0..=n as u64 + 2).map(Bn254Fr::from).collect();
+            let signals = ct.public_signals(&witness).unwrap();
+            assert_eq!(signals.len(), n);
+            assert_eq!(
+                signals[0],
+                "0x0100000000000000000000000000000000000000000000000000000000000000"
+            );
+            assert!(signals[n - 1].starts_with(&format!("0x{:02x}", n)));
+        }
+    }
+
+    #[test]
+    fn test_public_signals_witness_too_short() {
+        for &ct in CircuitType::all() {
+            let witness = vec![Bn254Fr::from(1u64); ct.num_public_signals()];
+            let err = ct.public_signals(&witness).unwrap_err();
+            assert!(err.contains(ct.as_str()));
+        }
+    }
+}
@@ -1,9 +1,13 @@
-use ark_bn254::{Bn254, Fq, Fq2, G1Affine, G2Affine};
-use ark_groth16::Proof as ArkProof;
-use ark_serialize::CanonicalSerialize;
+use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G2Affine};
+use ark_ff::PrimeField;
+use ark_groth16::{Groth16, Proof as ArkProof, VerifyingKey};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_snark::SNARK;
+use num_bigint::BigUint;
 
+use crate::curve::strip_vk_tag;
 use crate::error::ProofError;
-use crate::field::from_decimal_str;
+use crate::field::{from_decimal_str, to_decimal_str};
 
 #[derive(serde::Deserialize)]
 struct SnarkjsProof {
@@ -70,13 +74,252 @@ pub fn compress_snarkjs_proof(proof_json: &str) -> Result<Vec<u8>, ProofError> {
     Ok(compressed)
 }
 
+/// snarkjs `proof.json` layout, in snarkjs' key order.
+#[derive(serde::Serialize)]
+struct SnarkjsProofOut {
+    pi_a: [String; 3],
+    pi_b: [[String; 2]; 3],
+    pi_c: [String; 3],
+    protocol: &'static str,
+    curve: &'static str,
+}
+
+/// Serialize like snarkjs' `JSON.stringify(value, null, 1)`.
+fn to_snarkjs_json<T: serde::Serialize>(value: &T) -> Result<String, String> {
+    let mut out = Vec::new();
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(b" ");
+    value
+        .serialize(&mut serde_json::Serializer::with_formatter(
+            &mut out, formatter,
+        ))
+        .map_err(|e| e.to_string())?;
+    String::from_utf8(out).map_err(|e| e.to_string())
+}
+
+/// Render a compressed proof as a snarkjs `proof.json` (affine points with `z = 1`).
+///
+/// Together with [`write_public_json`] and a snarkjs `verification_key.json`, this
+/// gives the file set `snarkjs groth16 verify` expects.
+pub fn proof_to_snarkjs_json(proof_bytes: &[u8]) -> Result<String, String> {
+    let proof = ArkProof::<Bn254>::deserialize_compressed(proof_bytes)
+        .map_err(|e| format!("Failed to deserialize proof: {e}"))?;
+    to_snarkjs_json(&SnarkjsProofOut {
+        pi_a: g1_to_snarkjs(&proof.a),
+        pi_b: g2_to_snarkjs(&proof.b),
+        pi_c: g1_to_snarkjs(&proof.c),
+        protocol: "groth16",
+        curve: "bn128",
+    })
+}
+
+/// Write `signals` to `out_path` as the decimal string array of a snarkjs `public.json`.
+pub fn write_public_json(signals: &[Fr], out_path: &str) -> Result<(), String> {
+    let decimals: Vec<String> = signals.iter().map(to_decimal_str).collect();
+    let json = to_snarkjs_json(&decimals)?;
+    std::fs::write(out_path, json + "\n").map_err(|e| format!("Failed to write {out_path}: {e}"))
+}
+
+/// snarkjs `verification_key.json` layout, in snarkjs' key order.
+#[derive(serde::Serialize)]
+struct SnarkjsVkOut {
+    protocol: &'static str,
+    curve: &'static str,
+    #[serde(rename = "nPublic")]
+    n_public: usize,
+    vk_alpha_1: [String; 3],
+    vk_beta_2: [[String; 2]; 3],
+    vk_gamma_2: [[String; 2]; 3],
+    vk_delta_2: [[String; 2]; 3],
+    #[serde(rename = "IC")]
+    ic: Vec<[String; 3]>,
+}
+
+fn g1_to_snarkjs(p: &G1Affine) -> [String; 3] {
+    [to_decimal_str(&p.x), to_decimal_str(&p.y), "1".into()]
+}
+
+fn g2_to_snarkjs(p: &G2Affine) -> [[String; 2]; 3] {
+    [
+        [to_decimal_str(&p.x.c0), to_decimal_str(&p.x.c1)],
+        [to_decimal_str(&p.y.c0), to_decimal_str(&p.y.c1)],
+        ["1".into(), "0".into()],
+    ]
+}
+
+/// Render arkworks compressed verifying key bytes (optionally curve-tagged) as a
+/// snarkjs `verification_key.json`; the inverse of [`vk_from_snarkjs_json`].
+///
+/// `vk_alphabeta_12` is omitted: snarkjs recomputes it and does not need it to
+/// verify.
+pub fn vk_to_snarkjs_json(vk_bytes: &[u8]) -> Result<String, String> {
+    let vk = VerifyingKey::<Bn254>::deserialize_compressed(
+        strip_vk_tag(vk_bytes).map_err(|e| e.to_string())?,
+    )
+    .map_err(|e| format!("Failed to deserialize verifying key: {e}"))?;
+    to_snarkjs_json(&SnarkjsVkOut {
+        protocol: "groth16",
+        curve: "bn128",
+        n_public: vk.gamma_abc_g1.len().saturating_sub(1),
+        vk_alpha_1: g1_to_snarkjs(&vk.alpha_g1),
+        vk_beta_2: g2_to_snarkjs(&vk.beta_g2),
+        vk_gamma_2: g2_to_snarkjs(&vk.gamma_g2),
+        vk_delta_2: g2_to_snarkjs(&vk.delta_g2),
+        ic: vk.gamma_abc_g1.iter().map(g1_to_snarkjs).collect(),
+    })
+}
+
+#[derive(serde::Deserialize)]
+struct SnarkjsVk {
+    #[serde(default)]
+    curve: Option<String>,
+    #[serde(default, rename = "nPublic")]
+    n_public: Option<usize>,
+    vk_alpha_1: Vec<String>,
+    vk_beta_2: Vec<Vec<String>>,
+    vk_gamma_2: Vec<Vec<String>>,
+    vk_delta_2: Vec<Vec<String>>,
+    #[serde(rename = "IC")]
+    ic: Vec<Vec<String>>,
+}
+
+/// Parse a snarkjs `verification_key.json` into an arkworks verifying key.
+///
+/// Points are snarkjs projective `[x, y, z]` with `z = 1` (`[[x.c0, x.c1], [y.c0,
+/// y.c1], [1, 0]]` for G2), and must lie on the curve in the prime-order subgroup.
+/// `nPublic`, when present, must match the IC length.
+pub fn vk_from_snarkjs_json(json: &str) -> Result<VerifyingKey<Bn254>, String> {
+    let vk: SnarkjsVk = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    if let Some(curve) = vk.curve.as_deref() {
+        if curve != "bn128" && curve != "bn254" {
+            return Err(format!("Unsupported curve {curve} (expected bn128)"));
+        }
+    }
+    if vk.ic.is_empty() {
+        return Err("IC must contain at least one point".into());
+    }
+    if let Some(n) = vk.n_public {
+        if n + 1 != vk.ic.len() {
+            return Err(format!("nPublic is {n} but IC has {} points", vk.ic.len()));
+        }
+    }
+    Ok(VerifyingKey {
+        alpha_g1: vk_g1(&vk.vk_alpha_1, "vk_alpha_1")?,
+        beta_g2: vk_g2(&vk.vk_beta_2, "vk_beta_2")?,
+        gamma_g2: vk_g2(&vk.vk_gamma_2, "vk_gamma_2")?,
+        delta_g2: vk_g2(&vk.vk_delta_2, "vk_delta_2")?,
+        gamma_abc_g1: vk
+            .ic
+            .iter()
+            .enumerate()
+            .map(|(i, p)| vk_g1(p, &format!("IC[{i}]")))
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+/// Verify a snarkjs `proof.json` against its `public.json` and `verification_key.json`
+/// in one call, without converting anything to arkworks bytes first.
+///
+/// Public signals are snarkjs decimal strings and must be canonical (below the BN254
+/// scalar modulus). Proof points must be affine (`z = 1`) and on the curve. Returns
+/// `Ok(false)` for a well-formed proof that does not verify.
+pub fn verify_all_snarkjs(
+    proof_json: &str,
+    public_json: &str,
+    vk_json: &str,
+) -> Result<bool, String> {
+    let vk = vk_from_snarkjs_json(vk_json).map_err(|e| format!("Verifying key: {e}"))?;
+    let proof: SnarkjsProof =
+        serde_json::from_str(proof_json).map_err(|e| format!("Proof: {e}"))?;
+    let proof = ArkProof::<Bn254> {
+        a: vk_g1(&proof.pi_a, "pi_a")?,
+        b: vk_g2(&proof.pi_b, "pi_b")?,
+        c: vk_g1(&proof.pi_c, "pi_c")?,
+    };
+    let signals: Vec<String> =
+        serde_json::from_str(public_json).map_err(|e| format!("Public signals: {e}"))?;
+    let inputs = signals
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            let n = BigUint::parse_bytes(s.as_bytes(), 10)
+                .ok_or_else(|| format!("public[{i}]: not a decimal integer: {s}"))?;
+            if n >= Fr::MODULUS.into() {
+                return Err(format!("public[{i}]: {s} is not below the field modulus"));
+            }
+            Ok(Fr::from(n))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if inputs.len() + 1 != vk.gamma_abc_g1.len() {
+        return Err(format!(
+            "expected {} public signals, got {}",
+            vk.gamma_abc_g1.len() - 1,
+            inputs.len()
+        ));
+    }
+    Groth16::<Bn254>::verify(&vk, &inputs, &proof).map_err(|e| e.to_string())
+}
+
+fn vk_fq(s: &str, ctx: &str) -> Result<Fq, String> {
+    from_decimal_str::<Fq>(s).map_err(|e| format!("{ctx}: {e}"))
+}
+
+fn vk_g1(point: &[String], ctx: &str) -> Result<G1Affine, String> {
+    match point {
+        [x, y, z] if z == "1" => {
+            let p = G1Affine::new_unchecked(vk_fq(x, ctx)?, vk_fq(y, ctx)?);
+            if p.is_on_curve() && p.is_in_correct_subgroup_assuming_on_curve() {
+                Ok(p)
+            } else {
+                Err(format!("{ctx}: point is not on the curve"))
+            }
+        }
+        _ => Err(format!("{ctx}: expected [x, y, \"1\"]")),
+    }
+}
+
+fn vk_g2(point: &[Vec<String>], ctx: &str) -> Result<G2Affine, String> {
+    let fq2 = |c: &[String]| match c {
+        [c0, c1] => Ok(Fq2::new(vk_fq(c0, ctx)?, vk_fq(c1, ctx)?)),
+        _ => Err(format!("{ctx}: expected [c0, c1] coordinates")),
+    };
+    match point {
+        [x, y, z] if z.as_slice() == ["1", "0"] => {
+            let p = G2Affine::new_unchecked(fq2(x)?, fq2(y)?);
+            if p.is_on_curve() && p.is_in_correct_subgroup_assuming_on_curve() {
+                Ok(p)
+            } else {
+                Err(format!("{ctx}: point is not on the curve"))
+            }
+        }
+        _ => Err(format!("{ctx}: expected [x, y, [\"1\", \"0\"]]")),
+    }
+}
+
+/// Re-serialize a proof into canonical 128-byte compressed form.
+///
+/// Accepts compressed or uncompressed arkworks encodings; points are validated
+/// (on-curve, correct subgroup) during deserialization. Use before hashing or
+/// deduplicating proofs from untrusted sources.
+pub fn canonicalize_proof(proof_bytes: &[u8]) -> Result<Vec<u8>, ProofError> {
+    let proof = if proof_bytes.len() == ArkProof::<Bn254>::default().uncompressed_size() {
+        ArkProof::<Bn254>::deserialize_uncompressed(proof_bytes)
+    } else {
+        ArkProof::<Bn254>::deserialize_compressed(proof_bytes)
+    }
+    .map_err(|e| ProofError::ProofParse(e.to_string()))?;
+    let mut canonical = Vec::new();
+    proof
+        .serialize_compressed(&mut canonical)
+        .map_err(|e| ProofError::ProofSerialization(e.to_string()))?;
+    Ok(canonical)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use ark_bn254::{G1Projective, G2Projective};
     use ark_ec::{CurveGroup, PrimeGroup};
     use ark_ff::{BigInteger, PrimeField};
-    use num_bigint::BigUint;
 
     fn fq_to_decimal_string(value: Fq) -> String {
         value
@@ -164,9 +407,163 @@ mod tests {
         assert!(err.to_string().contains("pi_b must be a 2x2 matrix"));
     }
 
+    #[test]
+    fn test_canonicalize_canonical_proof_is_noop() {
+        let bytes = compress_snarkjs_proof(&build_valid_snarkjs_proof_json()).unwrap();
+        assert_eq!(canonicalize_proof(&bytes).unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_canonicalize_uncompressed_proof() {
+        let compressed = compress_snarkjs_proof(&build_valid_snarkjs_proof_json()).unwrap();
+        let proof = ArkProof::<Bn254>::deserialize_compressed(&compressed[..]).unwrap();
+        let mut uncompressed = Vec::new();
+        proof.serialize_uncompressed(&mut uncompressed).unwrap();
+        assert_eq!(canonicalize_proof(&uncompressed).unwrap(), compressed);
+    }
+
+    #[test]
+    fn test_canonicalize_rejects_invalid_bytes() {
+        assert!(matches!(
+            canonicalize_proof(&[0xffu8; 128]).unwrap_err(),
+            ProofError::ProofParse(_)
+        ));
+        assert!(canonicalize_proof(b"short").is_err());
+    }
+
     #[test]
     fn test_from_decimal_str_fq_invalid() {
         let err = from_decimal_str::<Fq>("not-a-number").unwrap_err();
         assert!(err.contains("Failed to parse decimal string"));
     }
+
+    #[test]
+    fn test_vk_from_snarkjs_json_matches_zkey_and_verifies() {
+        let vk =
+            vk_from_snarkjs_json(include_str!("../tests/fixtures/multiplier_vk.json")).unwrap();
+        let zkey = include_bytes!("../tests/fixtures/multiplier.zkey");
+        assert_eq!(vk, crate::read_zkey(zkey).unwrap().0.vk);
+
+        let witness = [1u64, 33, 3, 11].map(ark_bn254::Fr::from).to_vec();
+        let (proof, signals) = crate::ProofGenerator::from_zkey_bytes(zkey)
+            .unwrap()
+            .prove_with_public_signals(witness)
+            .unwrap();
+        assert!(crate::verify(&proof, &vk, &signals).unwrap());
+    }
+
+    #[test]
+    fn test_vk_to_snarkjs_json_round_trips_fixture() {
+        let fixture: serde_json::Value =
+            serde_json::from_str(include_str!("../tests/fixtures/multiplier_vk.json")).unwrap();
+        let vk = vk_from_snarkjs_json(&fixture.to_string()).unwrap();
+        let mut vk_bytes = Vec::new();
+        vk.serialize_compressed(&mut vk_bytes).unwrap();
+
+        let json = vk_to_snarkjs_json(&vk_bytes).unwrap();
+        assert_eq!(vk_from_snarkjs_json(&json).unwrap(), vk);
+        let exported: serde_json::Value = serde_json::from_str(&json).unwrap();
+        for key in [
+            "nPublic",
+            "vk_alpha_1",
+            "vk_beta_2",
+            "vk_gamma_2",
+            "vk_delta_2",
+            "IC",
+        ] {
+            assert_eq!(exported[key], fixture[key], "{key}");
+        }
+        assert!(vk_to_snarkjs_json(&vk_bytes[1..]).is_err());
+    }
+
+    #[test]
+    fn test_vk_from_snarkjs_json_rejects_bad_input() {
+        let fixture: serde_json::Value =
+            serde_json::from_str(include_str!("../tests/fixtures/multiplier_vk.json")).unwrap();
+        let with = |key: &str, value: serde_json::Value| {
+            let mut json = fixture.clone();
+            json[key] = value;
+            vk_from_snarkjs_json(&json.to_string()).unwrap_err()
+        };
+        assert!(with("nPublic", 2.into()).contains("nPublic is 2"));
+        assert!(with("curve", "bls12381".into()).contains("Unsupported curve"));
+        assert!(with("vk_alpha_1", serde_json::json!(["1", "3", "1"])).contains("not on the curve"));
+        assert!(with("vk_alpha_1", serde_json::json!(["x", "3", "1"])).contains("vk_alpha_1"));
+        assert!(with("IC", serde_json::json!([])).contains("at least one"));
+        assert!(vk_from_snarkjs_json("{}").is_err());
+    }
+
+    #[test]
+    fn test_verify_all_snarkjs_fixture_set() {
+        let proof = include_str!("../tests/fixtures/multiplier_proof.json");
+        let public = include_str!("../tests/fixtures/multiplier_public.json");
+        let vk = include_str!("../tests/fixtures/multiplier_vk.json");
+        assert!(verify_all_snarkjs(proof, public, vk).unwrap());
+        assert!(!verify_all_snarkjs(proof, r#"["34"]"#, vk).unwrap());
+    }
+
+    #[test]
+    fn test_verify_all_snarkjs_rejects_bad_input() {
+        let proof = include_str!("../tests/fixtures/multiplier_proof.json");
+        let vk = include_str!("../tests/fixtures/multiplier_vk.json");
+        let modulus = BigUint::from(Fr::MODULUS).to_string();
+        assert!(verify_all_snarkjs(proof, &format!(r#"["{modulus}"]"#), vk)
+            .unwrap_err()
+            .contains("not below the field modulus"));
+        assert!(verify_all_snarkjs(proof, r#"["33", "1"]"#, vk)
+            .unwrap_err()
+            .contains("expected 1 public signals, got 2"));
+        assert!(verify_all_snarkjs(proof, r#"["0x21"]"#, vk).is_err());
+
+        let mut bad: serde_json::Value = serde_json::from_str(proof).unwrap();
+        bad["pi_a"] = serde_json::json!(["1", "3", "1"]);
+        assert!(verify_all_snarkjs(&bad.to_string(), r#"["33"]"#, vk)
+            .unwrap_err()
+            .contains("pi_a: point is not on the curve"));
+        assert!(verify_all_snarkjs(proof, r#"["33"]"#, "{}")
+            .unwrap_err()
+            .starts_with("Verifying key:"));
+    }
+
+    #[test]
+    fn test_write_public_json_matches_snarkjs_format() {
+        let path = "/tmp/test_write_public_json.json";
+        let signals = [Fr::from(33u64), -Fr::from(1u64)];
+        write_public_json(&signals, path).unwrap();
+        let content = std::fs::read_to_string(path).unwrap();
+        let _ = std::fs::remove_file(path);
+
+        let parsed: Vec<String> = serde_json::from_str(&content).unwrap();
+        let r_minus_one = (BigUint::from(Fr::MODULUS) - 1u32).to_string();
+        assert_eq!(parsed, ["33".to_string(), r_minus_one]);
+
+        write_public_json(&signals[..1], path).unwrap();
+        let content = std::fs::read_to_string(path).unwrap();
+        let _ = std::fs::remove_file(path);
+        assert_eq!(
+            content,
+            include_str!("../tests/fixtures/multiplier_public.json")
+        );
+    }
+
+    #[test]
+    fn test_snarkjs_artifact_set_verifies() {
+        let zkey = include_bytes!("../tests/fixtures/multiplier.zkey");
+        let witness = [1u64, 33, 3, 11].map(Fr::from).to_vec();
+        let proof = crate::ProofGenerator::from_zkey_bytes(zkey)
+            .unwrap()
+            .prove(witness.clone())
+            .unwrap();
+
+        let proof_json = proof_to_snarkjs_json(&proof).unwrap();
+        assert_eq!(compress_snarkjs_proof(&proof_json).unwrap(), proof);
+        let path = "/tmp/test_snarkjs_artifact_set_public.json";
+        write_public_json(&witness[1..2], path).unwrap();
+        let public_json = std::fs::read_to_string(path).unwrap();
+        let _ = std::fs::remove_file(path);
+
+        let vk = include_str!("../tests/fixtures/multiplier_vk.json");
+        assert!(verify_all_snarkjs(&proof_json, &public_json, vk).unwrap());
+        assert!(proof_to_snarkjs_json(b"short").is_err());
+    }
 }
@@ -0,0 +1,158 @@
+use ark_bn254::{Bn254, Fr as Bn254Fr};
+use ark_groth16::VerifyingKey;
+use ark_serialize::CanonicalSerialize;
+use sha2::{Digest, Sha256};
+
+use crate::circuit_type::CircuitType;
+use crate::field::to_le_bytes_32;
+
+/// SHA-256 commitment to a witness, for audit logs that must not contain secrets.
+///
+/// The hash input is the concatenation of every element as a 32-byte little-endian
+/// word, so the commitment binds both the values and their order.
+/// Returns `0x`-prefixed hex.
+pub fn witness_commitment(witness: &[Bn254Fr]) -> String {
+    finalize(Sha256::new(), witness)
+}
+
+/// Domain-separated variant of [`witness_commitment`].
+///
+/// The hash input is `len(domain) as u64 LE || domain || witness words`. The length
+/// prefix keeps domains from bleeding into the witness bytes, so commitments made
+/// under different domains (e.g. per circuit type) cannot collide. The result never
+/// equals the untagged commitment, even for an empty domain.
+pub fn witness_commitment_tagged(witness: &[Bn254Fr], domain: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update((domain.len() as u64).to_le_bytes());
+    hasher.update(domain);
+    finalize(hasher, witness)
+}
+
+/// SHA-256 fingerprint of a verifying key's compressed arkworks serialization.
+///
+/// Equals the SHA-256 of the `.bin` produced by `convert-vk`. Returns `0x`-prefixed hex.
+pub fn vk_fingerprint(vk: &VerifyingKey<Bn254>) -> String {
+    let mut bytes = Vec::new();
+    vk.serialize_compressed(&mut bytes)
+        .expect("serializing to a Vec cannot fail");
+    format!("0x{}", hex::encode(Sha256::digest(&bytes)))
+}
+
+/// Deterministic ID for a proving request, for deduplication and cache lookups
+/// before proving.
+///
+/// SHA-256 over the length-prefixed (`u64` LE) circuit name, witness commitment and
+/// proving-key fingerprint. Independent of the prover's RNG, so the same request
+/// always maps to the same ID even though the proofs themselves differ.
+pub fn proof_id(circuit: CircuitType, witness: &[Bn254Fr], pk_fingerprint: &str) -> String {
+    let mut hasher = Sha256::new();
+    for part in [
+        circuit.as_str(),
+        &witness_commitment(witness),
+        pk_fingerprint,
+    ] {
+        hasher.update((part.len() as u64).to_le_bytes());
+        hasher.update(part.as_bytes());
+    }
+    format!("0x{}", hex::encode(hasher.finalize()))
+}
+
+fn finalize(mut hasher: Sha256, witness: &[Bn254Fr]) -> String {
+    for f in witness {
+        hasher.update(to_le_bytes_32(f));
+    }
+    format!("0x{}", hex::encode(hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Vec<Bn254Fr> {
+        (1u64..=5).map(Bn254Fr::from).collect()
+    }
+
+    #[test]
+    fn test_commitment_is_stable() {
+        assert_eq!(witness_commitment(&sample()), witness_commitment(&sample()));
+        assert_eq!(witness_commitment(&sample()).len(), 66);
+    }
+
+    #[test]
+    fn test_commitment_changes_with_any_element() {
+        let base = witness_commitment(&sample());
+        for i in 0..5 {
+            let mut w = sample();
+            w[i] += Bn254Fr::from(1u64);
+            assert_ne!(witness_commitment(&w), base, "index {i}");
+        }
+    }
+
+    #[test]
+    fn test_commitment_binds_order() {
+        let mut w = sample();
+        w.swap(1, 2);
+        assert_ne!(witness_commitment(&w), witness_commitment(&sample()));
+    }
+
+    #[test]
+    fn test_tagged_commitment_differs_by_domain() {
+        let a = witness_commitment_tagged(&sample(), b"orbinum/unshield");
+        let b = witness_commitment_tagged(&sample(), b"orbinum/transfer");
+        assert_ne!(a, b);
+        assert_eq!(a, witness_commitment_tagged(&sample(), b"orbinum/unshield"));
+    }
+
+    #[test]
+    fn test_tagged_commitment_differs_from_untagged() {
+        assert_ne!(
+            witness_commitment_tagged(&sample(), b""),
+            witness_commitment(&sample())
+        );
+    }
+
+    #[test]
+    fn test_tagged_domain_cannot_absorb_witness_word() {
+        // Moving the first witness word into the domain must change the commitment.
+        let w = sample();
+        let mut domain = b"d".to_vec();
+        domain.extend(to_le_bytes_32(&w[0]));
+        assert_ne!(
+            witness_commitment_tagged(&w[1..], &domain),
+            witness_commitment_tagged(&w, b"d")
+        );
+    }
+
+    #[test]
+    fn test_vk_fingerprint_stable_and_distinct() {
+        let (_, vk_a) = crate::test_utils::setup(1);
+        let (_, vk_b) = crate::test_utils::setup(2);
+        let fp = vk_fingerprint(&vk_a);
+        assert_eq!(fp, vk_fingerprint(&vk_a.clone()));
+        assert_eq!(fp.len(), 66);
+        assert_ne!(fp, vk_fingerprint(&vk_b));
+    }
+
+    #[test]
+    fn test_proof_id_is_deterministic() {
+        let id = proof_id(CircuitType::Unshield, &sample(), "0xabc");
+        assert_eq!(id, proof_id(CircuitType::Unshield, &sample(), "0xabc"));
+        assert_eq!(id.len(), 66);
+    }
+
+    #[test]
+    fn test_proof_id_depends_on_every_input() {
+        let id = proof_id(CircuitType::Unshield, &sample(), "0xabc");
+        assert_ne!(id, proof_id(CircuitType::Transfer, &sample(), "0xabc"));
+        assert_ne!(id, proof_id(CircuitType::Unshield, &sample()[1..], "0xabc"));
+        assert_ne!(id, proof_id(CircuitType::Unshield, &sample(), "0xabd"));
+    }
+
+    #[test]
+    fn test_commitment_of_empty_witness_is_sha256_of_nothing() {
+        assert_eq!(
+            witness_commitment(&[]),
+            "0xe3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+}
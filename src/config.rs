@@ -0,0 +1,124 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use crate::circuit_type::CircuitType;
+
+/// Operator configuration, usually `orbinum.toml`:
+///
+/// ```toml
+/// [keys]
+/// transfer = "keys/transfer_pk.ark"
+/// unshield = "keys/unshield_pk.ark"
+///
+/// [defaults]
+/// witness_endian = "le"   # le | be
+/// compress = "zstd"       # zstd | gzip
+/// log_format = "json"     # plain | json
+/// strict = true
+///
+/// [limits]
+/// max_witness_bytes = 16777216
+/// ```
+///
+/// Every section is optional. Defaults are kept as strings so each consumer parses
+/// them into its own option types; the CLI lets its flags override them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Proving key path per circuit name (see [`CircuitType`]).
+    pub keys: BTreeMap<String, PathBuf>,
+    pub defaults: ConfigDefaults,
+    pub limits: ConfigLimits,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigDefaults {
+    pub witness_endian: Option<String>,
+    pub compress: Option<String>,
+    pub log_format: Option<String>,
+    pub strict: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigLimits {
+    /// Largest witness file accepted, in bytes.
+    pub max_witness_bytes: Option<u64>,
+}
+
+impl Config {
+    /// Parse TOML, rejecting unknown sections, fields and circuit names.
+    pub fn from_toml_str(toml: &str) -> Result<Self, String> {
+        let config: Config = toml::from_str(toml).map_err(|e| format!("Invalid config: {e}"))?;
+        for circuit in config.keys.keys() {
+            CircuitType::from_str(circuit).map_err(|e| format!("Invalid config [keys]: {e}"))?;
+        }
+        Ok(config)
+    }
+
+    /// Load a config file. Relative key paths are resolved against the file's
+    /// directory, so the config works from any working directory.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let toml = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read config {}: {e}", path.display()))?;
+        let mut config = Self::from_toml_str(&toml)?;
+        let base = path.parent().unwrap_or(Path::new(""));
+        for key_path in config.keys.values_mut() {
+            if key_path.is_relative() {
+                *key_path = base.join(&*key_path);
+            }
+        }
+        Ok(config)
+    }
+
+    /// Configured proving key path for `circuit`, if any.
+    pub fn key_path(&self, circuit: CircuitType) -> Option<&Path> {
+        self.keys.get(circuit.as_str()).map(PathBuf::as_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_file_resolves_relative_keys() {
+        let dir = std::env::temp_dir().join(format!("orbinum-config-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("orbinum.toml");
+        std::fs::write(
+            &path,
+            "[keys]\ntransfer = \"keys/transfer_pk.ark\"\nunshield = \"/abs/unshield_pk.ark\"\n\n[defaults]\nstrict = true\ncompress = \"zstd\"\n\n[limits]\nmax_witness_bytes = 1024\n",
+        )
+        .unwrap();
+
+        let config = Config::from_file(&path).unwrap();
+        assert_eq!(
+            config.key_path(CircuitType::Transfer),
+            Some(dir.join("keys/transfer_pk.ark").as_path())
+        );
+        assert_eq!(
+            config.key_path(CircuitType::Unshield),
+            Some(Path::new("/abs/unshield_pk.ark"))
+        );
+        assert_eq!(config.key_path(CircuitType::Disclosure), None);
+        assert!(config.defaults.strict);
+        assert_eq!(config.defaults.compress.as_deref(), Some("zstd"));
+        assert_eq!(config.limits.max_witness_bytes, Some(1024));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_config_rejects_unknown_entries() {
+        assert_eq!(Config::from_toml_str("").unwrap(), Config::default());
+        assert!(Config::from_toml_str("[keys]\nmint = \"pk.ark\"")
+            .unwrap_err()
+            .contains("Unknown circuit type: mint"));
+        assert!(Config::from_toml_str("[defaults]\nstrikt = true").is_err());
+    }
+}
@@ -0,0 +1,136 @@
+use crate::error::ProofError;
+
+/// Size of a compressed BN254 Groth16 proof.
+const BN254_PROOF_LEN: usize = 128;
+
+/// One-byte curve discriminant prefixed to tagged proofs and verifying keys.
+///
+/// Tagging lets [`verify`](crate::verify) and [`Verifier`](crate::Verifier) reject an
+/// artifact from another curve with a clear error instead of a point-decoding failure.
+/// Untagged legacy artifacts are still accepted: an artifact is tagged exactly when it
+/// is one byte longer than a valid untagged encoding (129 bytes for a proof; a
+/// compressed BN254 VK is `232 + 32·n` bytes, so a tagged one is `≡ 9 mod 32`).
+/// Migrate by re-writing artifacts with [`tag_artifact`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Curve {
+    Bn254 = 0x01,
+    Bls12_381 = 0x02,
+}
+
+impl Curve {
+    pub fn from_tag(tag: u8) -> Option<Self> {
+        match tag {
+            0x01 => Some(Curve::Bn254),
+            0x02 => Some(Curve::Bls12_381),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Curve::Bn254 => "BN254",
+            Curve::Bls12_381 => "BLS12-381",
+        }
+    }
+}
+
+/// Prefix a serialized proof or verifying key with `curve`'s discriminant.
+pub fn tag_artifact(curve: Curve, bytes: &[u8]) -> Vec<u8> {
+    let mut tagged = Vec::with_capacity(bytes.len() + 1);
+    tagged.push(curve as u8);
+    tagged.extend_from_slice(bytes);
+    tagged
+}
+
+/// Strip a BN254 tag from proof bytes; untagged proofs pass through unchanged.
+pub(crate) fn strip_proof_tag(bytes: &[u8]) -> Result<&[u8], ProofError> {
+    if bytes.len() == BN254_PROOF_LEN + 1 {
+        return strip_tag(bytes, "Proof");
+    }
+    Ok(bytes)
+}
+
+/// Strip a BN254 tag from compressed VK bytes; untagged keys pass through unchanged.
+pub(crate) fn strip_vk_tag(bytes: &[u8]) -> Result<&[u8], ProofError> {
+    if bytes.len() % 32 == 9 {
+        return strip_tag(bytes, "Verifying key");
+    }
+    Ok(bytes)
+}
+
+fn strip_tag<'a>(bytes: &'a [u8], what: &str) -> Result<&'a [u8], ProofError> {
+    match Curve::from_tag(bytes[0]) {
+        Some(Curve::Bn254) => Ok(&bytes[1..]),
+        Some(other) => Err(ProofError::CurveMismatch(format!(
+            "{what} is tagged for {}, expected {}",
+            other.as_str(),
+            Curve::Bn254.as_str()
+        ))),
+        None => Err(ProofError::CurveMismatch(format!(
+            "{what} has unknown curve tag 0x{:02x}",
+            bytes[0]
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{prove, setup};
+    use ark_serialize::CanonicalSerialize;
+
+    fn vk_bytes(vk: &ark_groth16::VerifyingKey<ark_bn254::Bn254>) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        vk.serialize_compressed(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_tagged_and_legacy_artifacts_verify() {
+        let (pk, vk) = setup(1);
+        let (proof, signals) = prove(&pk, 3, 5);
+        let tagged = tag_artifact(Curve::Bn254, &proof);
+        assert!(crate::verify(&proof, &vk, &signals).unwrap());
+        assert!(crate::verify(&tagged, &vk, &signals).unwrap());
+
+        for key in [vk_bytes(&vk), tag_artifact(Curve::Bn254, &vk_bytes(&vk))] {
+            let verifier = crate::Verifier::from_bytes(&key, 1).unwrap();
+            assert!(verifier.verify(&tagged, &signals).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_curve_mismatch_is_rejected_before_decoding() {
+        let (pk, vk) = setup(1);
+        let (proof, signals) = prove(&pk, 3, 5);
+
+        let bls_proof = tag_artifact(Curve::Bls12_381, &proof);
+        let err = crate::verify(&bls_proof, &vk, &signals).unwrap_err();
+        assert!(matches!(err, ProofError::CurveMismatch(_)));
+        assert!(err
+            .to_string()
+            .contains("Proof is tagged for BLS12-381, expected BN254"));
+
+        let bls_vk = tag_artifact(Curve::Bls12_381, &vk_bytes(&vk));
+        let err = crate::Verifier::from_bytes(&bls_vk, 1).err().unwrap();
+        assert!(err
+            .to_string()
+            .contains("Verifying key is tagged for BLS12-381"));
+
+        let mut unknown = tag_artifact(Curve::Bn254, &proof);
+        unknown[0] = 0x7f;
+        assert!(crate::verify(&unknown, &vk, &signals)
+            .unwrap_err()
+            .to_string()
+            .contains("unknown curve tag 0x7f"));
+    }
+
+    #[test]
+    fn test_curve_tag_round_trip() {
+        for curve in [Curve::Bn254, Curve::Bls12_381] {
+            assert_eq!(Curve::from_tag(curve as u8), Some(curve));
+        }
+        assert_eq!(Curve::from_tag(0), None);
+    }
+}
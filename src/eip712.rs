@@ -0,0 +1,123 @@
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::error::ProofError;
+use crate::export::{proof_to_uint256_words, signals_to_uint256_words, CoordinateEndian, G2Order};
+
+/// Name of the EIP-712 struct describing a proof.
+pub const PRIMARY_TYPE: &str = "Groth16Proof";
+
+/// EIP-712 domain the typed data is bound to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Eip712Domain {
+    pub name: String,
+    pub version: String,
+    pub chain_id: u64,
+    /// `0x`-prefixed address of the verifier contract.
+    pub verifying_contract: String,
+}
+
+/// Format a compressed proof and hex-LE public signals as EIP-712 typed data,
+/// ready for `eth_signTypedData_v4`.
+///
+/// The message mirrors the Solidity verifier arguments: `a: uint256[2]`,
+/// `b: uint256[2][2]` (EIP-197 `c1, c0` limb order), `c: uint256[2]` and
+/// `publicSignals: uint256[]`, all as `0x` big-endian words.
+pub fn proof_to_eip712_typed_data(
+    proof_bytes: &[u8],
+    public_signals: &[String],
+    domain: &Eip712Domain,
+) -> Result<Value, ProofError> {
+    let w = proof_to_uint256_words(proof_bytes, G2Order::C1C0, CoordinateEndian::Big)?;
+    let inputs = signals_to_uint256_words(public_signals)?;
+
+    Ok(json!({
+        "types": {
+            "EIP712Domain": [
+                {"name": "name", "type": "string"},
+                {"name": "version", "type": "string"},
+                {"name": "chainId", "type": "uint256"},
+                {"name": "verifyingContract", "type": "address"},
+            ],
+            PRIMARY_TYPE: [
+                {"name": "a", "type": "uint256[2]"},
+                {"name": "b", "type": "uint256[2][2]"},
+                {"name": "c", "type": "uint256[2]"},
+                {"name": "publicSignals", "type": "uint256[]"},
+            ],
+        },
+        "primaryType": PRIMARY_TYPE,
+        "domain": domain,
+        "message": {
+            "a": [w[0], w[1]],
+            "b": [[w[2], w[3]], [w[4], w[5]]],
+            "c": [w[6], w[7]],
+            "publicSignals": inputs,
+        },
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn domain() -> Eip712Domain {
+        Eip712Domain {
+            name: "Orbinum".into(),
+            version: "1".into(),
+            chain_id: 1,
+            verifying_contract: format!("0x{}", "11".repeat(20)),
+        }
+    }
+
+    #[test]
+    fn test_typed_data_shape() {
+        let (pk, _) = crate::test_utils::setup(1);
+        let (proof, signals) = crate::test_utils::prove(&pk, 3, 5);
+        let data = proof_to_eip712_typed_data(&proof, &signals, &domain()).unwrap();
+
+        assert_eq!(data["primaryType"], PRIMARY_TYPE);
+        let fields: Vec<&str> = data["types"][PRIMARY_TYPE]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|f| f["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(fields, ["a", "b", "c", "publicSignals"]);
+        assert!(data["types"]["EIP712Domain"].is_array());
+        assert_eq!(data["domain"]["chainId"], 1);
+        assert_eq!(
+            data["domain"]["verifyingContract"],
+            domain().verifying_contract
+        );
+
+        let message = &data["message"];
+        assert_eq!(message["a"].as_array().unwrap().len(), 2);
+        assert_eq!(message["b"][1].as_array().unwrap().len(), 2);
+        assert_eq!(message["c"].as_array().unwrap().len(), 2);
+        // c = 3 * 5 = 15
+        assert_eq!(
+            message["publicSignals"][0],
+            format!("0x{}0f", "00".repeat(31))
+        );
+    }
+
+    #[test]
+    fn test_typed_data_message_matches_uint256_words() {
+        let (pk, _) = crate::test_utils::setup(1);
+        let (proof, signals) = crate::test_utils::prove(&pk, 2, 7);
+        let data = proof_to_eip712_typed_data(&proof, &signals, &domain()).unwrap();
+        let w = proof_to_uint256_words(&proof, G2Order::C1C0, CoordinateEndian::Big).unwrap();
+        assert_eq!(data["message"]["b"][0][0], w[2]);
+        assert_eq!(data["message"]["c"][1], w[7]);
+    }
+
+    #[test]
+    fn test_typed_data_rejects_bad_proof() {
+        assert!(matches!(
+            proof_to_eip712_typed_data(&[0u8; 10], &[], &domain()),
+            Err(ProofError::ProofParse(_))
+        ));
+    }
+}
@@ -11,6 +11,14 @@ pub enum ProofError {
     NumPublicSignals(String),
     WitnessJsonParse(String),
     SnarkjsProofParse(String),
+    ProofParse(String),
+    VerifyingKeyIo(String),
+    VerifyingKeyParse(String),
+    PublicSignalConversion(String),
+    Verification(String),
+    Cancelled,
+    CurveMismatch(String),
+    Setup(String),
 }
 
 impl fmt::Display for ProofError {
@@ -25,6 +33,18 @@ impl fmt::Display for ProofError {
             ProofError::NumPublicSignals(e) => write!(f, "Invalid num_public_signals: {e}"),
             ProofError::WitnessJsonParse(e) => write!(f, "Failed to parse witness JSON: {e}"),
             ProofError::SnarkjsProofParse(e) => write!(f, "Failed to parse snarkjs proof: {e}"),
+            ProofError::ProofParse(e) => write!(f, "Failed to deserialize proof: {e}"),
+            ProofError::VerifyingKeyIo(e) => write!(f, "Failed to read verifying key: {e}"),
+            ProofError::VerifyingKeyParse(e) => {
+                write!(f, "Failed to deserialize verifying key: {e}")
+            }
+            ProofError::PublicSignalConversion(e) => {
+                write!(f, "Public signal conversion failed: {e}")
+            }
+            ProofError::Verification(e) => write!(f, "Failed to verify proof: {e}"),
+            ProofError::Cancelled => write!(f, "Proof generation was cancelled"),
+            ProofError::CurveMismatch(e) => write!(f, "Curve mismatch: {e}"),
+            ProofError::Setup(e) => write!(f, "Setup failed: {e}"),
         }
     }
 }
@@ -0,0 +1,328 @@
+use ark_bn254::{Bn254, Fq, Fq2, Fr as Bn254Fr, G1Affine, G2Affine};
+use ark_ff::{BigInteger, PrimeField};
+use ark_groth16::Proof as ArkProof;
+use ark_serialize::CanonicalDeserialize;
+
+use crate::error::ProofError;
+use crate::field::from_hex_le;
+
+/// Order in which the two `Fq` components of each G2 `Fq2` coordinate are emitted.
+///
+/// - [`G2Order::C1C0`] — imaginary part first. Expected by the EIP-197 pairing
+///   precompile and therefore by snarkjs `exportSolidityCallData` and the Solidity
+///   verifiers snarkjs / circom generate. This is the default.
+/// - [`G2Order::C0C1`] — real part first. Matches arkworks' in-memory layout and
+///   gnark-style exporters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum G2Order {
+    #[default]
+    C1C0,
+    C0C1,
+}
+
+/// Byte order of each 32-byte coordinate word.
+///
+/// - [`CoordinateEndian::Big`] — most significant byte first, the Solidity / EVM
+///   `uint256` convention. This is the default.
+/// - [`CoordinateEndian::Little`] — least significant byte first, matching
+///   arkworks' serialized limbs.
+///
+/// Only the bytes within each word change; word order is unaffected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CoordinateEndian {
+    #[default]
+    Big,
+    Little,
+}
+
+fn to_word<F: PrimeField>(f: &F, endian: CoordinateEndian) -> String {
+    let bytes = match endian {
+        CoordinateEndian::Big => f.into_bigint().to_bytes_be(),
+        CoordinateEndian::Little => f.into_bigint().to_bytes_le(),
+    };
+    format!("0x{}", hex::encode(bytes))
+}
+
+fn fq2_limbs(f: &Fq2, order: G2Order) -> [Fq; 2] {
+    match order {
+        G2Order::C1C0 => [f.c1, f.c0],
+        G2Order::C0C1 => [f.c0, f.c1],
+    }
+}
+
+/// The `(a, b, c)` points of a compressed proof, decompressed.
+///
+/// Decoding checks that each point is on the curve and in the prime-order subgroup;
+/// these are the points every exporter in this module encodes.
+pub fn proof_points(proof_bytes: &[u8]) -> Result<(G1Affine, G2Affine, G1Affine), String> {
+    let proof = decode_proof(proof_bytes).map_err(|e| e.to_string())?;
+    Ok((proof.a, proof.b, proof.c))
+}
+
+/// `[a.x, a.y, b.x[0], b.x[1], b.y[0], b.y[1], c.x, c.y]` for a compressed proof.
+fn proof_coordinates(proof_bytes: &[u8], order: G2Order) -> Result<[Fq; 8], ProofError> {
+    let ArkProof { a, b, c } = decode_proof(proof_bytes)?;
+    let [bx0, bx1] = fq2_limbs(&b.x, order);
+    let [by0, by1] = fq2_limbs(&b.y, order);
+    Ok([a.x, a.y, bx0, bx1, by0, by1, c.x, c.y])
+}
+
+fn decode_proof(proof_bytes: &[u8]) -> Result<ArkProof<Bn254>, ProofError> {
+    ArkProof::<Bn254>::deserialize_compressed(proof_bytes)
+        .map_err(|e| ProofError::ProofParse(e.to_string()))
+}
+
+/// Expand a compressed proof into eight uint256 words:
+/// `[a.x, a.y, b.x[0], b.x[1], b.y[0], b.y[1], c.x, c.y]`, with the G2 limb
+/// order controlled by `order` and the byte order of each word by `endian`.
+pub fn proof_to_uint256_words(
+    proof_bytes: &[u8],
+    order: G2Order,
+    endian: CoordinateEndian,
+) -> Result<[String; 8], ProofError> {
+    Ok(proof_coordinates(proof_bytes, order)?.map(|f| to_word(&f, endian)))
+}
+
+/// ABI-encode a compressed proof as `uint256[8]` (`abi.encode` of the words from
+/// [`proof_to_uint256_words`] with the EVM's [`G2Order::C1C0`]): 256 bytes, each
+/// word big-endian. Contracts taking the proof as one `bytes` argument recover it
+/// with `abi.decode(proof, (uint256[8]))`.
+pub fn proof_to_abi_bytes(proof_bytes: &[u8]) -> Result<Vec<u8>, String> {
+    let coordinates = proof_coordinates(proof_bytes, G2Order::C1C0).map_err(|e| e.to_string())?;
+    Ok(coordinates
+        .iter()
+        .flat_map(|f| f.into_bigint().to_bytes_be())
+        .collect())
+}
+
+/// Proof portion of the calldata: `["a.x","a.y"],[["b.."],["b.."]],["c.x","c.y"]`.
+pub(crate) fn proof_points_calldata(
+    proof_bytes: &[u8],
+    order: G2Order,
+    endian: CoordinateEndian,
+) -> Result<String, ProofError> {
+    let w = proof_to_uint256_words(proof_bytes, order, endian)?;
+    Ok(format!(
+        "[\"{}\",\"{}\"],[[\"{}\",\"{}\"],[\"{}\",\"{}\"]],[\"{}\",\"{}\"]",
+        w[0], w[1], w[2], w[3], w[4], w[5], w[6], w[7]
+    ))
+}
+
+/// Format a compressed proof and hex-LE public signals as Solidity verifier calldata,
+/// in the `[a],[[b]],[c],[inputs]` shape produced by snarkjs `exportSolidityCallData`.
+///
+/// `endian` applies to every word, public inputs included; snarkjs-compatible
+/// calldata uses [`CoordinateEndian::Big`].
+pub fn proof_to_solidity_calldata(
+    proof_bytes: &[u8],
+    public_signals: &[String],
+    order: G2Order,
+    endian: CoordinateEndian,
+) -> Result<String, ProofError> {
+    let points = proof_points_calldata(proof_bytes, order, endian)?;
+    let inputs: Vec<String> = signals_to_words(public_signals, endian)?
+        .iter()
+        .map(|w| format!("\"{w}\""))
+        .collect();
+    Ok(format!("{points},[{}]", inputs.join(",")))
+}
+
+/// Zero-pad `signals` with `0x` 32-byte zero words up to `target` entries, for
+/// verifier contracts with a fixed public-input arity.
+///
+/// More than `target` signals is an error, since dropping any would change what
+/// the proof is checked against.
+pub fn pad_signals(mut signals: Vec<String>, target: usize) -> Result<Vec<String>, String> {
+    if signals.len() > target {
+        return Err(format!(
+            "{} public signals exceed the target of {target}",
+            signals.len()
+        ));
+    }
+    signals.resize(target, format!("0x{}", "00".repeat(32)));
+    Ok(signals)
+}
+
+/// Convert hex-LE public signals to `0x` big-endian uint256 words.
+pub(crate) fn signals_to_uint256_words(
+    public_signals: &[String],
+) -> Result<Vec<String>, ProofError> {
+    signals_to_words(public_signals, CoordinateEndian::Big)
+}
+
+fn signals_to_words(
+    public_signals: &[String],
+    endian: CoordinateEndian,
+) -> Result<Vec<String>, ProofError> {
+    public_signals
+        .iter()
+        .map(|s| from_hex_le::<Bn254Fr>(s).map(|f| to_word(&f, endian)))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(ProofError::PublicSignalConversion)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::{G1Projective, G2Projective};
+    use ark_ec::{CurveGroup, PrimeGroup};
+    use ark_serialize::CanonicalSerialize;
+
+    // BN254 G2 generator x-coordinate components (EIP-197).
+    const G2_GEN_X_C0: &str = "0x1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed";
+    const G2_GEN_X_C1: &str = "0x198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2";
+
+    #[test]
+    fn test_pad_signals_to_fixed_arity() {
+        let zero = format!("0x{}", "00".repeat(32));
+        let signals: Vec<String> = ["0x01", "0x02", "0x03"].map(String::from).to_vec();
+        let padded = pad_signals(signals.clone(), 5).unwrap();
+        assert_eq!(padded.len(), 5);
+        assert_eq!(padded[..3], signals[..]);
+        assert_eq!(padded[3..], [zero.clone(), zero]);
+        assert_eq!(pad_signals(signals.clone(), 3).unwrap(), signals);
+        assert!(pad_signals(signals, 2).unwrap_err().contains("exceed"));
+    }
+
+    #[test]
+    fn test_proof_points_reserialize_to_proof() {
+        let (pk, _) = crate::test_utils::setup(1);
+        let (proof_bytes, _) = crate::test_utils::prove(&pk, 3, 5);
+        let (a, b, c) = proof_points(&proof_bytes).unwrap();
+        assert!(a.is_on_curve() && a.is_in_correct_subgroup_assuming_on_curve());
+        assert!(b.is_on_curve() && b.is_in_correct_subgroup_assuming_on_curve());
+        assert!(c.is_on_curve() && c.is_in_correct_subgroup_assuming_on_curve());
+
+        let mut reserialized = Vec::new();
+        ArkProof::<Bn254> { a, b, c }
+            .serialize_compressed(&mut reserialized)
+            .unwrap();
+        assert_eq!(reserialized, proof_bytes);
+        assert!(proof_points(&proof_bytes[1..]).is_err());
+    }
+
+    fn generator_proof_bytes() -> Vec<u8> {
+        let proof = ArkProof::<Bn254> {
+            a: G1Projective::generator().into_affine(),
+            b: G2Projective::generator().into_affine(),
+            c: G1Projective::generator().into_affine(),
+        };
+        let mut bytes = Vec::new();
+        proof.serialize_compressed(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_default_order_is_c1c0() {
+        assert_eq!(G2Order::default(), G2Order::C1C0);
+    }
+
+    #[test]
+    fn test_words_c1c0_fixture() {
+        let words = proof_to_uint256_words(
+            &generator_proof_bytes(),
+            G2Order::C1C0,
+            CoordinateEndian::Big,
+        )
+        .unwrap();
+        assert_eq!(words[0], format!("0x{:064x}", 1));
+        assert_eq!(words[1], format!("0x{:064x}", 2));
+        assert_eq!(words[2], G2_GEN_X_C1);
+        assert_eq!(words[3], G2_GEN_X_C0);
+    }
+
+    #[test]
+    fn test_words_c0c1_fixture() {
+        let words = proof_to_uint256_words(
+            &generator_proof_bytes(),
+            G2Order::C0C1,
+            CoordinateEndian::Big,
+        )
+        .unwrap();
+        assert_eq!(words[2], G2_GEN_X_C0);
+        assert_eq!(words[3], G2_GEN_X_C1);
+    }
+
+    #[test]
+    fn test_orders_only_swap_g2_limbs() {
+        let bytes = generator_proof_bytes();
+        let a = proof_to_uint256_words(&bytes, G2Order::C1C0, CoordinateEndian::Big).unwrap();
+        let b = proof_to_uint256_words(&bytes, G2Order::C0C1, CoordinateEndian::Big).unwrap();
+        assert_eq!(a[0..2], b[0..2]);
+        assert_eq!(a[6..8], b[6..8]);
+        assert_eq!((&a[2], &a[3]), (&b[3], &b[2]));
+        assert_eq!((&a[4], &a[5]), (&b[5], &b[4]));
+    }
+
+    #[test]
+    fn test_calldata_shape() {
+        let signals = vec![format!("0x05{}", "00".repeat(31))];
+        let calldata = proof_to_solidity_calldata(
+            &generator_proof_bytes(),
+            &signals,
+            G2Order::C1C0,
+            CoordinateEndian::Big,
+        )
+        .unwrap();
+        assert!(calldata.starts_with(&format!("[\"0x{:064x}\"", 1)));
+        assert!(calldata.contains(&format!("[[\"{G2_GEN_X_C1}\",\"{G2_GEN_X_C0}\"]")));
+        assert!(calldata.ends_with(&format!("[\"0x{:064x}\"]", 5)));
+    }
+
+    #[test]
+    fn test_default_endian_is_big() {
+        assert_eq!(CoordinateEndian::default(), CoordinateEndian::Big);
+    }
+
+    #[test]
+    fn test_little_endian_reverses_bytes_within_each_word() {
+        let bytes = generator_proof_bytes();
+        let be = proof_to_uint256_words(&bytes, G2Order::C1C0, CoordinateEndian::Big).unwrap();
+        let le = proof_to_uint256_words(&bytes, G2Order::C1C0, CoordinateEndian::Little).unwrap();
+        assert_eq!(le[0], format!("0x01{}", "00".repeat(31)));
+        assert_eq!(le[1], format!("0x02{}", "00".repeat(31)));
+        for (be, le) in be.iter().zip(&le) {
+            let mut reversed = hex::decode(&be[2..]).unwrap();
+            reversed.reverse();
+            assert_eq!(le, &format!("0x{}", hex::encode(reversed)));
+        }
+    }
+
+    #[test]
+    fn test_calldata_little_endian_applies_to_inputs() {
+        let signals = vec![format!("0x05{}", "00".repeat(31))];
+        let calldata = proof_to_solidity_calldata(
+            &generator_proof_bytes(),
+            &signals,
+            G2Order::C1C0,
+            CoordinateEndian::Little,
+        )
+        .unwrap();
+        assert!(calldata.starts_with(&format!("[\"0x01{}\"", "00".repeat(31))));
+        assert!(calldata.ends_with(&format!("[\"{}\"]", signals[0])));
+    }
+
+    #[test]
+    fn test_abi_bytes_fixture() {
+        let bytes = generator_proof_bytes();
+        let abi = proof_to_abi_bytes(&bytes).unwrap();
+        assert_eq!(abi.len(), 8 * 32);
+        assert_eq!(hex::encode(&abi[..32]), format!("{:064x}", 1));
+        assert_eq!(format!("0x{}", hex::encode(&abi[64..96])), G2_GEN_X_C1);
+
+        let words = proof_to_uint256_words(&bytes, G2Order::C1C0, CoordinateEndian::Big).unwrap();
+        let from_words: Vec<u8> = words
+            .iter()
+            .flat_map(|w| hex::decode(&w[2..]).unwrap())
+            .collect();
+        assert_eq!(abi, from_words);
+        assert!(proof_to_abi_bytes(b"short").is_err());
+    }
+
+    #[test]
+    fn test_rejects_malformed_proof() {
+        let err = proof_to_uint256_words(b"short", G2Order::default(), CoordinateEndian::default())
+            .unwrap_err();
+        assert!(matches!(err, ProofError::ProofParse(_)));
+    }
+}
@@ -0,0 +1,159 @@
+//! C ABI for native callers that already hold BN254 scalar field data.
+//!
+//! All entry points return an `ORBINUM_*` status code; `0` is success.
+
+use std::slice;
+
+use crate::prover::prove_from_witness;
+use crate::utils::field_from_limbs;
+
+pub const ORBINUM_OK: i32 = 0;
+pub const ORBINUM_ERR_NULL_POINTER: i32 = 1;
+pub const ORBINUM_ERR_INVALID_FIELD: i32 = 2;
+pub const ORBINUM_ERR_PROVE: i32 = 3;
+pub const ORBINUM_ERR_BUFFER_TOO_SMALL: i32 = 4;
+
+/// BN254 scalar field element as four little-endian 64-bit limbs of its
+/// canonical (non-Montgomery) integer value. Must be below the field modulus.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OrbinumField {
+    pub limbs: [u64; 4],
+}
+
+/// Generate a compressed Groth16 proof from a witness of raw field elements.
+///
+/// On success writes the proof to `out_proof` and its length to `out_proof_len`.
+/// If `out_proof_cap` is too small, returns `ORBINUM_ERR_BUFFER_TOO_SMALL` with the
+/// required length in `out_proof_len`.
+///
+/// # Safety
+///
+/// `fields` must point to `len` readable `OrbinumField`s, `proving_key` to
+/// `proving_key_len` readable bytes, `out_proof` to `out_proof_cap` writable bytes,
+/// and `out_proof_len` to a writable `usize`.
+#[no_mangle]
+pub unsafe extern "C" fn orbinum_generate_proof_fields(
+    fields: *const OrbinumField,
+    len: usize,
+    proving_key: *const u8,
+    proving_key_len: usize,
+    num_public_signals: usize,
+    out_proof: *mut u8,
+    out_proof_cap: usize,
+    out_proof_len: *mut usize,
+) -> i32 {
+    if fields.is_null() || proving_key.is_null() || out_proof.is_null() || out_proof_len.is_null() {
+        return ORBINUM_ERR_NULL_POINTER;
+    }
+    let fields = slice::from_raw_parts(fields, len);
+    let proving_key = slice::from_raw_parts(proving_key, proving_key_len);
+
+    let Some(witness) = fields
+        .iter()
+        .map(|f| field_from_limbs(f.limbs).ok())
+        .collect::<Option<Vec<_>>>()
+    else {
+        return ORBINUM_ERR_INVALID_FIELD;
+    };
+
+    let proof = match prove_from_witness(proving_key, witness, num_public_signals) {
+        Ok(proof) => proof,
+        Err(_) => return ORBINUM_ERR_PROVE,
+    };
+
+    *out_proof_len = proof.len();
+    if proof.len() > out_proof_cap {
+        return ORBINUM_ERR_BUFFER_TOO_SMALL;
+    }
+    slice::from_raw_parts_mut(out_proof, proof.len()).copy_from_slice(&proof);
+    ORBINUM_OK
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_serialize::CanonicalSerialize;
+
+    fn pk_bytes() -> Vec<u8> {
+        let mut bytes = Vec::new();
+        crate::test_utils::setup(1)
+            .0
+            .serialize_compressed(&mut bytes)
+            .unwrap();
+        bytes
+    }
+
+    fn limbs(v: u64) -> OrbinumField {
+        OrbinumField {
+            limbs: [v, 0, 0, 0],
+        }
+    }
+
+    fn call(fields: &[OrbinumField], pk: &[u8], out: &mut [u8]) -> (i32, usize) {
+        let mut out_len = 0usize;
+        let status = unsafe {
+            orbinum_generate_proof_fields(
+                fields.as_ptr(),
+                fields.len(),
+                pk.as_ptr(),
+                pk.len(),
+                1,
+                out.as_mut_ptr(),
+                out.len(),
+                &mut out_len,
+            )
+        };
+        (status, out_len)
+    }
+
+    #[test]
+    fn test_generate_proof_from_limbs() {
+        let fields = [limbs(1), limbs(15), limbs(3), limbs(5)];
+        let mut out = [0u8; 256];
+        let (status, len) = call(&fields, &pk_bytes(), &mut out);
+        assert_eq!(status, ORBINUM_OK);
+        assert_eq!(len, 128);
+        assert!(out[..len].iter().any(|&b| b != 0));
+    }
+
+    #[test]
+    fn test_rejects_non_canonical_limbs() {
+        let fields = [
+            limbs(1),
+            OrbinumField {
+                limbs: [u64::MAX; 4],
+            },
+        ];
+        let mut out = [0u8; 256];
+        let (status, _) = call(&fields, &pk_bytes(), &mut out);
+        assert_eq!(status, ORBINUM_ERR_INVALID_FIELD);
+    }
+
+    #[test]
+    fn test_reports_required_buffer_len() {
+        let fields = [limbs(1), limbs(15), limbs(3), limbs(5)];
+        let mut out = [0u8; 16];
+        let (status, len) = call(&fields, &pk_bytes(), &mut out);
+        assert_eq!(status, ORBINUM_ERR_BUFFER_TOO_SMALL);
+        assert_eq!(len, 128);
+    }
+
+    #[test]
+    fn test_null_pointer() {
+        let mut out_len = 0usize;
+        let status = unsafe {
+            orbinum_generate_proof_fields(
+                std::ptr::null(),
+                0,
+                std::ptr::null(),
+                0,
+                1,
+                std::ptr::null_mut(),
+                0,
+                &mut out_len,
+            )
+        };
+        assert_eq!(status, ORBINUM_ERR_NULL_POINTER);
+    }
+}
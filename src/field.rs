@@ -1,4 +1,4 @@
-use ark_ff::PrimeField;
+use ark_ff::{BigInteger, PrimeField};
 use num_bigint::BigUint;
 
 /// Parse a decimal string into any `PrimeField` element (snarkjs native wire format).
@@ -8,16 +8,45 @@ pub fn from_decimal_str<F: PrimeField>(s: &str) -> Result<F, String> {
     Ok(F::from_le_bytes_mod_order(&n.to_bytes_le()))
 }
 
-/// Parse a little-endian hex string (`0x…` prefix optional) into any `PrimeField` element.
+/// Parse a little-endian hex string (`0x…` / `0X…` prefix optional, digits in any case)
+/// into any `PrimeField` element.
 pub fn from_hex_le<F: PrimeField>(hex: &str) -> Result<F, String> {
-    let stripped = hex.strip_prefix("0x").unwrap_or(hex);
+    Ok(F::from_le_bytes_mod_order(&decode_hex(hex)?))
+}
+
+/// Big-endian counterpart of [`from_hex_le`], for tools that emit BE hex.
+pub fn from_hex_be<F: PrimeField>(hex: &str) -> Result<F, String> {
+    Ok(F::from_be_bytes_mod_order(&decode_hex(hex)?))
+}
+
+/// Decode hex with an optional `0x` / `0X` prefix, left-padding odd-length input.
+pub fn decode_hex(hex: &str) -> Result<Vec<u8>, String> {
+    let stripped = strip_hex_prefix(hex);
     let padded = if stripped.len() % 2 == 1 {
         format!("0{stripped}")
     } else {
         stripped.to_string()
     };
-    let bytes = hex::decode(&padded).map_err(|e| format!("Failed to decode hex: {e}"))?;
-    Ok(F::from_le_bytes_mod_order(&bytes))
+    hex::decode(&padded).map_err(|e| format!("Failed to decode hex: {e}"))
+}
+
+/// Strip a leading `0x` or `0X`, if present.
+pub fn strip_hex_prefix(hex: &str) -> &str {
+    hex.strip_prefix("0x")
+        .or_else(|| hex.strip_prefix("0X"))
+        .unwrap_or(hex)
+}
+
+/// Format any `PrimeField` element as a decimal string (snarkjs native wire format).
+pub fn to_decimal_str<F: PrimeField>(f: &F) -> String {
+    BigUint::from_bytes_le(&f.into_bigint().to_bytes_le()).to_str_radix(10)
+}
+
+/// Canonical little-endian encoding of a field element, zero-padded to 32 bytes.
+pub(crate) fn to_le_bytes_32<F: PrimeField>(f: &F) -> Vec<u8> {
+    let mut bytes = f.into_bigint().to_bytes_le();
+    bytes.resize(32, 0u8);
+    bytes
 }
 
 #[cfg(test)]
@@ -65,6 +94,13 @@ mod tests {
         assert_eq!(a, b);
     }
 
+    #[test]
+    fn test_to_decimal_roundtrip() {
+        let f = from_decimal_str::<Bn254Fr>("12345678901234567890").unwrap();
+        assert_eq!(to_decimal_str(&f), "12345678901234567890");
+        assert_eq!(to_decimal_str(&Bn254Fr::from(0u64)), "0");
+    }
+
     #[test]
     fn test_hex_le_one() {
         let hex = "0x0100000000000000000000000000000000000000000000000000000000000000";
@@ -94,6 +130,27 @@ mod tests {
         assert_eq!(from_hex_le::<Bn254Fr>("0x1").unwrap(), Bn254Fr::from(1u64));
     }
 
+    #[test]
+    fn test_hex_le_prefix_and_digit_case() {
+        let expected = Bn254Fr::from(0xabcdu64);
+        for hex in ["0xcdab", "0XCDAB", "0XcDaB", "0xCDAB", "CdAb"] {
+            assert_eq!(from_hex_le::<Bn254Fr>(hex).unwrap(), expected, "{hex}");
+        }
+    }
+
+    #[test]
+    fn test_hex_be_vs_le() {
+        assert_eq!(
+            from_hex_be::<Bn254Fr>("0x0100").unwrap(),
+            Bn254Fr::from(256u64)
+        );
+        assert_eq!(
+            from_hex_le::<Bn254Fr>("0x0100").unwrap(),
+            Bn254Fr::from(1u64)
+        );
+        assert_eq!(from_hex_be::<Bn254Fr>("0X1").unwrap(), Bn254Fr::from(1u64));
+    }
+
     #[test]
     fn test_hex_le_invalid() {
         let err = from_hex_le::<Bn254Fr>("0xGGGG").unwrap_err();
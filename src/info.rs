@@ -0,0 +1,36 @@
+use serde::Serialize;
+
+/// Library, arkworks, and curve identification for bug reports.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BuildInfo {
+    pub version: &'static str,
+    pub ark_groth16: &'static str,
+    pub ark_bn254: &'static str,
+    pub curve: &'static str,
+}
+
+/// Versions this build was compiled against. Arkworks versions are `unknown` when
+/// the crate was built without access to its `Cargo.lock`.
+pub fn build_info() -> BuildInfo {
+    BuildInfo {
+        version: env!("CARGO_PKG_VERSION"),
+        ark_groth16: env!("ARK_GROTH16_VERSION"),
+        ark_bn254: env!("ARK_BN254_VERSION"),
+        curve: "bn254",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_info() {
+        let info = build_info();
+        assert_eq!(info.curve, "bn254");
+        assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+        assert!(!info.ark_groth16.is_empty());
+        let json = serde_json::to_value(&info).unwrap();
+        assert_eq!(json["curve"], "bn254");
+    }
+}
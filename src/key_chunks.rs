@@ -0,0 +1,85 @@
+use std::fs;
+use std::path::Path;
+
+/// Split a proving key file into numbered chunks of at most `chunk_size` bytes.
+///
+/// Chunks are written to `out_dir` (created if missing) as `<file name>.part000`,
+/// `.part001`, … and their paths are returned in order, ready for
+/// [`reassemble_key`].
+pub fn split_key_file(path: &str, chunk_size: usize, out_dir: &str) -> Result<Vec<String>, String> {
+    if chunk_size == 0 {
+        return Err("chunk_size must be greater than 0".into());
+    }
+    let bytes = fs::read(path).map_err(|e| format!("Failed to read {path}: {e}"))?;
+    let name = Path::new(path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| format!("Invalid key path: {path}"))?;
+    fs::create_dir_all(out_dir).map_err(|e| format!("Failed to create {out_dir}: {e}"))?;
+
+    bytes
+        .chunks(chunk_size)
+        .enumerate()
+        .map(|(i, chunk)| {
+            let chunk_path = Path::new(out_dir).join(format!("{name}.part{i:03}"));
+            fs::write(&chunk_path, chunk)
+                .map_err(|e| format!("Failed to write {}: {e}", chunk_path.display()))?;
+            Ok(chunk_path.to_string_lossy().into_owned())
+        })
+        .collect()
+}
+
+/// Concatenate chunk files, in the given order, back into the key bytes.
+pub fn reassemble_key(chunk_paths: &[String]) -> Result<Vec<u8>, String> {
+    if chunk_paths.is_empty() {
+        return Err("No chunks to reassemble".into());
+    }
+    let mut bytes = Vec::new();
+    for path in chunk_paths {
+        bytes.extend(fs::read(path).map_err(|e| format!("Failed to read {path}: {e}"))?);
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Bn254;
+    use ark_groth16::ProvingKey;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+    #[test]
+    fn test_split_and_reassemble_roundtrip() {
+        let (pk, _) = crate::test_utils::setup(1);
+        let mut pk_bytes = Vec::new();
+        pk.serialize_compressed(&mut pk_bytes).unwrap();
+
+        let dir = "/tmp/test_key_chunks";
+        let key_path = format!("{dir}/pk.ark");
+        fs::create_dir_all(dir).unwrap();
+        fs::write(&key_path, &pk_bytes).unwrap();
+
+        let chunk_size = 100;
+        let chunks = split_key_file(&key_path, chunk_size, &format!("{dir}/parts")).unwrap();
+        assert_eq!(chunks.len(), pk_bytes.len().div_ceil(chunk_size));
+        assert!(chunks[0].ends_with("pk.ark.part000"));
+
+        let reassembled = reassemble_key(&chunks).unwrap();
+        let _ = fs::remove_dir_all(dir);
+        assert_eq!(reassembled, pk_bytes);
+        let restored = ProvingKey::<Bn254>::deserialize_compressed(reassembled.as_slice()).unwrap();
+        assert_eq!(restored, pk);
+    }
+
+    #[test]
+    fn test_split_rejects_zero_chunk_size() {
+        assert!(split_key_file("/tmp/whatever.ark", 0, "/tmp").is_err());
+    }
+
+    #[test]
+    fn test_reassemble_missing_chunk() {
+        assert!(reassemble_key(&[]).is_err());
+        let err = reassemble_key(&["/nonexistent/pk.ark.part000".into()]).unwrap_err();
+        assert!(err.contains("Failed to read"));
+    }
+}
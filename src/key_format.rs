@@ -0,0 +1,100 @@
+use ark_bn254::Bn254;
+use ark_groth16::ProvingKey;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+/// On-disk encoding of an arkworks proving key.
+///
+/// Compressed keys store only x-coordinates and are roughly half the size;
+/// uncompressed keys skip point decompression and load faster.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyFormat {
+    Compressed,
+    Uncompressed,
+}
+
+/// Decode a proving key in either format, reporting which one it was.
+fn decode_any(bytes: &[u8]) -> Result<(ProvingKey<Bn254>, KeyFormat), String> {
+    let mut reader = bytes;
+    if let Ok(pk) = ProvingKey::<Bn254>::deserialize_compressed(&mut reader) {
+        if reader.is_empty() {
+            return Ok((pk, KeyFormat::Compressed));
+        }
+    }
+    let mut reader = bytes;
+    match ProvingKey::<Bn254>::deserialize_uncompressed(&mut reader) {
+        Ok(pk) if reader.is_empty() => Ok((pk, KeyFormat::Uncompressed)),
+        _ => Err(format!(
+            "{} bytes are neither a compressed nor an uncompressed proving key",
+            bytes.len()
+        )),
+    }
+}
+
+/// Rewrite the proving key at `in_path` to `out_path` in the `target` format.
+///
+/// The input format is detected, and the key is fully validated either way. If it
+/// already matches `target`, the original bytes are written unchanged. `in_path`
+/// and `out_path` may be the same file.
+pub fn recompress_key_file(in_path: &str, out_path: &str, target: KeyFormat) -> Result<(), String> {
+    let bytes = std::fs::read(in_path).map_err(|e| format!("Failed to read key {in_path}: {e}"))?;
+    let (pk, format) = decode_any(&bytes).map_err(|e| format!("{in_path}: {e}"))?;
+    let output = if format == target {
+        bytes
+    } else {
+        let mut output = Vec::new();
+        match target {
+            KeyFormat::Compressed => pk.serialize_compressed(&mut output),
+            KeyFormat::Uncompressed => pk.serialize_uncompressed(&mut output),
+        }
+        .map_err(|e| format!("Failed to serialize key: {e}"))?;
+        output
+    };
+    std::fs::write(out_path, output).map_err(|e| format!("Failed to write key {out_path}: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::setup;
+
+    #[test]
+    fn test_recompress_round_trip() {
+        let dir = std::env::temp_dir().join(format!("orbinum-recompress-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = |name: &str| dir.join(name).to_str().unwrap().to_string();
+
+        let (pk, _) = setup(1);
+        let mut compressed = Vec::new();
+        pk.serialize_compressed(&mut compressed).unwrap();
+        std::fs::write(path("pk.ark"), &compressed).unwrap();
+
+        recompress_key_file(&path("pk.ark"), &path("pk_u.ark"), KeyFormat::Uncompressed).unwrap();
+        let uncompressed = std::fs::read(path("pk_u.ark")).unwrap();
+        assert!(uncompressed.len() > compressed.len());
+        assert_eq!(
+            ProvingKey::<Bn254>::deserialize_uncompressed(&uncompressed[..]).unwrap(),
+            pk
+        );
+
+        recompress_key_file(&path("pk_u.ark"), &path("pk_c.ark"), KeyFormat::Compressed).unwrap();
+        assert_eq!(std::fs::read(path("pk_c.ark")).unwrap(), compressed);
+
+        // Matching format: bytes pass through untouched.
+        recompress_key_file(
+            &path("pk_u.ark"),
+            &path("pk_u2.ark"),
+            KeyFormat::Uncompressed,
+        )
+        .unwrap();
+        assert_eq!(std::fs::read(path("pk_u2.ark")).unwrap(), uncompressed);
+
+        std::fs::write(path("bad.ark"), [0u8; 64]).unwrap();
+        let err = recompress_key_file(&path("bad.ark"), &path("out.ark"), KeyFormat::Compressed)
+            .unwrap_err();
+        assert!(
+            err.contains("neither a compressed nor an uncompressed"),
+            "{err}"
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,94 @@
+use ark_bn254::Bn254;
+use ark_groth16::{ProvingKey, VerifyingKey};
+use ark_serialize::CanonicalDeserialize;
+
+use crate::curve::strip_vk_tag;
+
+/// Which kind of arkworks key a byte buffer holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyKind {
+    ProvingKey,
+    VerifyingKey,
+}
+
+impl KeyKind {
+    /// Whether `bytes` decode as exactly one compressed key of this kind.
+    fn parses(self, bytes: &[u8]) -> bool {
+        match self {
+            KeyKind::ProvingKey => consumes_all::<ProvingKey<Bn254>>(bytes),
+            KeyKind::VerifyingKey => {
+                strip_vk_tag(bytes).is_ok_and(consumes_all::<VerifyingKey<Bn254>>)
+            }
+        }
+    }
+}
+
+// A proving key starts with its verifying key, so a decode that leaves bytes
+// over must not count as a match.
+fn consumes_all<T: CanonicalDeserialize>(bytes: &[u8]) -> bool {
+    let mut reader = bytes;
+    T::deserialize_compressed(&mut reader).is_ok() && reader.is_empty()
+}
+
+/// Report whether `bytes` are a compressed proving key or verifying key.
+///
+/// A compressed BN254 VK is `232 + 32·n` bytes (`≡ 8 mod 32`, or `9` when
+/// curve-tagged) while a PK is `≡ 16 mod 32`, so the likely kind is tried first
+/// and the full proving-key decode is usually the only one performed for PKs.
+pub fn detect_key_type(bytes: &[u8]) -> Result<KeyKind, String> {
+    let order = if bytes.len() % 32 == 16 {
+        [KeyKind::ProvingKey, KeyKind::VerifyingKey]
+    } else {
+        [KeyKind::VerifyingKey, KeyKind::ProvingKey]
+    };
+    order
+        .into_iter()
+        .find(|kind| kind.parses(bytes))
+        .ok_or_else(|| {
+            format!(
+                "{} bytes are neither a compressed proving key nor a verifying key",
+                bytes.len()
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::setup;
+    use crate::{tag_artifact, Curve};
+    use ark_serialize::CanonicalSerialize;
+
+    fn compressed<T: CanonicalSerialize>(value: &T) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        value.serialize_compressed(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_detect_key_type_distinguishes_pk_and_vk() {
+        let (pk, vk) = setup(1);
+        let (pk, vk) = (compressed(&pk), compressed(&vk));
+        assert_eq!(pk.len() % 32, 16);
+        assert_eq!(vk.len() % 32, 8);
+        assert_eq!(detect_key_type(&pk).unwrap(), KeyKind::ProvingKey);
+        assert_eq!(detect_key_type(&vk).unwrap(), KeyKind::VerifyingKey);
+        assert_eq!(
+            detect_key_type(&tag_artifact(Curve::Bn254, &vk)).unwrap(),
+            KeyKind::VerifyingKey
+        );
+    }
+
+    #[test]
+    fn test_detect_key_type_rejects_other_bytes() {
+        let (pk, _) = setup(1);
+        let pk = compressed(&pk);
+        assert!(detect_key_type(&pk[..pk.len() - 1]).is_err());
+        let mut trailing = pk.clone();
+        trailing.extend([0; 32]);
+        assert!(detect_key_type(&trailing)
+            .unwrap_err()
+            .contains("neither a compressed proving key"));
+        assert!(detect_key_type(&[]).is_err());
+    }
+}
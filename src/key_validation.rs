@@ -0,0 +1,91 @@
+use ark_bn254::Bn254;
+use ark_ec::pairing::Pairing;
+use ark_ec::AffineRepr;
+use ark_groth16::ProvingKey;
+use ark_serialize::Valid;
+
+/// Sanity-check a proving key before proving with it.
+///
+/// A corrupted key can still deserialize, especially through unchecked paths, and then
+/// silently produce proofs that never verify. This catches the common cases:
+///
+/// - every point is on the curve and in the prime-order subgroup (the same checks
+///   the validating deserializer runs);
+/// - the key has at least one IC point;
+/// - `alpha`, `beta`, `gamma` and `delta` are not the identity;
+/// - the G1 copies of `beta` and `delta` are consistent with their G2 counterparts
+///   in the VK.
+///
+/// Passing does not prove the key came from an honest setup.
+pub fn validate_proving_key(pk: &ProvingKey<Bn254>) -> Result<(), String> {
+    pk.check()
+        .map_err(|e| format!("point not on the curve or not in the subgroup: {e}"))?;
+    if pk.vk.gamma_abc_g1.is_empty() {
+        return Err("key has no IC points".into());
+    }
+    for (name, is_zero) in [
+        ("alpha_g1", pk.vk.alpha_g1.is_zero()),
+        ("beta_g1", pk.beta_g1.is_zero()),
+        ("beta_g2", pk.vk.beta_g2.is_zero()),
+        ("gamma_g2", pk.vk.gamma_g2.is_zero()),
+        ("delta_g1", pk.delta_g1.is_zero()),
+        ("delta_g2", pk.vk.delta_g2.is_zero()),
+    ] {
+        if is_zero {
+            return Err(format!("{name} is the identity"));
+        }
+    }
+    // arkworks setup draws random G1/G2 generators, so compare the pairs against each
+    // other: both sides equal e(g1, g2)^(beta·delta).
+    if Bn254::pairing(pk.beta_g1, pk.vk.delta_g2) != Bn254::pairing(pk.delta_g1, pk.vk.beta_g2) {
+        return Err(
+            "beta_g1 / delta_g1 do not match the verifying key's beta_g2 / delta_g2".into(),
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::setup;
+    use ark_bn254::{Fq, G1Affine};
+
+    #[test]
+    fn test_fixture_keys_pass() {
+        validate_proving_key(&setup(1).0).unwrap();
+        let zkey = include_bytes!("../tests/fixtures/multiplier.zkey");
+        validate_proving_key(&crate::read_zkey(zkey).unwrap().0).unwrap();
+    }
+
+    #[test]
+    fn test_corrupted_keys_fail() {
+        let (pk, _) = setup(1);
+
+        let mut off_curve = pk.clone();
+        off_curve.a_query[0] = G1Affine::new_unchecked(Fq::from(1u64), Fq::from(3u64));
+        assert!(validate_proving_key(&off_curve)
+            .unwrap_err()
+            .contains("not on the curve"));
+
+        let mut no_alpha = pk.clone();
+        no_alpha.vk.alpha_g1 = G1Affine::zero();
+        assert_eq!(
+            validate_proving_key(&no_alpha).unwrap_err(),
+            "alpha_g1 is the identity"
+        );
+
+        let mut swapped_delta = pk.clone();
+        swapped_delta.delta_g1 = G1Affine::generator();
+        assert!(validate_proving_key(&swapped_delta)
+            .unwrap_err()
+            .contains("do not match the verifying key"));
+
+        let mut no_ic = pk;
+        no_ic.vk.gamma_abc_g1.clear();
+        assert_eq!(
+            validate_proving_key(&no_ic).unwrap_err(),
+            "key has no IC points"
+        );
+    }
+}
@@ -3,42 +3,166 @@
 //! # Architecture
 //!
 //! - `error`  — [`ProofError`] unified error type
-//! - `field`  — generic [`from_decimal_str`] / [`from_hex_le`] field conversion
+//! - `curve`  — [`Curve`] / [`tag_artifact`]: one-byte curve tags on proofs and VKs
+//! - `circuit_type` — [`CircuitType`]: supported Orbinum circuits and their public arity
+//! - `field`  — generic [`from_decimal_str`] / [`from_hex_le`] / [`from_hex_be`] / [`to_decimal_str`] field conversion; [`decode_hex`] / [`strip_hex_prefix`] hex normalization
+//! - `proof_version` — [`write_proof_versioned`] / [`read_proof_versioned`]: opt-in one-byte [`ProofVersion`] prefix on proofs
+//! - `result` — [`ProofResult`]: proof + signals with a length-prefixed binary framing and MessagePack (`msgpack` feature); [`bundle_size`]
+//! - `commitment` — [`witness_commitment`] / [`vk_fingerprint`] / [`proof_id`]: SHA-256 identifiers for audit logs and caching
 //! - `circuit`— [`WitnessCircuit`]: arkworks `ConstraintSynthesizer` adapter
-//! - `prover` — [`prove_from_witness`]: core prover shared by native and WASM paths
-//! - `codec`  — [`codec::compress_snarkjs_proof`]: snarkjs JSON → compressed bytes
-//! - `proof`  — [`generate_proof_from_witness`]: file-I/O adapter (native/CLI)
-//! - `utils`  — backward-compat shims for `decimal_to_field` / `hex_to_field`
-//! - `wasm`   — WASM bindings (`generate_proof_from_decimal_wasm`, `compress_snarkjs_proof_wasm`)
+//! - `prover` — [`prove_from_witness`] / [`ProofGenerator`]: core prover shared by native and WASM paths; [`setup_keys`]; [`RngKind`]; [`prove_with_secret_loader`]
+//! - `codec`  — [`codec::compress_snarkjs_proof`]: snarkjs JSON → compressed bytes; [`canonicalize_proof`]; [`vk_from_snarkjs_json`] / [`vk_to_snarkjs_json`]; [`verify_all_snarkjs`]; [`proof_to_snarkjs_json`] / [`write_public_json`]
+//! - `export` — [`proof_to_uint256_words`] / [`proof_to_solidity_calldata`] / [`proof_to_abi_bytes`]: EVM exporters; [`proof_points`]; [`pad_signals`]
+//! - `qr`     — [`proof_to_qr_string`] / [`proof_from_qr_string`]: Base45 for QR alphanumeric mode
+//! - `eip712` — [`proof_to_eip712_typed_data`]: EIP-712 typed data for wallet display/signing
+//! - `zkey`   — [`read_zkey`] / [`proving_key_to_zkey`]: snarkjs `.zkey` import and export; see [`ProofGenerator::from_zkey_bytes`]
+//! - `proof`  — [`generate_proof_from_witness`] / [`generate_proof_cancellable`]: file-I/O adapter (native/CLI)
+//! - `verifier` — [`Verifier`] / [`verify`] / [`verify_borrowed`] / [`verify_timed`] / [`verify_with_commitment`] / [`verify_report`] / [`verify_full`] / [`batch_verify`] / [`identify_circuit`] / [`compute_vk_x`]: Groth16 verification
+//! - `artifact` — [`parse_combined_artifact`] / [`CombinedKeys`]: proving key, VK and arity in one file
+//! - `info`   — [`build_info`]: crate/arkworks versions and curve, for bug reports
+//! - `key_kind` — [`detect_key_type`] / [`KeyKind`]: tell proving keys from verifying keys
+//! - `key_format` — [`recompress_key_file`] / [`KeyFormat`]: switch a proving key between compressed and uncompressed
+//! - `key_validation` — [`validate_proving_key`]: sanity checks on a deserialized proving key
+//! - `key_chunks` — [`split_key_file`] / [`reassemble_key`]: distribute large keys in chunks
+//! - `manifest` — [`validate_manifest`]: structural checks on `{proof, public_signals, circuit}` bundles
+//! - `nullifier` — [`check_nullifier`]: double-spend check of a nullifier signal against a seen set
+//! - `witness` — [`parse_sparse_witness`] / [`parse_sparse_witness_with_max_len`]: alternative witness encodings; [`witness_diff`]; [`find_duplicate_fields`]; [`check_witness_matches_signals`]; [`canonicalize_witness_json`]; [`parse_decimal_witness_json`]; `read_witness_streaming` (`streaming` feature; see `ProofGenerator::prove_from_reader`)
+//! - `witness_calc` — `calculate_witness` / `prove_from_inputs`: circom `.wasm` witness generation, then proving (`witness-calc` feature)
+//! - `config` — `Config`: `orbinum.toml` key paths, CLI defaults and limits (`config` feature)
+//! - `utils`  — backward-compat shims for `decimal_to_field` / `hex_to_field`, BN254 helpers, [`field_add`] / [`field_sub`] / [`field_mul`] / [`field_neg`] / [`field_inv`]
+//! - `ffi`    — C ABI: [`ffi::orbinum_generate_proof_fields`] over raw limb arrays (`ffi` feature)
+//! - `server` — `POST /prove` HTTP router over preloaded [`ProofGenerator`]s (`server` feature)
+//! - `test_utils` — [`test_utils::TestCircuit`] with deterministic keys and random witnesses (`testing` feature)
+//! - `wasm`   — WASM bindings (`generate_proof_from_decimal_wasm` / `generate_proof_wasm_async`, `compress_snarkjs_proof_wasm`, `convert_proof_wasm`, preloaded-key registry)
 
+mod artifact;
 mod circuit;
+mod circuit_type;
 mod codec;
+mod commitment;
+mod curve;
+mod eip712;
 mod error;
+mod export;
 mod field;
+mod info;
+mod key_chunks;
+mod key_format;
+mod key_kind;
+mod key_validation;
+mod manifest;
+mod nullifier;
 mod proof;
+mod proof_version;
 mod prover;
+mod qr;
+mod result;
 mod utils;
+mod verifier;
+mod witness;
+mod zkey;
+
+#[cfg(any(test, feature = "testing"))]
+pub mod test_utils;
+
+#[cfg(feature = "witness-calc")]
+mod witness_calc;
+
+#[cfg(feature = "config")]
+mod config;
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+#[cfg(feature = "server")]
+pub mod server;
 
 #[cfg(feature = "wasm")]
 pub mod wasm;
 
 // Core types
 pub use circuit::WitnessCircuit;
+pub use circuit_type::CircuitType;
+#[cfg(feature = "config")]
+pub use config::{Config, ConfigDefaults, ConfigLimits};
+pub use curve::{tag_artifact, Curve};
 pub use error::ProofError;
 
 // Proof generation
-pub use proof::generate_proof_from_witness;
-pub use prover::prove_from_witness;
+pub use proof::{generate_proof_cancellable, generate_proof_from_witness};
+pub use proof_version::{read_proof_versioned, write_proof_versioned, ProofVersion};
+pub use prover::{
+    constraint_matrices, prove_from_witness, prove_with_secret_loader, setup_keys, ProofGenerator,
+    RngKind,
+};
+pub use qr::{proof_from_qr_string, proof_to_qr_string};
+pub use result::{bundle_size, ProofResult};
+pub use zkey::{proving_key_to_zkey, read_zkey};
+
+// Audit
+pub use commitment::{proof_id, vk_fingerprint, witness_commitment, witness_commitment_tagged};
+pub use manifest::validate_manifest;
+pub use nullifier::check_nullifier;
+
+// Verification
+pub use verifier::{
+    batch_verify, compute_vk_x, identify_circuit, verify, verify_borrowed, verify_full,
+    verify_report, verify_timed, verify_with_commitment, verify_with_components, Verifier,
+    VerifyReport,
+};
+
+// EVM export
+pub use eip712::{proof_to_eip712_typed_data, Eip712Domain};
+pub use export::{
+    pad_signals, proof_points, proof_to_abi_bytes, proof_to_solidity_calldata,
+    proof_to_uint256_words, CoordinateEndian, G2Order,
+};
 
 // snarkjs interop
-pub use codec::compress_snarkjs_proof;
+pub use codec::{
+    canonicalize_proof, compress_snarkjs_proof, proof_to_snarkjs_json, verify_all_snarkjs,
+    vk_from_snarkjs_json, vk_to_snarkjs_json, write_public_json,
+};
+
+// Key artifacts
+pub use artifact::{parse_combined_artifact, CombinedKeys, COMBINED_ARTIFACT_MAGIC};
+pub use key_chunks::{reassemble_key, split_key_file};
+pub use key_format::{recompress_key_file, KeyFormat};
+pub use key_kind::{detect_key_type, KeyKind};
+pub use key_validation::validate_proving_key;
+
+// Build metadata
+pub use info::{build_info, BuildInfo};
+
+// Witness encodings
+#[cfg(feature = "streaming")]
+pub use witness::read_witness_streaming;
+pub use witness::{
+    canonicalize_witness_json, check_witness_matches_signals, find_duplicate_fields,
+    fit_witness_to_key, hex_fields_from_reader, parse_decimal_witness_json, parse_sparse_witness,
+    parse_sparse_witness_with_max_len, parse_witness_entries, parse_witness_entries_labeled,
+    witness_diff, WitnessEntry, MAX_HEX_TOKEN_LEN, MAX_SPARSE_WITNESS_LEN,
+};
+#[cfg(feature = "witness-calc")]
+pub use witness_calc::{calculate_witness, prove_from_inputs};
 
 // Field conversion
-pub use field::{from_decimal_str, from_hex_le};
+pub use field::{
+    decode_hex, from_decimal_str, from_hex_be, from_hex_le, strip_hex_prefix, to_decimal_str,
+};
 
-// Backward-compat aliases
-pub use utils::{decimal_to_field, hex_to_field};
+// Backward-compat aliases and BN254 helpers
+pub use utils::{
+    bn254_scalar_modulus, decimal_to_field, field_add, field_eq_hex, field_from_bytes_be,
+    field_from_bytes_le, field_from_i64, field_from_limbs, field_from_u64, field_inv, field_mul,
+    field_neg, field_sub, hex_to_field, hex_to_field_be, hex_to_field_with_flag,
+    BN254_SCALAR_MODULUS_HEX,
+};
 
 // WASM re-exports
 #[cfg(feature = "wasm")]
-pub use wasm::{compress_snarkjs_proof_wasm, generate_proof_from_decimal_wasm, init_panic_hook};
+pub use wasm::{
+    compress_snarkjs_proof_wasm, convert_proof_wasm, generate_proof_from_decimal_wasm,
+    generate_proof_wasm_async, generate_proof_with_handle_wasm, init_panic_hook,
+    load_proving_key_wasm, release_proving_key_wasm, set_key_registry_capacity,
+};
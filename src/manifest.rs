@@ -0,0 +1,139 @@
+use std::str::FromStr;
+
+use ark_bn254::Fr as Bn254Fr;
+use serde::Deserialize;
+
+use crate::circuit_type::CircuitType;
+use crate::field::{from_hex_le, to_le_bytes_32};
+
+/// Length of an arkworks compressed BN254 Groth16 proof.
+const COMPRESSED_PROOF_LEN: usize = 128;
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProofManifest {
+    proof: String,
+    public_signals: Vec<String>,
+    circuit: String,
+}
+
+/// Structural checks on a `{proof, public_signals, circuit}` manifest, before verification.
+///
+/// Checks that the circuit is known, the signal count matches it, every signal is a
+/// canonical `0x`-prefixed 32-byte LE hex field element, and the proof is 128 bytes.
+/// Does not verify the proof.
+pub fn validate_manifest(json: &str) -> Result<(), String> {
+    let manifest: ProofManifest =
+        serde_json::from_str(json).map_err(|e| format!("Invalid manifest JSON: {e}"))?;
+
+    let circuit = CircuitType::from_str(&manifest.circuit)?;
+    if manifest.public_signals.len() != circuit.num_public_signals() {
+        return Err(format!(
+            "{} expects {} public signals, manifest has {}",
+            circuit.as_str(),
+            circuit.num_public_signals(),
+            manifest.public_signals.len()
+        ));
+    }
+    for (i, signal) in manifest.public_signals.iter().enumerate() {
+        check_canonical_signal(signal).map_err(|e| format!("public_signals[{i}]: {e}"))?;
+    }
+
+    let proof_hex = manifest
+        .proof
+        .strip_prefix("0x")
+        .ok_or("proof must be 0x-prefixed hex")?;
+    let proof = hex::decode(proof_hex).map_err(|e| format!("proof is not valid hex: {e}"))?;
+    if proof.len() != COMPRESSED_PROOF_LEN {
+        return Err(format!(
+            "proof must be {COMPRESSED_PROOF_LEN} bytes, got {}",
+            proof.len()
+        ));
+    }
+    Ok(())
+}
+
+/// A canonical signal is exactly the re-encoding of the field element it parses to.
+fn check_canonical_signal(signal: &str) -> Result<(), String> {
+    let digits = signal.strip_prefix("0x").ok_or("must be 0x-prefixed")?;
+    if digits.len() != 64 {
+        return Err(format!("expected 64 hex digits, got {}", digits.len()));
+    }
+    let value: Bn254Fr = from_hex_le(signal)?;
+    if hex::encode(to_le_bytes_32(&value)) != digits {
+        return Err("not a canonical field element (uppercase or >= modulus)".into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signal(v: u8) -> String {
+        format!("0x{v:02x}{}", "00".repeat(31))
+    }
+
+    fn manifest(proof_len: usize, signals: &[String], circuit: &str) -> String {
+        serde_json::json!({
+            "proof": format!("0x{}", "ab".repeat(proof_len)),
+            "public_signals": signals,
+            "circuit": circuit,
+        })
+        .to_string()
+    }
+
+    fn signals(n: u8) -> Vec<String> {
+        (1..=n).map(signal).collect()
+    }
+
+    #[test]
+    fn test_valid_manifest() {
+        validate_manifest(&manifest(128, &signals(5), "unshield")).unwrap();
+        validate_manifest(&manifest(128, &signals(4), "disclosure")).unwrap();
+    }
+
+    #[test]
+    fn test_unknown_circuit() {
+        let err = validate_manifest(&manifest(128, &signals(5), "shield")).unwrap_err();
+        assert!(err.contains("Unknown circuit type"));
+    }
+
+    #[test]
+    fn test_wrong_signal_count() {
+        let err = validate_manifest(&manifest(128, &signals(4), "transfer")).unwrap_err();
+        assert!(err.contains("expects 5"));
+    }
+
+    #[test]
+    fn test_non_canonical_signals() {
+        let mut s = signals(5);
+        s[2] = "0x03".into();
+        assert!(validate_manifest(&manifest(128, &s, "unshield"))
+            .unwrap_err()
+            .contains("public_signals[2]"));
+
+        s[2] = format!("0xAB{}", "00".repeat(31));
+        assert!(validate_manifest(&manifest(128, &s, "unshield")).is_err());
+
+        // r itself, little-endian: reduces to zero, so it is not canonical.
+        let mut r = hex::decode(&crate::utils::BN254_SCALAR_MODULUS_HEX[2..]).unwrap();
+        r.reverse();
+        s[2] = format!("0x{}", hex::encode(r));
+        assert!(validate_manifest(&manifest(128, &s, "unshield"))
+            .unwrap_err()
+            .contains("canonical"));
+    }
+
+    #[test]
+    fn test_wrong_proof_length() {
+        let err = validate_manifest(&manifest(64, &signals(5), "unshield")).unwrap_err();
+        assert!(err.contains("128 bytes"));
+    }
+
+    #[test]
+    fn test_malformed_json() {
+        assert!(validate_manifest("{").is_err());
+        assert!(validate_manifest(r#"{"proof":"0x","circuit":"unshield"}"#).is_err());
+    }
+}
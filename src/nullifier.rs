@@ -0,0 +1,67 @@
+use std::collections::HashSet;
+
+use ark_bn254::Fr as Bn254Fr;
+
+use crate::field::{from_hex_le, to_le_bytes_32};
+
+/// Check that the nullifier in `signals[nullifier_index]` has not been spent.
+///
+/// The signal is canonicalized (reduced mod `r`, 32-byte lowercase little-endian hex
+/// with `0x`) before the lookup, so `seen` must hold nullifiers in that form — the form
+/// this crate emits public signals in. Returns `Ok(true)` for a fresh nullifier and
+/// `Ok(false)` for one already in `seen`.
+pub fn check_nullifier(
+    signals: &[String],
+    nullifier_index: usize,
+    seen: &HashSet<String>,
+) -> Result<bool, String> {
+    let signal = signals.get(nullifier_index).ok_or_else(|| {
+        format!(
+            "Nullifier index {nullifier_index} out of range for {} public signals",
+            signals.len()
+        )
+    })?;
+    let nullifier = from_hex_le::<Bn254Fr>(signal)?;
+    let canonical = format!("0x{}", hex::encode(to_le_bytes_32(&nullifier)));
+    Ok(!seen.contains(&canonical))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn canonical(v: u64) -> String {
+        format!("0x{:02x}{}", v, "00".repeat(31))
+    }
+
+    #[test]
+    fn test_repeated_nullifier_is_rejected() {
+        let signals = vec![canonical(1), canonical(7)];
+        let mut seen = HashSet::new();
+        assert!(check_nullifier(&signals, 1, &seen).unwrap());
+        seen.insert(signals[1].clone());
+        assert!(!check_nullifier(&signals, 1, &seen).unwrap());
+        assert!(check_nullifier(&signals, 0, &seen).unwrap());
+    }
+
+    #[test]
+    fn test_nullifier_is_canonicalized_before_lookup() {
+        let seen = HashSet::from([canonical(7)]);
+        for variant in [
+            "0x07",
+            "0X07",
+            "07",
+            &canonical(7).to_uppercase().replace("0X", "0x"),
+        ] {
+            let signals = vec![variant.to_string()];
+            assert!(!check_nullifier(&signals, 0, &seen).unwrap(), "{variant}");
+        }
+    }
+
+    #[test]
+    fn test_nullifier_index_out_of_range() {
+        let err = check_nullifier(&[canonical(1)], 1, &HashSet::new()).unwrap_err();
+        assert!(err.contains("out of range"));
+        assert!(check_nullifier(&["0xzz".into()], 0, &HashSet::new()).is_err());
+    }
+}
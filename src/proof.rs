@@ -1,8 +1,11 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use ark_bn254::Fr as Bn254Fr;
 
 use crate::error::ProofError;
 use crate::field::from_hex_le;
-use crate::prover::prove_from_witness;
+use crate::prover::{prove_from_witness, ProofGenerator};
+use crate::witness::at_index;
 
 /// Generate a Groth16 proof from a hex-LE witness array and a `.ark` proving key at `path`.
 ///
@@ -13,11 +16,7 @@ pub fn generate_proof_from_witness(
     proving_key_path: &str,
     num_public_signals: usize,
 ) -> Result<Vec<u8>, ProofError> {
-    let witness: Vec<Bn254Fr> = witness_hex
-        .iter()
-        .map(|h| from_hex_le(h))
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(ProofError::WitnessConversion)?;
+    let witness = parse_witness_hex(witness_hex)?;
 
     let pk_bytes =
         std::fs::read(proving_key_path).map_err(|e| ProofError::ProvingKeyIo(e.to_string()))?;
@@ -25,6 +24,45 @@ pub fn generate_proof_from_witness(
     prove_from_witness(&pk_bytes, witness, num_public_signals)
 }
 
+/// [`generate_proof_from_witness`] that gives up with [`ProofError::Cancelled`]
+/// once `cancel` is set.
+///
+/// arkworks proving cannot be interrupted, so the flag is only checked at coarse
+/// boundaries: before reading the key, after deserializing it, and right before
+/// proving. A proof that has already started runs to completion; its result is
+/// still returned. Share the flag with the canceller via `Arc<AtomicBool>`.
+pub fn generate_proof_cancellable(
+    witness_hex: &[String],
+    proving_key_path: &str,
+    num_public_signals: usize,
+    cancel: &AtomicBool,
+) -> Result<Vec<u8>, ProofError> {
+    let check = || {
+        if cancel.load(Ordering::Relaxed) {
+            Err(ProofError::Cancelled)
+        } else {
+            Ok(())
+        }
+    };
+
+    let witness = parse_witness_hex(witness_hex)?;
+    check()?;
+    let pk_bytes =
+        std::fs::read(proving_key_path).map_err(|e| ProofError::ProvingKeyIo(e.to_string()))?;
+    let generator = ProofGenerator::from_bytes(&pk_bytes, num_public_signals)?;
+    check()?;
+    generator.prove(witness)
+}
+
+fn parse_witness_hex(witness_hex: &[String]) -> Result<Vec<Bn254Fr>, ProofError> {
+    witness_hex
+        .iter()
+        .enumerate()
+        .map(|(i, h)| from_hex_le(h).map_err(|e| at_index(None, i, e)))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(ProofError::WitnessConversion)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -67,8 +105,32 @@ mod tests {
 
     #[test]
     fn test_generate_proof_invalid_hex_in_witness() {
-        let witness_hex = vec!["0xGGGGGGGG".to_string()];
+        let witness_hex = vec!["0x01".to_string(), "0xGGGGGGGG".to_string()];
         let result = generate_proof_from_witness(&witness_hex, "/fake/path.ark", 5);
-        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("witness[1]: "));
+    }
+
+    #[test]
+    fn test_cancel_before_proving_aborts() {
+        use crate::test_utils::{setup, to_hex_le};
+        use ark_serialize::CanonicalSerialize;
+
+        let (pk, _) = setup(1);
+        let path = "/tmp/test_generate_proof_cancellable.ark";
+        let mut bytes = Vec::new();
+        pk.serialize_compressed(&mut bytes).unwrap();
+        std::fs::write(path, bytes).unwrap();
+        let witness: Vec<String> = [1u64, 15, 3, 5]
+            .map(|v| to_hex_le(Bn254Fr::from(v)))
+            .to_vec();
+
+        let cancel = std::sync::Arc::new(AtomicBool::new(true));
+        let result = generate_proof_cancellable(&witness, path, 1, &cancel);
+        assert!(matches!(result, Err(ProofError::Cancelled)));
+
+        cancel.store(false, Ordering::Relaxed);
+        let proof = generate_proof_cancellable(&witness, path, 1, &cancel);
+        let _ = std::fs::remove_file(path);
+        assert_eq!(proof.unwrap().len(), 128);
     }
 }
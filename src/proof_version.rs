@@ -0,0 +1,102 @@
+use ark_bn254::Bn254;
+use ark_groth16::Proof as ArkProof;
+use ark_serialize::CanonicalDeserialize;
+
+/// Proof encodings that can follow the version byte of a versioned proof.
+///
+/// Unprefixed 128-byte proofs stay the default output everywhere; versioning is
+/// opt-in through [`write_proof_versioned`], so future encodings can coexist with
+/// today's without guessing from lengths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ProofVersion {
+    /// arkworks compressed BN254 proof, 128 bytes — the crate's raw format.
+    V0 = 0x00,
+}
+
+impl ProofVersion {
+    pub fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0x00 => Some(ProofVersion::V0),
+            _ => None,
+        }
+    }
+}
+
+/// Prefix a raw compressed proof with `version`'s byte, after checking that it
+/// decodes.
+///
+/// Version bytes never collide with [curve tags](crate::Curve) (`0x01`, `0x02`),
+/// but a versioned proof is not itself accepted by [`verify`](crate::verify):
+/// unwrap it with [`read_proof_versioned`] first.
+pub fn write_proof_versioned(proof_bytes: &[u8], version: ProofVersion) -> Result<Vec<u8>, String> {
+    match version {
+        ProofVersion::V0 => {
+            decode_v0(proof_bytes)?;
+            let mut versioned = Vec::with_capacity(proof_bytes.len() + 1);
+            versioned.push(version as u8);
+            versioned.extend_from_slice(proof_bytes);
+            Ok(versioned)
+        }
+    }
+}
+
+/// Read a versioned proof, dispatching on its first byte, and return the raw
+/// compressed (version 0) proof bytes. Unknown versions are an error.
+pub fn read_proof_versioned(bytes: &[u8]) -> Result<Vec<u8>, String> {
+    let (&byte, payload) = bytes.split_first().ok_or("Versioned proof is empty")?;
+    match ProofVersion::from_byte(byte) {
+        Some(ProofVersion::V0) => {
+            decode_v0(payload)?;
+            Ok(payload.to_vec())
+        }
+        None => Err(format!("Unsupported proof version 0x{byte:02x}")),
+    }
+}
+
+fn decode_v0(payload: &[u8]) -> Result<(), String> {
+    let mut reader = payload;
+    ArkProof::<Bn254>::deserialize_compressed(&mut reader)
+        .map_err(|e| format!("Invalid version 0 proof: {e}"))?;
+    if !reader.is_empty() {
+        return Err(format!(
+            "Invalid version 0 proof: {} trailing bytes",
+            reader.len()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{prove, setup};
+
+    #[test]
+    fn test_versioned_proof_round_trip() {
+        let (pk, vk) = setup(1);
+        let (proof, signals) = prove(&pk, 3, 5);
+
+        let versioned = write_proof_versioned(&proof, ProofVersion::V0).unwrap();
+        assert_eq!(versioned.len(), 129);
+        assert_eq!(versioned[0], 0x00);
+        let raw = read_proof_versioned(&versioned).unwrap();
+        assert_eq!(raw, proof);
+        assert!(crate::verify(&raw, &vk, &signals).unwrap());
+    }
+
+    #[test]
+    fn test_versioned_proof_rejects_unknown_version() {
+        let (pk, _) = setup(1);
+        let (proof, _) = prove(&pk, 3, 5);
+        let mut versioned = write_proof_versioned(&proof, ProofVersion::V0).unwrap();
+        versioned[0] = 0x07;
+        assert_eq!(
+            read_proof_versioned(&versioned).unwrap_err(),
+            "Unsupported proof version 0x07"
+        );
+        assert!(read_proof_versioned(&[]).is_err());
+        assert!(read_proof_versioned(&versioned[..100]).is_err());
+        assert!(write_proof_versioned(&proof[..127], ProofVersion::V0).is_err());
+    }
+}
@@ -1,25 +1,23 @@
 use ark_bn254::{Bn254, Fr as Bn254Fr};
-use ark_groth16::{Groth16, ProvingKey};
+use ark_ff::UniformRand;
+use ark_groth16::{Groth16, Proof as ArkProof, ProvingKey, VerifyingKey};
+use ark_relations::r1cs::{
+    ConstraintMatrices, ConstraintSynthesizer, ConstraintSystem, OptimizationGoal, SynthesisMode,
+};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ark_snark::SNARK;
-use ark_std::rand::rngs::StdRng;
-use ark_std::rand::SeedableRng;
+use ark_std::rand::rngs::{OsRng, StdRng};
+use ark_std::rand::{CryptoRng, RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use zeroize::{Zeroize, Zeroizing};
 
 use crate::circuit::WitnessCircuit;
 use crate::error::ProofError;
+use crate::field::{from_hex_le, to_le_bytes_32};
+use crate::witness::{at_index, fit_witness_to_key};
+use crate::zkey::{read_zkey, CircomReduction};
 
-/// Generate a Groth16 compressed proof from a pre-computed witness.
-///
-/// * `pk_bytes` — raw bytes of an arkworks compressed proving key (`.ark` format).
-/// * `witness`  — full Circom witness vector (index 0 = constant 1).
-/// * `num_public_signals` — number of public signals (indices 1..=n in the witness).
-///
-/// Returns 128 compressed proof bytes on success.
-pub fn prove_from_witness(
-    pk_bytes: &[u8],
-    witness: Vec<Bn254Fr>,
-    num_public_signals: usize,
-) -> Result<Vec<u8>, ProofError> {
+fn validate_witness(witness: &[Bn254Fr], num_public_signals: usize) -> Result<(), ProofError> {
     if witness.is_empty() {
         return Err(ProofError::WitnessEmpty);
     }
@@ -34,29 +32,501 @@ pub fn prove_from_witness(
             witness.len()
         )));
     }
+    Ok(())
+}
+
+/// RNG behind the proof blinding factors `r` and `s` in [`ProofGenerator::prove`].
+///
+/// All variants are cryptographically secure and seeded from the OS; the choice
+/// lets auditors pin the primitive. For reproducible proofs in tests, pass a seeded
+/// RNG to [`ProofGenerator::prove_with_rng`] instead.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RngKind {
+    /// `rand`'s `StdRng` (currently ChaCha12).
+    #[default]
+    Std,
+    /// ChaCha20 (`rand_chacha::ChaCha20Rng`).
+    ChaCha20,
+    /// The operating system generator directly, with no userspace state.
+    OsRng,
+}
 
-    let pk = ProvingKey::<Bn254>::deserialize_compressed(pk_bytes)
-        .map_err(|e| ProofError::ProvingKeyParse(e.to_string()))?;
+/// Reusable prover holding a deserialized proving key.
+///
+/// Deserializing a large `.ark` key dominates single-proof latency; services proving
+/// repeatedly for the same circuit should load it once and call [`ProofGenerator::prove`].
+pub struct ProofGenerator {
+    pk: ProvingKey<Bn254>,
+    matrices: Option<ConstraintMatrices<Bn254Fr>>,
+    pad_witness: bool,
+    /// Keys imported from snarkjs need snarkjs' QAP witness map.
+    circom_reduction: bool,
+    rng: RngKind,
+    #[cfg(any(test, feature = "testing"))]
+    rng_seed: Option<u64>,
+}
 
-    let circuit = WitnessCircuit {
-        witness,
-        num_public_signals,
-    };
-    let mut rng = StdRng::from_entropy();
-    let proof = Groth16::<Bn254>::prove(&pk, circuit, &mut rng)
+impl ProofGenerator {
+    /// Load from arkworks compressed proving key bytes.
+    ///
+    /// Fails if the key's IC points (`gamma_abc_g1`) do not cover exactly
+    /// `num_public_signals` inputs, rather than producing unverifiable proofs later.
+    pub fn from_bytes(pk_bytes: &[u8], num_public_signals: usize) -> Result<Self, ProofError> {
+        let generator = Self::from_key_bytes(pk_bytes)?;
+        check_ic_len(generator.pk.vk.gamma_abc_g1.len(), num_public_signals)?;
+        Ok(generator)
+    }
+
+    /// Attach the circuit's R1CS constraint matrices (see [`constraint_matrices`]).
+    ///
+    /// With matrices cached, [`prove`](Self::prove) computes the QAP witness map
+    /// straight from them and the full witness, skipping constraint synthesis on
+    /// every call. The saving is modest because MSMs and FFTs dominate: on a
+    /// 4096-constraint squaring chain (`bench-groth16 --synthetic 4096 20`, release,
+    /// single core) it was ~2% (594 ms → 581 ms). Circuits with expensive synthesis
+    /// gain more.
+    pub fn with_constraint_matrices(
+        mut self,
+        matrices: ConstraintMatrices<Bn254Fr>,
+    ) -> Result<Self, ProofError> {
+        if matrices.num_instance_variables != self.num_public_signals() + 1 {
+            return Err(ProofError::NumPublicSignals(format!(
+                "matrices have {} public inputs, {} requested",
+                matrices.num_instance_variables - 1,
+                self.num_public_signals()
+            )));
+        }
+        self.matrices = Some(matrices);
+        Ok(self)
+    }
+
+    /// Zero-pad witnesses shorter than [`witness_len`](Self::witness_len) before
+    /// proving (see [`fit_witness_to_key`]). Off by default.
+    pub fn with_witness_padding(mut self, pad: bool) -> Self {
+        self.pad_witness = pad;
+        self
+    }
+
+    /// Select the RNG [`prove`](Self::prove) draws blinding factors from.
+    pub fn with_rng(mut self, rng: RngKind) -> Self {
+        self.rng = rng;
+        self
+    }
+
+    /// Seed the [`with_rng`](Self::with_rng) generator so [`prove`](Self::prove) is
+    /// reproducible. Tests only: [`RngKind::OsRng`] cannot be seeded and ignores it.
+    #[cfg(any(test, feature = "testing"))]
+    pub fn with_rng_seed(mut self, seed: u64) -> Self {
+        self.rng_seed = Some(seed);
+        self
+    }
+
+    /// Number of witness entries the key expects, including the constant at index 0.
+    pub fn witness_len(&self) -> usize {
+        self.pk.vk.gamma_abc_g1.len() + self.pk.l_query.len()
+    }
+
+    /// Load from arkworks compressed proving key bytes, taking the public-signal
+    /// count from the key instead of the caller.
+    pub fn from_key_bytes(pk_bytes: &[u8]) -> Result<Self, ProofError> {
+        let pk = ProvingKey::<Bn254>::deserialize_compressed(pk_bytes)
+            .map_err(|e| ProofError::ProvingKeyParse(e.to_string()))?;
+        Self::from_proving_key(pk)
+    }
+
+    /// Wrap an already deserialized proving key.
+    pub(crate) fn from_proving_key(pk: ProvingKey<Bn254>) -> Result<Self, ProofError> {
+        if pk.vk.gamma_abc_g1.is_empty() {
+            return Err(ProofError::ProvingKeyParse("key has no IC points".into()));
+        }
+        Ok(Self {
+            pk,
+            matrices: None,
+            pad_witness: false,
+            circom_reduction: false,
+            rng: RngKind::default(),
+            #[cfg(any(test, feature = "testing"))]
+            rng_seed: None,
+        })
+    }
+
+    /// Number of public signals the key was set up for (`gamma_abc_g1.len() - 1`).
+    pub fn num_public_signals(&self) -> usize {
+        self.pk.vk.gamma_abc_g1.len() - 1
+    }
+
+    /// Witness indices holding the public signals, in IC order.
+    ///
+    /// Groth16 keys (arkworks and snarkjs alike) number instance variables first, so
+    /// IC point `i` pairs with witness index `i` for `i` in `1..gamma_abc_g1.len()`.
+    /// Index 0 is the constant 1 and is not a signal. Use this instead of hardcoding
+    /// `1..=n` when extracting signals from a witness.
+    pub fn public_signal_indices(&self) -> Vec<usize> {
+        (1..self.pk.vk.gamma_abc_g1.len()).collect()
+    }
+
+    /// Load from a snarkjs `.zkey` (see [`read_zkey`](crate::read_zkey)).
+    ///
+    /// The constraint matrices come from the zkey itself, and proofs use snarkjs'
+    /// QAP witness map, so they verify against the zkey's verifying key.
+    pub fn from_zkey_bytes(zkey_bytes: &[u8]) -> Result<Self, ProofError> {
+        let (pk, matrices) = read_zkey(zkey_bytes).map_err(ProofError::ProvingKeyParse)?;
+        let mut generator = Self::from_proving_key(pk)?.with_constraint_matrices(matrices)?;
+        generator.circom_reduction = true;
+        Ok(generator)
+    }
+
+    /// Load from an `.ark` proving key file.
+    pub fn from_file(path: &str, num_public_signals: usize) -> Result<Self, ProofError> {
+        let pk_bytes = std::fs::read(path).map_err(|e| ProofError::ProvingKeyIo(e.to_string()))?;
+        Self::from_bytes(&pk_bytes, num_public_signals)
+    }
+
+    /// Generate a compressed proof for a full Circom witness (index 0 = constant 1).
+    pub fn prove(&self, witness: Vec<Bn254Fr>) -> Result<Vec<u8>, ProofError> {
+        #[cfg(any(test, feature = "testing"))]
+        if let Some(seed) = self.rng_seed {
+            return match self.rng {
+                RngKind::Std => self.prove_with_rng(witness, &mut StdRng::seed_from_u64(seed)),
+                RngKind::ChaCha20 => {
+                    self.prove_with_rng(witness, &mut ChaCha20Rng::seed_from_u64(seed))
+                }
+                RngKind::OsRng => self.prove_with_rng(witness, &mut OsRng),
+            };
+        }
+        match self.rng {
+            RngKind::Std => self.prove_with_rng(witness, &mut StdRng::from_entropy()),
+            RngKind::ChaCha20 => self.prove_with_rng(witness, &mut ChaCha20Rng::from_entropy()),
+            RngKind::OsRng => self.prove_with_rng(witness, &mut OsRng),
+        }
+    }
+
+    /// [`prove`](Self::prove) with caller-supplied randomness for the blinding
+    /// factors `r` and `s`. Only pass a seeded RNG in tests: reusing blinders
+    /// across proofs breaks zero-knowledge.
+    pub fn prove_with_rng<R: RngCore + CryptoRng>(
+        &self,
+        witness: Vec<Bn254Fr>,
+        rng: &mut R,
+    ) -> Result<Vec<u8>, ProofError> {
+        let witness = if self.pad_witness {
+            fit_witness_to_key(witness, self.witness_len(), true)
+                .map_err(ProofError::WitnessConversion)?
+        } else {
+            witness
+        };
+        validate_witness(&witness, self.num_public_signals())?;
+
+        let proof = match &self.matrices {
+            Some(matrices) => self.prove_with_matrices(matrices, &witness, rng)?,
+            None => {
+                let circuit = WitnessCircuit {
+                    witness,
+                    num_public_signals: self.num_public_signals(),
+                };
+                Groth16::<Bn254>::prove(&self.pk, circuit, rng)
+                    .map_err(|e| ProofError::ProveGeneration(e.to_string()))?
+            }
+        };
+
+        let mut proof_bytes = Vec::new();
+        proof
+            .serialize_compressed(&mut proof_bytes)
+            .map_err(|e| ProofError::ProofSerialization(e.to_string()))?;
+
+        Ok(proof_bytes)
+    }
+
+    /// [`prove`](Self::prove), also returning the public signals (witness indices
+    /// `1..=num_public_signals()`) as `0x` 32-byte LE hex.
+    pub fn prove_with_public_signals(
+        &self,
+        witness: Vec<Bn254Fr>,
+    ) -> Result<(Vec<u8>, Vec<String>), ProofError> {
+        let public_signals = witness
+            .iter()
+            .skip(1)
+            .take(self.num_public_signals())
+            .map(|f| format!("0x{}", hex::encode(to_le_bytes_32(f))))
+            .collect();
+        let proof = self.prove(witness)?;
+        Ok((proof, public_signals))
+    }
+
+    /// [`prove_with_public_signals`](Self::prove_with_public_signals) for a witness
+    /// read from a JSON stream by [`read_witness_streaming`](crate::read_witness_streaming),
+    /// so only the field vector is held in memory, never the JSON text.
+    #[cfg(feature = "streaming")]
+    pub fn prove_from_reader<R: std::io::Read>(
+        &self,
+        reader: R,
+    ) -> Result<(Vec<u8>, Vec<String>), ProofError> {
+        self.prove_with_public_signals(crate::witness::read_witness_streaming(reader)?)
+    }
+
+    /// Prove an all-zero witness (constant 1 at index 0) and verify it against the
+    /// key's own VK, as a startup or readiness check that the key and prover agree.
+    ///
+    /// Circuits the zero witness does not satisfy (e.g. ones with `x * inv = 1`
+    /// constraints) need [`self_test_with`](Self::self_test_with) and a known-good
+    /// canary witness instead.
+    pub fn self_test(&self) -> Result<(), String> {
+        let mut witness = vec![Bn254Fr::from(0u64); self.witness_len()];
+        witness[0] = Bn254Fr::from(1u64);
+        self.self_test_with(witness)
+    }
+
+    /// [`self_test`](Self::self_test) with a caller-provided canary witness.
+    pub fn self_test_with(&self, canary_witness: Vec<Bn254Fr>) -> Result<(), String> {
+        let (proof, public_signals) = self
+            .prove_with_public_signals(canary_witness)
+            .map_err(|e| format!("self-test proving failed: {e}"))?;
+        match crate::verifier::verify_borrowed(&self.pk.vk, &proof, &public_signals) {
+            Ok(true) => Ok(()),
+            Ok(false) => Err("self-test proof does not verify against the key's VK".into()),
+            Err(e) => Err(format!("self-test verification failed: {e}")),
+        }
+    }
+
+    /// Prove every witness in parallel, returning proofs in input order.
+    ///
+    /// Runs on a dedicated rayon pool of `max_threads` threads (all cores when `None`)
+    /// instead of the global pool, so a batch can be capped on shared hardware. The
+    /// first failing witness fails the whole batch.
+    #[cfg(feature = "parallel")]
+    pub fn prove_batch(
+        &self,
+        witnesses: Vec<Vec<Bn254Fr>>,
+        max_threads: Option<usize>,
+    ) -> Result<Vec<Vec<u8>>, ProofError> {
+        use rayon::prelude::*;
+
+        if max_threads == Some(0) {
+            return Err(ProofError::ProveGeneration(
+                "max_threads must be at least 1".into(),
+            ));
+        }
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(max_threads.unwrap_or(0))
+            .build()
+            .map_err(|e| ProofError::ProveGeneration(e.to_string()))?;
+        pool.install(|| {
+            witnesses
+                .into_par_iter()
+                .map(|witness| self.prove(witness))
+                .collect()
+        })
+    }
+
+    fn prove_with_matrices<R: RngCore>(
+        &self,
+        matrices: &ConstraintMatrices<Bn254Fr>,
+        witness: &[Bn254Fr],
+        rng: &mut R,
+    ) -> Result<ArkProof<Bn254>, ProofError> {
+        let expected = matrices.num_instance_variables + matrices.num_witness_variables;
+        if witness.len() != expected {
+            return Err(ProofError::WitnessConversion(format!(
+                "witness length {} does not match circuit ({expected} variables)",
+                witness.len()
+            )));
+        }
+        // Same draw order as `Groth16::prove`, so seeded runs are reproducible.
+        let r = Bn254Fr::rand(rng);
+        let s = Bn254Fr::rand(rng);
+        let (num_inputs, num_constraints) =
+            (matrices.num_instance_variables, matrices.num_constraints);
+        if self.circom_reduction {
+            Groth16::<Bn254, CircomReduction>::create_proof_with_reduction_and_matrices(
+                &self.pk,
+                r,
+                s,
+                matrices,
+                num_inputs,
+                num_constraints,
+                witness,
+            )
+        } else {
+            Groth16::<Bn254>::create_proof_with_reduction_and_matrices(
+                &self.pk,
+                r,
+                s,
+                matrices,
+                num_inputs,
+                num_constraints,
+                witness,
+            )
+        }
+        .map_err(|e| ProofError::ProveGeneration(e.to_string()))
+    }
+}
+
+/// Synthesize `circuit` once in setup mode and extract its R1CS matrices,
+/// for caching on a [`ProofGenerator`].
+pub fn constraint_matrices<C: ConstraintSynthesizer<Bn254Fr>>(
+    circuit: C,
+) -> Result<ConstraintMatrices<Bn254Fr>, ProofError> {
+    let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+    // Must match the synthesis settings arkworks uses for setup and proving.
+    cs.set_optimization_goal(OptimizationGoal::Constraints);
+    cs.set_mode(SynthesisMode::Setup);
+    circuit
+        .generate_constraints(cs.clone())
         .map_err(|e| ProofError::ProveGeneration(e.to_string()))?;
+    cs.finalize();
+    cs.to_matrices()
+        .ok_or_else(|| ProofError::ProveGeneration("constraint matrices unavailable".into()))
+}
+
+/// Run a circuit-specific Groth16 setup for `circuit`, refusing circuits with more
+/// than `max_constraints` constraints.
+///
+/// The circuit is synthesized once in setup mode to count its constraints; that pass
+/// holds no assignments, and the limit is checked before key generation allocates the
+/// evaluation domain and key vectors, which dominate setup memory. Keys produced here
+/// come from a single party's randomness and are for testing and private deployments,
+/// not a substitute for a ceremony.
+pub fn setup_keys<C, R>(
+    circuit: C,
+    max_constraints: usize,
+    rng: &mut R,
+) -> Result<(ProvingKey<Bn254>, VerifyingKey<Bn254>), ProofError>
+where
+    C: ConstraintSynthesizer<Bn254Fr> + Clone,
+    R: RngCore + CryptoRng,
+{
+    let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+    cs.set_optimization_goal(OptimizationGoal::Constraints);
+    cs.set_mode(SynthesisMode::Setup);
+    circuit
+        .clone()
+        .generate_constraints(cs.clone())
+        .map_err(|e| ProofError::Setup(e.to_string()))?;
+    let num_constraints = cs.num_constraints();
+    if num_constraints > max_constraints {
+        return Err(ProofError::Setup(format!(
+            "circuit has {num_constraints} constraints, limit is {max_constraints}"
+        )));
+    }
+    Groth16::<Bn254>::circuit_specific_setup(circuit, rng)
+        .map_err(|e| ProofError::Setup(e.to_string()))
+}
+
+/// A key with `ic_len` IC points supports `ic_len - 1` public inputs.
+pub(crate) fn check_ic_len(ic_len: usize, num_public_signals: usize) -> Result<(), ProofError> {
+    let supported = ic_len.saturating_sub(1);
+    if supported != num_public_signals {
+        return Err(ProofError::NumPublicSignals(format!(
+            "key supports {supported} public inputs, {num_public_signals} requested"
+        )));
+    }
+    Ok(())
+}
+
+/// Generate a Groth16 compressed proof from a pre-computed witness.
+///
+/// * `pk_bytes` — raw bytes of an arkworks compressed proving key (`.ark` format).
+/// * `witness`  — full Circom witness vector (index 0 = constant 1).
+/// * `num_public_signals` — number of public signals (indices 1..=n in the witness).
+///
+/// Returns 128 compressed proof bytes on success.
+pub fn prove_from_witness(
+    pk_bytes: &[u8],
+    witness: Vec<Bn254Fr>,
+    num_public_signals: usize,
+) -> Result<Vec<u8>, ProofError> {
+    validate_witness(&witness, num_public_signals)?;
+    ProofGenerator::from_bytes(pk_bytes, num_public_signals)?.prove(witness)
+}
 
-    let mut proof_bytes = Vec::new();
-    proof
-        .serialize_compressed(&mut proof_bytes)
-        .map_err(|e| ProofError::ProofSerialization(e.to_string()))?;
+/// Prove a witness whose private part is fetched only at prove time, e.g. from a vault.
+///
+/// `public_witness` holds the leading hex-LE entries (the constant 1 and the public
+/// signals); `loader` returns the remaining private entries. The loaded strings are
+/// zeroized as soon as they are parsed, as are the parsed field elements if any
+/// entry fails to parse. Otherwise the field elements move into the prover without
+/// a copy; the prover and arkworks' internal buffers do not zeroize them.
+pub fn prove_with_secret_loader(
+    public_witness: &[String],
+    loader: impl FnOnce() -> Result<Vec<String>, String>,
+    generator: &ProofGenerator,
+) -> Result<Vec<u8>, String> {
+    let mut secret = loader()?;
+    let mut witness = assemble_secret_witness(public_witness, &mut secret)?;
+    generator
+        .prove(std::mem::take(&mut *witness))
+        .map_err(|e| e.to_string())
+}
 
-    Ok(proof_bytes)
+/// Parse `public_witness ++ secret` into field elements, zeroizing every `secret`
+/// string in place whether or not parsing succeeds. The emptied strings keep their
+/// (wiped) allocations until `secret` is dropped.
+fn assemble_secret_witness(
+    public_witness: &[String],
+    secret: &mut [String],
+) -> Result<Zeroizing<Vec<Bn254Fr>>, String> {
+    let mut witness = Zeroizing::new(Vec::with_capacity(public_witness.len() + secret.len()));
+    let parsed = public_witness
+        .iter()
+        .chain(secret.iter())
+        .enumerate()
+        .try_for_each(|(i, hex)| {
+            witness.push(from_hex_le(hex).map_err(|e| at_index(None, i, e))?);
+            Ok::<_, String>(())
+        });
+    secret.iter_mut().for_each(Zeroize::zeroize);
+    parsed.map(|()| witness)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::TestCircuit;
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_prove_batch_single_thread() {
+        let (pk, vk) = crate::test_utils::setup(1);
+        let generator = ProofGenerator::from_proving_key(pk)
+            .unwrap()
+            .with_constraint_matrices(
+                constraint_matrices(TestCircuit { a: None, b: None }).unwrap(),
+            )
+            .unwrap();
+        let witnesses: Vec<Vec<Bn254Fr>> = (1..=4u64)
+            .map(|a| [1, a * 7, a, 7].map(Bn254Fr::from).to_vec())
+            .collect();
+        let proofs = generator.prove_batch(witnesses.clone(), Some(1)).unwrap();
+        assert_eq!(proofs.len(), 4);
+        for (proof, witness) in proofs.iter().zip(&witnesses) {
+            let signal = crate::test_utils::to_hex_le(witness[1]);
+            assert!(crate::verify(proof, &vk, &[signal]).unwrap());
+        }
+        assert!(generator.prove_batch(witnesses, Some(0)).is_err());
+    }
+
+    #[test]
+    fn test_setup_keys_within_limit_proves() {
+        let circuit = TestCircuit { a: None, b: None };
+        let (pk, vk) = setup_keys(circuit, 1, &mut StdRng::seed_from_u64(1)).unwrap();
+        let witness = [1u64, 15, 3, 5].map(Bn254Fr::from).to_vec();
+        let generator = ProofGenerator::from_proving_key(pk)
+            .unwrap()
+            .with_constraint_matrices(
+                constraint_matrices(TestCircuit { a: None, b: None }).unwrap(),
+            )
+            .unwrap();
+        let (proof, signals) = generator.prove_with_public_signals(witness).unwrap();
+        assert!(crate::verify(&proof, &vk, &signals).unwrap());
+    }
+
+    #[test]
+    fn test_setup_keys_rejects_over_limit_circuit() {
+        let circuit = TestCircuit { a: None, b: None };
+        let err = setup_keys(circuit, 0, &mut StdRng::seed_from_u64(1)).unwrap_err();
+        assert!(matches!(err, ProofError::Setup(_)));
+        assert!(err.to_string().contains("1 constraints, limit is 0"));
+    }
 
     #[test]
     fn test_empty_witness_is_rejected() {
@@ -94,6 +564,258 @@ mod tests {
         ));
     }
 
+    fn test_pk_bytes() -> Vec<u8> {
+        let (pk, _) = crate::test_utils::setup(1);
+        let mut bytes = Vec::new();
+        pk.serialize_compressed(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_generator_rejects_ic_mismatch() {
+        let err = ProofGenerator::from_bytes(&test_pk_bytes(), 2)
+            .err()
+            .unwrap();
+        assert!(err
+            .to_string()
+            .contains("key supports 1 public inputs, 2 requested"));
+    }
+
+    #[test]
+    fn test_prove_from_witness_rejects_ic_mismatch() {
+        let w = vec![Bn254Fr::from(1u64); 10];
+        let err = prove_from_witness(&test_pk_bytes(), w, 3).unwrap_err();
+        assert!(matches!(err, ProofError::NumPublicSignals(_)));
+    }
+
+    #[test]
+    fn test_generator_proves_with_matching_key() {
+        let generator = ProofGenerator::from_bytes(&test_pk_bytes(), 1).unwrap();
+        let w = [1u64, 15, 3, 5].map(Bn254Fr::from).to_vec();
+        assert_eq!(generator.prove(w).unwrap().len(), 128);
+    }
+
+    #[test]
+    fn test_generator_pads_short_witness_when_enabled() {
+        let generator = ProofGenerator::from_bytes(&test_pk_bytes(), 1).unwrap();
+        assert_eq!(generator.witness_len(), 4);
+        let w = [1u64, 0, 0].map(Bn254Fr::from).to_vec();
+
+        let padded = generator.with_witness_padding(true);
+        assert_eq!(padded.prove(w).unwrap().len(), 128);
+
+        let too_long = [1u64, 15, 3, 5, 7].map(Bn254Fr::from).to_vec();
+        let err = padded.prove(too_long).unwrap_err();
+        assert!(err.to_string().contains("key expects 4"));
+    }
+
+    #[test]
+    fn test_num_public_signals_derived_from_key() {
+        let generator = ProofGenerator::from_key_bytes(&test_pk_bytes()).unwrap();
+        assert_eq!(generator.num_public_signals(), 1);
+        assert_eq!(
+            ProofGenerator::from_bytes(&test_pk_bytes(), 1)
+                .unwrap()
+                .num_public_signals(),
+            1
+        );
+
+        let w = [1u64, 15, 3, 5].map(Bn254Fr::from).to_vec();
+        let (proof, signals) = generator.prove_with_public_signals(w).unwrap();
+        assert_eq!(proof.len(), 128);
+        assert_eq!(signals, vec![format!("0x0f{}", "00".repeat(31))]);
+    }
+
+    #[test]
+    fn test_generator_from_missing_file() {
+        let err = ProofGenerator::from_file("/nonexistent/pk.ark", 1)
+            .err()
+            .unwrap();
+        assert!(matches!(err, ProofError::ProvingKeyIo(_)));
+    }
+
+    #[test]
+    fn test_cached_matrices_match_circuit_proving() {
+        use crate::test_utils::{setup, to_hex_le, TestCircuit};
+        let (pk, vk) = setup(1);
+        let (a, b) = (Bn254Fr::from(3u64), Bn254Fr::from(5u64));
+
+        let matrices = constraint_matrices(TestCircuit { a: None, b: None }).unwrap();
+        let generator = ProofGenerator::from_bytes(&test_pk_bytes(), 1)
+            .unwrap()
+            .with_constraint_matrices(matrices)
+            .unwrap();
+        let cached = generator
+            .prove_with_rng(
+                vec![Bn254Fr::from(1u64), a * b, a, b],
+                &mut StdRng::seed_from_u64(9),
+            )
+            .unwrap();
+
+        let reference = Groth16::<Bn254>::prove(
+            &pk,
+            TestCircuit {
+                a: Some(a),
+                b: Some(b),
+            },
+            &mut StdRng::seed_from_u64(9),
+        )
+        .unwrap();
+        let mut expected = Vec::new();
+        reference.serialize_compressed(&mut expected).unwrap();
+
+        assert_eq!(cached, expected);
+        assert!(crate::verify(&cached, &vk, &[to_hex_le(a * b)]).unwrap());
+    }
+
+    #[test]
+    fn test_rng_kinds_prove_and_chacha20_seed_is_deterministic() {
+        use crate::test_utils::{setup, to_hex_le, TestCircuit};
+        let (_, vk) = setup(1);
+        let (a, b) = (Bn254Fr::from(3u64), Bn254Fr::from(5u64));
+        let witness = vec![Bn254Fr::from(1u64), a * b, a, b];
+        let generator = |kind| {
+            ProofGenerator::from_bytes(&test_pk_bytes(), 1)
+                .unwrap()
+                .with_constraint_matrices(
+                    constraint_matrices(TestCircuit { a: None, b: None }).unwrap(),
+                )
+                .unwrap()
+                .with_rng(kind)
+        };
+
+        for kind in [RngKind::Std, RngKind::ChaCha20, RngKind::OsRng] {
+            let proof = generator(kind).prove(witness.clone()).unwrap();
+            assert!(
+                crate::verify(&proof, &vk, &[to_hex_le(a * b)]).unwrap(),
+                "{kind:?}"
+            );
+        }
+
+        // `with_rng(ChaCha20)` draws from ChaCha20: a seeded run matches the same
+        // stream passed explicitly, and differs from `StdRng` under the same seed.
+        let seeded = |kind, seed| {
+            generator(kind)
+                .with_rng_seed(seed)
+                .prove(witness.clone())
+                .unwrap()
+        };
+        let chacha = seeded(RngKind::ChaCha20, 7);
+        assert_eq!(chacha, seeded(RngKind::ChaCha20, 7));
+        assert_ne!(chacha, seeded(RngKind::ChaCha20, 8));
+        assert_ne!(chacha, seeded(RngKind::Std, 7));
+        let explicit = generator(RngKind::Std)
+            .prove_with_rng(witness.clone(), &mut ChaCha20Rng::seed_from_u64(7))
+            .unwrap();
+        assert_eq!(chacha, explicit);
+    }
+
+    #[cfg(feature = "streaming")]
+    #[test]
+    fn test_prove_from_reader_verifies() {
+        use crate::test_utils::{setup, to_hex_le, TestCircuit};
+        let (_, vk) = setup(1);
+        let generator = ProofGenerator::from_bytes(&test_pk_bytes(), 1)
+            .unwrap()
+            .with_constraint_matrices(
+                constraint_matrices(TestCircuit { a: None, b: None }).unwrap(),
+            )
+            .unwrap();
+        let witness: Vec<String> = [1u64, 15, 3, 5]
+            .map(|v| to_hex_le(Bn254Fr::from(v)))
+            .to_vec();
+        let json = serde_json::json!({ "num_public_signals": 1, "witness": witness }).to_string();
+        // A tiny buffer makes the reader cross chunk boundaries mid-token.
+        let reader = std::io::BufReader::with_capacity(8, json.as_bytes());
+
+        let (proof, signals) = generator.prove_from_reader(reader).unwrap();
+        assert_eq!(signals, [witness[1].clone()]);
+        assert!(crate::verify(&proof, &vk, &signals).unwrap());
+        assert!(generator.prove_from_reader(&b"[\"0xZZ\"]"[..]).is_err());
+    }
+
+    #[test]
+    fn test_cached_matrices_reject_wrong_witness_length() {
+        let matrices =
+            constraint_matrices(crate::test_utils::TestCircuit { a: None, b: None }).unwrap();
+        let generator = ProofGenerator::from_bytes(&test_pk_bytes(), 1)
+            .unwrap()
+            .with_constraint_matrices(matrices)
+            .unwrap();
+        let err = generator.prove(vec![Bn254Fr::from(1u64); 5]).unwrap_err();
+        assert!(err.to_string().contains("does not match circuit"));
+    }
+
+    #[test]
+    fn test_prove_with_secret_loader_clears_secret() {
+        use crate::test_utils::to_hex_le;
+
+        let (_, vk) = crate::test_utils::setup(1);
+        let generator = ProofGenerator::from_bytes(&test_pk_bytes(), 1)
+            .unwrap()
+            .with_constraint_matrices(
+                constraint_matrices(TestCircuit { a: None, b: None }).unwrap(),
+            )
+            .unwrap();
+        let hex = |v: u64| to_hex_le(Bn254Fr::from(v));
+        let public = vec![hex(1), hex(15)];
+
+        let proof =
+            prove_with_secret_loader(&public, || Ok(vec![hex(3), hex(5)]), &generator).unwrap();
+        assert!(crate::verify(&proof, &vk, &public[1..]).unwrap());
+        assert_eq!(
+            prove_with_secret_loader(&public, || Err("vault sealed".into()), &generator)
+                .unwrap_err(),
+            "vault sealed"
+        );
+
+        // Each secret string's buffer is overwritten, not merely truncated.
+        let assert_wiped = |secret: &[String], probes: &[(*const u8, usize)]| {
+            assert_eq!(secret.len(), probes.len());
+            for (s, &(ptr, capacity)) in secret.iter().zip(probes) {
+                assert!(s.is_empty());
+                // Same live allocation, so reading it back is sound.
+                assert_eq!((s.as_ptr(), s.capacity()), (ptr, capacity));
+                let bytes = unsafe { std::slice::from_raw_parts(ptr, capacity) };
+                assert!(bytes.iter().all(|&b| b == 0), "{bytes:?}");
+            }
+        };
+        let probe = |secret: &[String]| -> Vec<(*const u8, usize)> {
+            secret.iter().map(|s| (s.as_ptr(), s.capacity())).collect()
+        };
+
+        let mut secret = vec![hex(3), hex(5)];
+        let probes = probe(&secret);
+        let witness = assemble_secret_witness(&public, &mut secret).unwrap();
+        assert_eq!(witness.len(), 4);
+        assert_wiped(&secret, &probes);
+
+        let mut secret = vec![hex(3), "0xZZ".to_string()];
+        let probes = probe(&secret);
+        let err = assemble_secret_witness(&public, &mut secret).unwrap_err();
+        assert!(err.starts_with("witness[3]:"), "{err}");
+        assert_wiped(&secret, &probes);
+    }
+
+    #[test]
+    fn test_self_test_passes_for_valid_generator() {
+        let matrices =
+            constraint_matrices(crate::test_utils::TestCircuit { a: None, b: None }).unwrap();
+        let generator = ProofGenerator::from_bytes(&test_pk_bytes(), 1).unwrap();
+        let canary = [1u64, 15, 3, 5].map(Bn254Fr::from).to_vec();
+        // Synthesis-free proofs of a non-trivial witness don't satisfy a constrained key.
+        assert!(generator.self_test_with(canary.clone()).is_err());
+
+        let generator = generator.with_constraint_matrices(matrices).unwrap();
+        generator.self_test().unwrap();
+        generator.self_test_with(canary).unwrap();
+        let bad_canary = [1u64, 16, 3, 5].map(Bn254Fr::from).to_vec();
+        assert!(generator
+            .self_test_with(bad_canary)
+            .unwrap_err()
+            .contains("does not verify"));
+    }
+
     #[test]
     fn test_error_messages_are_descriptive() {
         let result = prove_from_witness(b"dummy", vec![Bn254Fr::from(1u64); 10], 0);
@@ -104,4 +826,38 @@ mod tests {
         let msg2 = result2.unwrap_err().to_string();
         assert!(msg2.contains("Witness is empty"));
     }
+
+    #[test]
+    fn test_prove_with_imported_zkey() {
+        let zkey = include_bytes!("../tests/fixtures/multiplier.zkey");
+        let generator = ProofGenerator::from_zkey_bytes(zkey).unwrap();
+        let (pk, _) = read_zkey(zkey).unwrap();
+
+        // Multiplier circuit: witness [1, c, a, b] with c = a * b.
+        let witness: Vec<Bn254Fr> = [1u64, 33, 3, 11].map(Bn254Fr::from).to_vec();
+        let (proof, signals) = generator.prove_with_public_signals(witness).unwrap();
+        assert!(crate::verify(&proof, &pk.vk, &signals).unwrap());
+
+        let wrong = vec![crate::test_utils::to_hex_le(Bn254Fr::from(34u64))];
+        assert!(!crate::verify(&proof, &pk.vk, &wrong).unwrap());
+    }
+
+    #[test]
+    fn test_public_signal_indices_match_zkey_layout() {
+        let zkey = include_bytes!("../tests/fixtures/multiplier.zkey");
+        let generator = ProofGenerator::from_zkey_bytes(zkey).unwrap();
+        // multiplier_public.json is ["33"]: the output c at witness index 1.
+        assert_eq!(generator.public_signal_indices(), vec![1]);
+
+        let witness: Vec<Bn254Fr> = [1u64, 33, 3, 11].map(Bn254Fr::from).to_vec();
+        let (_, signals) = generator
+            .prove_with_public_signals(witness.clone())
+            .unwrap();
+        let from_indices: Vec<String> = generator
+            .public_signal_indices()
+            .into_iter()
+            .map(|i| crate::test_utils::to_hex_le(witness[i]))
+            .collect();
+        assert_eq!(from_indices, signals);
+    }
 }
@@ -0,0 +1,85 @@
+/// Base45 alphabet (RFC 9285): exactly the QR alphanumeric-mode character set.
+const BASE45_ALPHABET: &[u8; 45] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+/// Encode proof bytes as Base45 (RFC 9285) for QR alphanumeric mode.
+///
+/// Every character is in the QR alphanumeric set (`0-9`, `A-Z`, space and
+/// `$%*+-./:`), which packs 5.5 bits per character instead of byte mode's 8. Each
+/// two bytes become three characters, so a 128-byte compressed proof is 192
+/// characters and fits a version 10-M QR code (311 alphanumeric characters).
+/// The space character means the string must be quoted in URLs; decode with
+/// [`proof_from_qr_string`].
+pub fn proof_to_qr_string(proof_bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(proof_bytes.len().div_ceil(2) * 3);
+    let mut push = |value: usize, digits: usize| {
+        let mut value = value;
+        for _ in 0..digits {
+            out.push(BASE45_ALPHABET[value % 45] as char);
+            value /= 45;
+        }
+    };
+    for chunk in proof_bytes.chunks(2) {
+        match *chunk {
+            [hi, lo] => push(usize::from(hi) * 256 + usize::from(lo), 3),
+            [byte] => push(usize::from(byte), 2),
+            _ => unreachable!("chunks(2) yields one or two bytes"),
+        }
+    }
+    out
+}
+
+/// Decode a [`proof_to_qr_string`] string back into proof bytes.
+pub fn proof_from_qr_string(s: &str) -> Result<Vec<u8>, String> {
+    let digits = s
+        .bytes()
+        .map(|c| {
+            BASE45_ALPHABET
+                .iter()
+                .position(|&a| a == c)
+                .ok_or_else(|| format!("Invalid Base45 character {:?}", c as char))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut out = Vec::with_capacity(digits.len() / 3 * 2 + 1);
+    for group in digits.chunks(3) {
+        let value = group.iter().rev().fold(0, |acc, d| acc * 45 + d);
+        match group.len() {
+            3 if value <= 0xffff => out.extend([(value >> 8) as u8, value as u8]),
+            2 if value <= 0xff => out.push(value as u8),
+            1 => return Err("Base45 string has a dangling character".into()),
+            _ => return Err(format!("Base45 group {value} is out of range")),
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{prove, setup};
+
+    #[test]
+    fn test_qr_round_trip_fits_qr_capacity() {
+        let (pk, _) = setup(1);
+        let (proof, _) = prove(&pk, 3, 5);
+        let encoded = proof_to_qr_string(&proof);
+        assert_eq!(encoded.len(), 192);
+        assert!(encoded.len() <= 311);
+        assert!(encoded.bytes().all(|c| BASE45_ALPHABET.contains(&c)));
+        assert_eq!(proof_from_qr_string(&encoded).unwrap(), proof);
+    }
+
+    #[test]
+    fn test_qr_rfc9285_vectors() {
+        assert_eq!(proof_to_qr_string(b"AB"), "BB8");
+        assert_eq!(proof_to_qr_string(b"Hello!!"), "%69 VD92EX0");
+        assert_eq!(proof_to_qr_string(b"ietf!"), "QED8WEX0");
+        assert_eq!(proof_from_qr_string("QED8WEX0").unwrap(), b"ietf!");
+    }
+
+    #[test]
+    fn test_qr_rejects_invalid_strings() {
+        assert!(proof_from_qr_string("abc").is_err());
+        assert!(proof_from_qr_string("GGW").is_err());
+        assert!(proof_from_qr_string("BB8A").is_err());
+    }
+}
@@ -0,0 +1,195 @@
+/// A proof with its public signals, for transports that want one buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "msgpack", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProofResult {
+    /// Compressed proof bytes.
+    #[cfg_attr(feature = "msgpack", serde(with = "serde_bytes"))]
+    pub proof: Vec<u8>,
+    /// `0x` hex-LE public signals.
+    pub public_signals: Vec<String>,
+}
+
+impl From<(Vec<u8>, Vec<String>)> for ProofResult {
+    /// From the output of [`ProofGenerator::prove_with_public_signals`](crate::ProofGenerator::prove_with_public_signals).
+    fn from((proof, public_signals): (Vec<u8>, Vec<String>)) -> Self {
+        Self {
+            proof,
+            public_signals,
+        }
+    }
+}
+
+impl ProofResult {
+    /// Encode as one length-prefixed frame (all integers `u32` little-endian):
+    ///
+    /// ```text
+    /// [proof_len][proof bytes][n_signals]([signal_len][signal bytes])*
+    /// ```
+    ///
+    /// Each signal frame holds the signal string's UTF-8 bytes (66 for a canonical
+    /// `0x` + 64-digit signal), so signals round-trip exactly.
+    pub fn to_framed_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(bundle_size(self.proof.len(), &self.public_signals));
+        put_frame(&mut out, &self.proof);
+        out.extend((self.public_signals.len() as u32).to_le_bytes());
+        for signal in &self.public_signals {
+            put_frame(&mut out, signal.as_bytes());
+        }
+        out
+    }
+
+    /// Decode [`to_framed_bytes`](Self::to_framed_bytes) output. Trailing bytes are
+    /// rejected.
+    pub fn from_framed_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let mut rest = bytes;
+        let proof = take_frame(&mut rest)?.to_vec();
+        let n_signals = take_u32(&mut rest)?;
+        let public_signals = (0..n_signals)
+            .map(|_| {
+                let signal = take_frame(&mut rest)?;
+                String::from_utf8(signal.to_vec()).map_err(|_| "Signal is not UTF-8".to_string())
+            })
+            .collect::<Result<_, _>>()?;
+        if !rest.is_empty() {
+            return Err(format!("{} trailing bytes after frame", rest.len()));
+        }
+        Ok(Self {
+            proof,
+            public_signals,
+        })
+    }
+
+    /// Encode as MessagePack: a two-element array of the proof as `bin` and the
+    /// signals as strings. Smaller than the JSON output and cheaper to parse.
+    #[cfg(feature = "msgpack")]
+    pub fn to_msgpack(&self) -> Result<Vec<u8>, String> {
+        rmp_serde::to_vec(self).map_err(|e| format!("Failed to encode MessagePack: {e}"))
+    }
+
+    /// Decode [`to_msgpack`](Self::to_msgpack) output.
+    #[cfg(feature = "msgpack")]
+    pub fn from_msgpack(bytes: &[u8]) -> Result<Self, String> {
+        rmp_serde::from_slice(bytes).map_err(|e| format!("Failed to decode MessagePack: {e}"))
+    }
+}
+
+/// Exact length of [`ProofResult::to_framed_bytes`] for a `proof_len`-byte proof
+/// and these signals, so a single buffer can be allocated up front.
+pub fn bundle_size(proof_len: usize, signals: &[String]) -> usize {
+    FRAME_HEADER_LEN
+        + proof_len
+        + FRAME_HEADER_LEN
+        + signals
+            .iter()
+            .map(|s| FRAME_HEADER_LEN + s.len())
+            .sum::<usize>()
+}
+
+/// Every length and count in a frame is a `u32`.
+const FRAME_HEADER_LEN: usize = 4;
+
+fn put_frame(out: &mut Vec<u8>, data: &[u8]) {
+    out.extend((data.len() as u32).to_le_bytes());
+    out.extend_from_slice(data);
+}
+
+fn take<'a>(rest: &mut &'a [u8], n: usize) -> Result<&'a [u8], String> {
+    if rest.len() < n {
+        return Err("Truncated frame".into());
+    }
+    let (head, tail) = rest.split_at(n);
+    *rest = tail;
+    Ok(head)
+}
+
+fn take_u32(rest: &mut &[u8]) -> Result<u32, String> {
+    Ok(u32::from_le_bytes(take(rest, 4)?.try_into().unwrap()))
+}
+
+fn take_frame<'a>(rest: &mut &'a [u8]) -> Result<&'a [u8], String> {
+    let len = take_u32(rest)? as usize;
+    take(rest, len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{prove, setup};
+
+    #[test]
+    fn test_framed_round_trip_with_several_signals() {
+        let (pk, _) = setup(1);
+        let (proof, mut signals) = prove(&pk, 3, 5);
+        signals.push(format!("0x02{}", "00".repeat(31)));
+        signals.push(format!("0x{}", "ff".repeat(31)));
+        let result = ProofResult::from((proof, signals));
+
+        let framed = result.to_framed_bytes();
+        assert_eq!(framed.len(), 4 + 128 + 4 + 3 * 4 + 66 + 66 + 64);
+        assert_eq!(&framed[..4], &128u32.to_le_bytes());
+        assert_eq!(ProofResult::from_framed_bytes(&framed).unwrap(), result);
+    }
+
+    #[test]
+    fn test_bundle_size_matches_framed_length() {
+        let (pk, _) = setup(1);
+        let (proof, mut signals) = prove(&pk, 3, 5);
+        signals.push("0x01".into());
+        assert_eq!(
+            bundle_size(proof.len(), &signals),
+            4 + 128 + 4 + 4 + 66 + 4 + 4
+        );
+        let result = ProofResult::from((proof, signals));
+        assert_eq!(
+            bundle_size(result.proof.len(), &result.public_signals),
+            result.to_framed_bytes().len()
+        );
+        assert_eq!(
+            bundle_size(0, &[]),
+            ProofResult::from((vec![], vec![])).to_framed_bytes().len()
+        );
+    }
+
+    #[test]
+    fn test_framed_rejects_truncated_and_trailing() {
+        let result = ProofResult {
+            proof: vec![7; 4],
+            public_signals: vec!["0x01".into()],
+        };
+        let framed = result.to_framed_bytes();
+        assert!(ProofResult::from_framed_bytes(&framed[..framed.len() - 1])
+            .unwrap_err()
+            .contains("Truncated"));
+        let mut trailing = framed.clone();
+        trailing.push(0);
+        assert!(ProofResult::from_framed_bytes(&trailing)
+            .unwrap_err()
+            .contains("trailing"));
+    }
+
+    #[test]
+    fn test_framed_rejects_non_utf8_signal() {
+        let mut framed = ProofResult {
+            proof: vec![],
+            public_signals: vec!["0x01".into()],
+        }
+        .to_framed_bytes();
+        let last = framed.len() - 1;
+        framed[last] = 0xff;
+        assert!(ProofResult::from_framed_bytes(&framed).is_err());
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn test_msgpack_round_trip() {
+        let (pk, _) = setup(1);
+        let (proof, signals) = prove(&pk, 3, 5);
+        let result = ProofResult::from((proof, signals));
+
+        let packed = result.to_msgpack().unwrap();
+        // fixarray(2), then bin8 with a 128-byte length.
+        assert_eq!(&packed[..3], &[0x92, 0xc4, 128]);
+        assert_eq!(ProofResult::from_msgpack(&packed).unwrap(), result);
+        assert!(ProofResult::from_msgpack(&packed[..packed.len() - 1]).is_err());
+    }
+}
@@ -0,0 +1,515 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use ark_bn254::Fr as Bn254Fr;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+
+use crate::circuit_type::CircuitType;
+use crate::error::ProofError;
+use crate::field::from_hex_le;
+use crate::prover::{check_ic_len, ProofGenerator};
+
+/// Startup configuration for `proof-server`.
+///
+/// ```json
+/// {
+///   "keys": { "unshield": "keys/unshield_pk.ark", "transfer": "keys/transfer_pk.ark" },
+///   "allowed_circuits": ["unshield"],
+///   "canary_witnesses": { "unshield": ["0x01…", "0x…"] }
+/// }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct ServerConfig {
+    /// Proving key path per circuit name.
+    pub keys: HashMap<String, String>,
+    /// Circuit names the server accepts; every circuit when omitted.
+    #[serde(default)]
+    pub allowed_circuits: Option<Vec<String>>,
+    /// Known-good hex-LE witness per circuit name for the `GET /ready` self-test,
+    /// for circuits the all-zero witness does not satisfy.
+    #[serde(default)]
+    pub canary_witnesses: HashMap<String, Vec<String>>,
+}
+
+impl ServerConfig {
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("Invalid server config: {e}"))
+    }
+
+    /// Parsed `allowed_circuits`; `None` allows every circuit.
+    pub fn allowed_circuits(&self) -> Result<Option<HashSet<CircuitType>>, String> {
+        self.allowed_circuits
+            .as_ref()
+            .map(|names| names.iter().map(|n| CircuitType::from_str(n)).collect())
+            .transpose()
+    }
+
+    /// Parsed `canary_witnesses`.
+    pub fn canary_witnesses(&self) -> Result<HashMap<CircuitType, Vec<Bn254Fr>>, String> {
+        self.canary_witnesses
+            .iter()
+            .map(|(name, witness)| {
+                let witness = witness
+                    .iter()
+                    .map(|h| from_hex_le(h))
+                    .collect::<Result<_, _>>()
+                    .map_err(|e| format!("{name} canary witness: {e}"))?;
+                Ok((CircuitType::from_str(name)?, witness))
+            })
+            .collect()
+    }
+
+    /// Deserialize every configured proving key once, up front. Keys for circuits
+    /// outside `allowed_circuits` are not loaded.
+    ///
+    /// A `.zkey` path loads through [`ProofGenerator::from_zkey_bytes`], so proofs
+    /// use the circuit's own constraints and verify for any satisfying witness. An
+    /// `.ark` key carries no constraints: the server proves with [`WitnessCircuit`](crate::WitnessCircuit),
+    /// like the CLI, which only verifies against keys set up from that layout.
+    pub fn load_generators(&self) -> Result<HashMap<CircuitType, ProofGenerator>, String> {
+        let allowed = self.allowed_circuits()?;
+        let mut generators = HashMap::new();
+        for (name, path) in &self.keys {
+            let circuit = CircuitType::from_str(name)?;
+            if allowed.as_ref().is_some_and(|a| !a.contains(&circuit)) {
+                continue;
+            }
+            let generator = load_generator(path, circuit).map_err(|e| format!("{name}: {e}"))?;
+            generators.insert(circuit, generator);
+        }
+        Ok(generators)
+    }
+}
+
+fn load_generator(path: &str, circuit: CircuitType) -> Result<ProofGenerator, ProofError> {
+    if !path.ends_with(".zkey") {
+        return ProofGenerator::from_file(path, circuit.num_public_signals());
+    }
+    let bytes = std::fs::read(path).map_err(|e| ProofError::ProvingKeyIo(e.to_string()))?;
+    let generator = ProofGenerator::from_zkey_bytes(&bytes)?;
+    check_ic_len(
+        generator.num_public_signals() + 1,
+        circuit.num_public_signals(),
+    )?;
+    Ok(generator)
+}
+
+/// `POST /prove` body. `witness` holds hex-LE field elements, index 0 = constant 1.
+#[derive(Debug, Deserialize)]
+pub struct ProveRequest {
+    pub circuit: String,
+    pub witness: Vec<String>,
+}
+
+/// `POST /prove` response, same shape as the `generate-proof-from-witness` output.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProveResponse {
+    pub proof: String,
+    pub public_signals: Vec<String>,
+}
+
+type ApiError = (StatusCode, Json<serde_json::Value>);
+
+fn api_error(status: StatusCode, message: impl ToString) -> ApiError {
+    (
+        status,
+        Json(serde_json::json!({ "error": message.to_string() })),
+    )
+}
+
+struct AppState {
+    generators: HashMap<CircuitType, ProofGenerator>,
+    allowed_circuits: Option<HashSet<CircuitType>>,
+    canary_witnesses: HashMap<CircuitType, Vec<Bn254Fr>>,
+    /// Set by the first passing `GET /ready`; failures are retried on the next call.
+    readiness: tokio::sync::OnceCell<()>,
+}
+
+impl AppState {
+    /// Self-test every loaded generator, with its canary witness when one is
+    /// configured and [`ProofGenerator::self_test`] otherwise.
+    fn self_test(&self) -> Result<(), String> {
+        let mut circuits: Vec<_> = self.generators.keys().collect();
+        circuits.sort_by_key(|c| c.as_str());
+        for circuit in circuits {
+            let generator = &self.generators[circuit];
+            match self.canary_witnesses.get(circuit) {
+                Some(canary) => generator.self_test_with(canary.clone()),
+                None => generator.self_test(),
+            }
+            .map_err(|e| format!("{}: {e}", circuit.as_str()))?;
+        }
+        Ok(())
+    }
+}
+
+/// Build the HTTP router over preloaded generators.
+pub fn router(generators: HashMap<CircuitType, ProofGenerator>) -> Router {
+    router_with_allowed(generators, None)
+}
+
+/// [`router`] that answers `403` for circuits outside `allowed_circuits`, before
+/// parsing the witness. `None` allows every circuit.
+///
+/// `GET /ready` runs [`ProofGenerator::self_test`] on every loaded key and answers
+/// `200`, or `503` with the failing circuit. Once it passes, later calls answer
+/// from the cached result; a failure is retried on the next call. Without a
+/// canary witness this only proves the all-zero witness, so it shows the key and
+/// prover agree, not that real witnesses produce verifiable proofs.
+pub fn router_with_allowed(
+    generators: HashMap<CircuitType, ProofGenerator>,
+    allowed_circuits: Option<HashSet<CircuitType>>,
+) -> Router {
+    router_with_canaries(generators, allowed_circuits, HashMap::new())
+}
+
+/// [`router_with_allowed`] whose `GET /ready` self-test proves `canary_witnesses`
+/// for the circuits listed there, instead of the all-zero witness.
+pub fn router_with_canaries(
+    generators: HashMap<CircuitType, ProofGenerator>,
+    allowed_circuits: Option<HashSet<CircuitType>>,
+    canary_witnesses: HashMap<CircuitType, Vec<Bn254Fr>>,
+) -> Router {
+    Router::new()
+        .route("/prove", post(prove))
+        .route("/ready", get(ready))
+        .with_state(app_state(generators, allowed_circuits, canary_witnesses))
+}
+
+fn app_state(
+    generators: HashMap<CircuitType, ProofGenerator>,
+    allowed_circuits: Option<HashSet<CircuitType>>,
+    canary_witnesses: HashMap<CircuitType, Vec<Bn254Fr>>,
+) -> Arc<AppState> {
+    Arc::new(AppState {
+        generators,
+        allowed_circuits,
+        canary_witnesses,
+        readiness: tokio::sync::OnceCell::new(),
+    })
+}
+
+async fn ready(State(state): State<Arc<AppState>>) -> Result<Json<serde_json::Value>, ApiError> {
+    state
+        .readiness
+        .get_or_try_init(|| {
+            let state = state.clone();
+            async move {
+                // Self-tests prove once per key; keep them off the executor threads.
+                tokio::task::spawn_blocking(move || state.self_test())
+                    .await
+                    .unwrap_or_else(|e| Err(e.to_string()))
+            }
+        })
+        .await
+        .map_err(|e| api_error(StatusCode::SERVICE_UNAVAILABLE, e))?;
+    Ok(Json(serde_json::json!({ "ready": true })))
+}
+
+async fn prove(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<ProveRequest>,
+) -> Result<Json<ProveResponse>, ApiError> {
+    let circuit = CircuitType::from_str(&request.circuit)
+        .map_err(|e| api_error(StatusCode::BAD_REQUEST, e))?;
+    if state
+        .allowed_circuits
+        .as_ref()
+        .is_some_and(|allowed| !allowed.contains(&circuit))
+    {
+        return Err(api_error(
+            StatusCode::FORBIDDEN,
+            format!("Circuit {} is not served by this server", request.circuit),
+        ));
+    }
+    if !state.generators.contains_key(&circuit) {
+        return Err(api_error(
+            StatusCode::NOT_FOUND,
+            format!("No proving key loaded for {}", request.circuit),
+        ));
+    }
+    let witness: Vec<Bn254Fr> = request
+        .witness
+        .iter()
+        .map(|h| from_hex_le(h))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| api_error(StatusCode::BAD_REQUEST, ProofError::WitnessConversion(e)))?;
+
+    // Proving is CPU-bound; keep it off the async executor threads. The signals
+    // come from the generator so they match the loaded key's public inputs.
+    let (proof_bytes, public_signals) = tokio::task::spawn_blocking(move || {
+        state.generators[&circuit].prove_with_public_signals(witness)
+    })
+    .await
+    .map_err(|e| api_error(StatusCode::INTERNAL_SERVER_ERROR, e))?
+    .map_err(|e| match e {
+        ProofError::ProveGeneration(_) | ProofError::ProofSerialization(_) => {
+            api_error(StatusCode::INTERNAL_SERVER_ERROR, e)
+        }
+        _ => api_error(StatusCode::UNPROCESSABLE_ENTITY, e),
+    })?;
+
+    Ok(Json(ProveResponse {
+        proof: format!("0x{}", hex::encode(&proof_bytes)),
+        public_signals,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::to_hex_le;
+    use crate::WitnessCircuit;
+    use ark_bn254::Bn254;
+    use ark_groth16::Groth16;
+    use ark_serialize::CanonicalSerialize;
+    use ark_snark::SNARK;
+    use ark_std::rand::rngs::StdRng;
+    use ark_std::rand::SeedableRng;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    /// Minimal HTTP/1.1 client: returns (status code, body).
+    async fn post_json(addr: std::net::SocketAddr, path: &str, body: &str) -> (u16, String) {
+        send(addr, "POST", path, body).await
+    }
+
+    async fn send(
+        addr: std::net::SocketAddr,
+        method: &str,
+        path: &str,
+        body: &str,
+    ) -> (u16, String) {
+        let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+        let request = format!(
+            "{method} {path} HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\n\
+             Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        stream.write_all(request.as_bytes()).await.unwrap();
+        let mut raw = String::new();
+        stream.read_to_string(&mut raw).await.unwrap();
+        let status = raw[9..12].parse().unwrap();
+        let body = raw.split("\r\n\r\n").nth(1).unwrap_or_default().to_string();
+        (status, body)
+    }
+
+    async fn spawn_server() -> (
+        std::net::SocketAddr,
+        ark_groth16::VerifyingKey<ark_bn254::Bn254>,
+    ) {
+        spawn_server_with_allowed(None).await
+    }
+
+    /// Generators loaded through [`ServerConfig::load_generators`], as `proof-server`
+    /// does: a disclosure `.ark` key set up from the `WitnessCircuit` layout
+    /// `[1, x_1..x_4, w]`, the only `.ark` keys its proofs verify against.
+    fn test_generators(
+        name: &str,
+    ) -> (
+        HashMap<CircuitType, ProofGenerator>,
+        ark_groth16::VerifyingKey<ark_bn254::Bn254>,
+    ) {
+        let layout = WitnessCircuit {
+            witness: vec![Bn254Fr::from(0u64); 6],
+            num_public_signals: 4,
+        };
+        let mut rng = StdRng::seed_from_u64(1);
+        let (pk, vk) = Groth16::<Bn254>::circuit_specific_setup(layout, &mut rng).unwrap();
+        let path = std::env::temp_dir().join(format!(
+            "server-{name}-{}-disclosure.ark",
+            std::process::id()
+        ));
+        let mut pk_bytes = Vec::new();
+        pk.serialize_compressed(&mut pk_bytes).unwrap();
+        std::fs::write(&path, pk_bytes).unwrap();
+
+        let config = ServerConfig::from_json(
+            &serde_json::json!({ "keys": { "disclosure": path } }).to_string(),
+        )
+        .unwrap();
+        let generators = config.load_generators().unwrap();
+        let _ = std::fs::remove_file(&path);
+        (generators, vk)
+    }
+
+    fn disclosure_witness() -> Vec<String> {
+        [1u64, 10, 11, 12, 13, 99]
+            .iter()
+            .map(|&v| to_hex_le(Bn254Fr::from(v)))
+            .collect()
+    }
+
+    async fn spawn_server_with_allowed(
+        allowed: Option<HashSet<CircuitType>>,
+    ) -> (
+        std::net::SocketAddr,
+        ark_groth16::VerifyingKey<ark_bn254::Bn254>,
+    ) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (generators, vk) = test_generators(&addr.port().to_string());
+        tokio::spawn(async move {
+            axum::serve(listener, router_with_allowed(generators, allowed)).await
+        });
+        (addr, vk)
+    }
+
+    #[tokio::test]
+    async fn test_prove_over_http() {
+        let (addr, vk) = spawn_server().await;
+        let witness = disclosure_witness();
+        let body = serde_json::json!({ "circuit": "disclosure", "witness": witness }).to_string();
+
+        let (status, body) = post_json(addr, "/prove", &body).await;
+        assert_eq!(status, 200, "{body}");
+        let response: ProveResponse = serde_json::from_str(&body).unwrap();
+        let proof = hex::decode(response.proof.trim_start_matches("0x")).unwrap();
+        assert_eq!(proof.len(), 128);
+        assert_eq!(response.public_signals, witness[1..5].to_vec());
+        assert!(crate::verify(&proof, &vk, &response.public_signals).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_readiness_runs_self_test() {
+        let (addr, _) = spawn_server().await;
+        let (status, body) = send(addr, "GET", "/ready", "").await;
+        assert_eq!(status, 200, "{body}");
+        assert_eq!(body, r#"{"ready":true}"#);
+        // Second call answers from the cached result.
+        let (status, _) = send(addr, "GET", "/ready", "").await;
+        assert_eq!(status, 200);
+    }
+
+    #[tokio::test]
+    async fn test_readiness_uses_canary_and_retries_failures() {
+        let canary = |values: &[u64]| {
+            let witness = values.iter().map(|&v| Bn254Fr::from(v)).collect();
+            HashMap::from([(CircuitType::Disclosure, witness)])
+        };
+        // Too short for the key's layout, so the self-test cannot prove it.
+        let (generators, _) = test_generators("canary-bad");
+        let state = app_state(generators, None, canary(&[1, 10, 11]));
+        let (status, Json(body)) = ready(State(state.clone())).await.unwrap_err();
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert!(body["error"].as_str().unwrap().starts_with("disclosure: "));
+        // The failure is not cached, so the next call runs the self-test again.
+        assert!(!state.readiness.initialized());
+        assert!(ready(State(state.clone())).await.is_err());
+
+        let (generators, _) = test_generators("canary-good");
+        let state = app_state(generators, None, canary(&[1, 10, 11, 12, 13, 99]));
+        assert!(ready(State(state.clone())).await.is_ok());
+        assert!(state.readiness.initialized());
+    }
+
+    #[test]
+    fn test_config_canary_witnesses() {
+        let config = ServerConfig::from_json(
+            r#"{"keys":{},"canary_witnesses":{"transfer":["0x01","0x0f"]}}"#,
+        )
+        .unwrap();
+        let canaries = config.canary_witnesses().unwrap();
+        assert_eq!(
+            canaries[&CircuitType::Transfer],
+            vec![Bn254Fr::from(1u64), Bn254Fr::from(15u64)]
+        );
+
+        let config =
+            ServerConfig::from_json(r#"{"keys":{},"canary_witnesses":{"transfer":["0xZZ"]}}"#)
+                .unwrap();
+        assert!(config
+            .canary_witnesses()
+            .unwrap_err()
+            .starts_with("transfer canary witness: "));
+    }
+
+    #[tokio::test]
+    async fn test_unloaded_circuit_is_not_found() {
+        let (addr, _) = spawn_server().await;
+        let body = r#"{"circuit":"unshield","witness":["0x01"]}"#;
+        let (status, body) = post_json(addr, "/prove", body).await;
+        assert_eq!(status, 404);
+        assert!(body.contains("No proving key loaded"));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_circuit_is_bad_request() {
+        let (addr, _) = spawn_server().await;
+        let body = r#"{"circuit":"mint","witness":["0x01"]}"#;
+        let (status, body) = post_json(addr, "/prove", body).await;
+        assert_eq!(status, 400);
+        assert!(body.contains("Unknown circuit type"));
+    }
+
+    #[tokio::test]
+    async fn test_allow_list() {
+        let allowed = HashSet::from([CircuitType::Disclosure]);
+        let (addr, _) = spawn_server_with_allowed(Some(allowed)).await;
+
+        // Rejected before the (malformed) witness is even looked at.
+        let body = r#"{"circuit":"transfer","witness":["0xZZ"]}"#;
+        let (status, body) = post_json(addr, "/prove", body).await;
+        assert_eq!(status, 403);
+        assert!(body.contains("not served"));
+
+        let body = serde_json::json!({ "circuit": "disclosure", "witness": disclosure_witness() })
+            .to_string();
+        let (status, body) = post_json(addr, "/prove", &body).await;
+        assert_eq!(status, 200, "{body}");
+    }
+
+    #[test]
+    fn test_config_allowed_circuits() {
+        let config = ServerConfig::from_json(
+            r#"{"keys":{"transfer":"/nonexistent.ark"},"allowed_circuits":["unshield"]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.allowed_circuits().unwrap(),
+            Some(HashSet::from([CircuitType::Unshield]))
+        );
+        // The disallowed transfer key is never read.
+        assert!(config.load_generators().unwrap().is_empty());
+
+        let config = ServerConfig::from_json(r#"{"keys":{},"allowed_circuits":["mint"]}"#).unwrap();
+        assert!(config.allowed_circuits().is_err());
+    }
+
+    #[test]
+    fn test_config_loads_zkey_keys() {
+        let zkey = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/multiplier.zkey"
+        );
+        // The multiplier has one public signal; disclosure needs four.
+        let config = ServerConfig::from_json(
+            &serde_json::json!({ "keys": { "disclosure": zkey } }).to_string(),
+        )
+        .unwrap();
+        let err = config.load_generators().err().unwrap();
+        assert!(
+            err.starts_with("disclosure: ") && err.contains("1 public inputs, 4 requested"),
+            "{err}"
+        );
+        // A missing `.zkey` fails on read, like a missing `.ark`.
+        let config =
+            ServerConfig::from_json(r#"{"keys":{"disclosure":"/nonexistent.zkey"}}"#).unwrap();
+        assert!(config
+            .load_generators()
+            .err()
+            .unwrap()
+            .contains("Failed to read proving key"));
+    }
+
+    #[test]
+    fn test_config_rejects_unknown_circuit() {
+        let config = ServerConfig::from_json(r#"{"keys":{"mint":"/tmp/x.ark"}}"#).unwrap();
+        let err = config.load_generators().err().unwrap();
+        assert!(err.contains("Unknown circuit type"));
+    }
+}
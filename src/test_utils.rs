@@ -0,0 +1,102 @@
+//! A tiny constrained circuit with real keys, shared by unit tests and exposed to
+//! downstream crates through the `testing` feature for randomized round-trip tests.
+use ark_bn254::{Bn254, Fr as Bn254Fr};
+use ark_ff::UniformRand;
+use ark_ff::{BigInteger, PrimeField};
+use ark_groth16::{Groth16, ProvingKey, VerifyingKey};
+use ark_relations::lc;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use ark_serialize::CanonicalSerialize;
+use ark_snark::SNARK;
+use ark_std::rand::rngs::StdRng;
+use ark_std::rand::{RngCore, SeedableRng};
+
+/// `a * b = c` with `c` public. Witness layout: `[1, c, a, b]`.
+#[derive(Clone)]
+pub struct TestCircuit {
+    pub a: Option<Bn254Fr>,
+    pub b: Option<Bn254Fr>,
+}
+
+impl TestCircuit {
+    /// Sample random `a`, `b` and return the full satisfying assignment `[1, a·b, a, b]`
+    /// together with the public inputs `[a·b]`.
+    pub fn random_witness<R: RngCore>(rng: &mut R) -> (Vec<Bn254Fr>, Vec<Bn254Fr>) {
+        let (a, b) = (Bn254Fr::rand(rng), Bn254Fr::rand(rng));
+        let c = a * b;
+        (vec![Bn254Fr::from(1u64), c, a, b], vec![c])
+    }
+}
+
+impl ConstraintSynthesizer<Bn254Fr> for TestCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Bn254Fr>) -> Result<(), SynthesisError> {
+        let c = cs.new_input_variable(|| {
+            let a = self.a.ok_or(SynthesisError::AssignmentMissing)?;
+            let b = self.b.ok_or(SynthesisError::AssignmentMissing)?;
+            Ok(a * b)
+        })?;
+        let a = cs.new_witness_variable(|| self.a.ok_or(SynthesisError::AssignmentMissing))?;
+        let b = cs.new_witness_variable(|| self.b.ok_or(SynthesisError::AssignmentMissing))?;
+        cs.enforce_constraint(lc!() + a, lc!() + b, lc!() + c)?;
+        Ok(())
+    }
+}
+
+/// Deterministic key pair for [`TestCircuit`]; different seeds give unrelated keys.
+pub fn setup(seed: u64) -> (ProvingKey<Bn254>, VerifyingKey<Bn254>) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    Groth16::<Bn254>::circuit_specific_setup(TestCircuit { a: None, b: None }, &mut rng).unwrap()
+}
+
+/// Prove `a * b = c`, returning compressed proof bytes and the hex-LE public signals.
+pub fn prove(pk: &ProvingKey<Bn254>, a: u64, b: u64) -> (Vec<u8>, Vec<String>) {
+    let (a, b) = (Bn254Fr::from(a), Bn254Fr::from(b));
+    let mut rng = StdRng::seed_from_u64(42);
+    let proof = Groth16::<Bn254>::prove(
+        pk,
+        TestCircuit {
+            a: Some(a),
+            b: Some(b),
+        },
+        &mut rng,
+    )
+    .unwrap();
+    let mut bytes = Vec::new();
+    proof.serialize_compressed(&mut bytes).unwrap();
+    (bytes, vec![to_hex_le(a * b)])
+}
+
+pub fn to_hex_le(f: Bn254Fr) -> String {
+    let mut bytes = f.into_bigint().to_bytes_le();
+    bytes.resize(32, 0u8);
+    format!("0x{}", hex::encode(&bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_random_witnesses_prove_and_verify() {
+        let (pk, vk) = setup(1);
+        let pvk = Groth16::<Bn254>::process_vk(&vk).unwrap();
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..16 {
+            let (witness, public) = TestCircuit::random_witness(&mut rng);
+            assert_eq!(witness[0], Bn254Fr::from(1u64));
+            assert_eq!(witness[1], witness[2] * witness[3]);
+            assert_eq!(public, vec![witness[1]]);
+
+            let circuit = TestCircuit {
+                a: Some(witness[2]),
+                b: Some(witness[3]),
+            };
+            let proof = Groth16::<Bn254>::prove(&pk, circuit, &mut rng).unwrap();
+            assert!(Groth16::<Bn254>::verify_with_processed_vk(&pvk, &public, &proof).unwrap());
+
+            let mut bytes = Vec::new();
+            proof.serialize_compressed(&mut bytes).unwrap();
+            assert!(crate::verify(&bytes, &vk, &[to_hex_le(public[0])]).unwrap());
+        }
+    }
+}
@@ -1,7 +1,18 @@
-// Backward-compatible shims for decimal_to_field and hex_to_field.
-// Logic lives in field.rs as generic functions.
-use crate::field::{from_decimal_str, from_hex_le};
+// Backward-compatible shims for decimal_to_field and hex_to_field, plus
+// BN254-specific helpers. Generic logic lives in field.rs.
+use crate::field::{decode_hex, from_decimal_str, from_hex_be, from_hex_le};
 use ark_bn254::Fr as Bn254Fr;
+use ark_ff::{BigInt, Field, PrimeField};
+use num_bigint::BigUint;
+
+/// BN254 scalar field modulus `r` as big-endian hex.
+pub const BN254_SCALAR_MODULUS_HEX: &str =
+    "0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001";
+
+/// BN254 scalar field modulus `r` (the order of `Bn254Fr`).
+pub fn bn254_scalar_modulus() -> BigUint {
+    Bn254Fr::MODULUS.into()
+}
 
 pub fn decimal_to_field(s: &str) -> Result<Bn254Fr, String> {
     from_decimal_str::<Bn254Fr>(s)
@@ -11,10 +22,106 @@ pub fn hex_to_field(hex: &str) -> Result<Bn254Fr, String> {
     from_hex_le::<Bn254Fr>(hex)
 }
 
+/// [`hex_to_field`], also reporting whether the value was reduced: `true` when the
+/// encoded integer is at or above the modulus, so the returned element differs from it.
+pub fn hex_to_field_with_flag(hex: &str) -> Result<(Bn254Fr, bool), String> {
+    let bytes = decode_hex(hex)?;
+    let reduced = BigUint::from_bytes_le(&bytes) >= bn254_scalar_modulus();
+    Ok((Bn254Fr::from_le_bytes_mod_order(&bytes), reduced))
+}
+
+/// Big-endian counterpart of [`hex_to_field`].
+pub fn hex_to_field_be(hex: &str) -> Result<Bn254Fr, String> {
+    from_hex_be::<Bn254Fr>(hex)
+}
+
+/// Compare two hex-LE strings as field elements.
+///
+/// Case and encoding length don't matter: `"0x0A"` equals `"0x0a"` and the fully
+/// padded `"0x0a00…00"`. Note that in little-endian the insignificant zeros are the
+/// trailing bytes, so `"0x000a"` is 2560, not 10.
+pub fn field_eq_hex(a: &str, b: &str) -> Result<bool, String> {
+    Ok(hex_to_field(a)? == hex_to_field(b)?)
+}
+
+pub fn field_from_u64(v: u64) -> Bn254Fr {
+    Bn254Fr::from(v)
+}
+
+/// Negative values map to `r - |v|`, matching Circom's representation of negatives.
+pub fn field_from_i64(v: i64) -> Bn254Fr {
+    let magnitude = Bn254Fr::from(v.unsigned_abs());
+    if v < 0 {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// Interpret `bytes` as a little-endian integer, reduced modulo `r`.
+pub fn field_from_bytes_le(bytes: &[u8]) -> Bn254Fr {
+    Bn254Fr::from_le_bytes_mod_order(bytes)
+}
+
+/// Interpret `bytes` as a big-endian integer, reduced modulo `r`.
+pub fn field_from_bytes_be(bytes: &[u8]) -> Bn254Fr {
+    Bn254Fr::from_be_bytes_mod_order(bytes)
+}
+
+/// Build a field element from four little-endian `u64` limbs of its canonical value.
+/// Unlike the byte constructors this does not reduce: values `>= r` are rejected.
+pub fn field_from_limbs(limbs: [u64; 4]) -> Result<Bn254Fr, String> {
+    Bn254Fr::from_bigint(BigInt::new(limbs))
+        .ok_or_else(|| format!("Limbs {limbs:?} are not below the BN254 scalar modulus"))
+}
+
+/// `a + b` mod `r`.
+pub fn field_add(a: Bn254Fr, b: Bn254Fr) -> Bn254Fr {
+    a + b
+}
+
+/// `a - b` mod `r`.
+pub fn field_sub(a: Bn254Fr, b: Bn254Fr) -> Bn254Fr {
+    a - b
+}
+
+/// `a · b` mod `r`.
+pub fn field_mul(a: Bn254Fr, b: Bn254Fr) -> Bn254Fr {
+    a * b
+}
+
+/// `-a` mod `r`, i.e. `r - a` for non-zero `a`.
+pub fn field_neg(a: Bn254Fr) -> Bn254Fr {
+    -a
+}
+
+/// Multiplicative inverse of `a`; zero has none and is an error.
+pub fn field_inv(a: Bn254Fr) -> Result<Bn254Fr, String> {
+    a.inverse()
+        .ok_or_else(|| "Zero has no multiplicative inverse".to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_hex_to_field_with_flag() {
+        let (f, reduced) = hex_to_field_with_flag("0x0a").unwrap();
+        assert_eq!((f, reduced), (Bn254Fr::from(10u64), false));
+
+        let mut r_plus_one = (bn254_scalar_modulus() + 1u32).to_bytes_le();
+        r_plus_one.resize(32, 0);
+        let (f, reduced) =
+            hex_to_field_with_flag(&format!("0x{}", hex::encode(r_plus_one))).unwrap();
+        assert_eq!((f, reduced), (Bn254Fr::from(1u64), true));
+
+        let mut r_minus_one = (bn254_scalar_modulus() - 1u32).to_bytes_le();
+        r_minus_one.resize(32, 0);
+        assert!(!hex_to_field_with_flag(&hex::encode(r_minus_one)).unwrap().1);
+        assert!(hex_to_field_with_flag("0xzz").is_err());
+    }
+
     #[test]
     fn test_decimal_to_field() {
         let decimal = "1";
@@ -147,6 +254,113 @@ mod tests {
             .contains("Failed to parse decimal string"));
     }
 
+    #[test]
+    fn test_modulus_matches_hex_constant() {
+        let from_hex = BigUint::parse_bytes(
+            BN254_SCALAR_MODULUS_HEX.trim_start_matches("0x").as_bytes(),
+            16,
+        )
+        .unwrap();
+        assert_eq!(bn254_scalar_modulus(), from_hex);
+    }
+
+    #[test]
+    fn test_modulus_reduces_to_zero() {
+        let mut bytes = bn254_scalar_modulus().to_bytes_le();
+        bytes.resize(32, 0u8);
+        let hex = format!("0x{}", hex::encode(&bytes));
+        assert_eq!(hex_to_field(&hex).unwrap(), Bn254Fr::from(0u64));
+    }
+
+    #[test]
+    fn test_field_eq_hex_padding() {
+        let padded = format!("0x0a{}", "00".repeat(31));
+        assert!(field_eq_hex("0x0a", &padded).unwrap());
+        assert!(field_eq_hex("0xa", "0x0a").unwrap());
+    }
+
+    #[test]
+    fn test_field_eq_hex_case_insensitive() {
+        assert!(field_eq_hex("0xABCDEF", "0xabcdef").unwrap());
+        assert!(field_eq_hex("0xAbCdEf", "0xaBcDeF").unwrap());
+    }
+
+    #[test]
+    fn test_field_eq_hex_reduced_values() {
+        let mut modulus = bn254_scalar_modulus().to_bytes_le();
+        modulus.resize(32, 0u8);
+        assert!(field_eq_hex(&format!("0x{}", hex::encode(modulus)), "0x00").unwrap());
+    }
+
+    #[test]
+    fn test_field_eq_hex_different_values() {
+        assert!(!field_eq_hex("0x0a", "0x0b").unwrap());
+        // Leading zeros are significant in little-endian.
+        assert!(!field_eq_hex("0x0a", "0x000a").unwrap());
+    }
+
+    #[test]
+    fn test_field_eq_hex_invalid_input() {
+        assert!(field_eq_hex("0x0a", "0xZZ").is_err());
+    }
+
+    #[test]
+    fn test_field_arithmetic_helpers() {
+        let (a, b) = (field_from_u64(7), field_from_u64(5));
+        assert_eq!(field_add(a, b), field_from_u64(12));
+        assert_eq!(field_sub(b, a), field_from_i64(-2));
+        assert_eq!(field_mul(a, b), field_from_u64(35));
+        assert_eq!(field_neg(a), field_from_i64(-7));
+        assert_eq!(field_neg(field_from_u64(0)), field_from_u64(0));
+        assert_eq!(field_mul(a, field_inv(a).unwrap()), field_from_u64(1));
+        assert!(field_inv(field_from_u64(0))
+            .unwrap_err()
+            .contains("no multiplicative inverse"));
+    }
+
+    #[test]
+    fn test_field_from_u64() {
+        assert_eq!(field_from_u64(42), Bn254Fr::from(42u64));
+        assert_eq!(field_from_u64(u64::MAX), Bn254Fr::from(u64::MAX));
+    }
+
+    #[test]
+    fn test_field_from_i64_positive_and_zero() {
+        assert_eq!(field_from_i64(7), Bn254Fr::from(7u64));
+        assert_eq!(field_from_i64(0), Bn254Fr::from(0u64));
+    }
+
+    #[test]
+    fn test_field_from_i64_negative() {
+        assert_eq!(
+            field_from_i64(-5) + Bn254Fr::from(5u64),
+            Bn254Fr::from(0u64)
+        );
+        assert_eq!(field_from_i64(i64::MIN), -Bn254Fr::from(1u64 << 63));
+    }
+
+    #[test]
+    fn test_field_from_bytes_le_and_be() {
+        assert_eq!(field_from_bytes_le(&[0x39, 0x30]), Bn254Fr::from(12345u64));
+        assert_eq!(field_from_bytes_be(&[0x30, 0x39]), Bn254Fr::from(12345u64));
+    }
+
+    #[test]
+    fn test_field_from_limbs() {
+        assert_eq!(field_from_limbs([5, 0, 0, 0]).unwrap(), Bn254Fr::from(5u64));
+        assert_eq!(
+            field_from_limbs([0, 1, 0, 0]).unwrap(),
+            Bn254Fr::from(u64::MAX) + Bn254Fr::from(1u64)
+        );
+        assert!(field_from_limbs([u64::MAX; 4]).is_err());
+    }
+
+    #[test]
+    fn test_field_from_bytes_reduces_modulus() {
+        let be = bn254_scalar_modulus().to_bytes_be();
+        assert_eq!(field_from_bytes_be(&be), Bn254Fr::from(0u64));
+    }
+
     #[test]
     fn test_decimal_to_field_leading_zeros() {
         // "0001" should parse the same as "1"
@@ -154,4 +368,16 @@ mod tests {
         let b = decimal_to_field("1").unwrap();
         assert_eq!(a, b);
     }
+
+    #[test]
+    fn test_hex_to_field_uppercase_prefix() {
+        let lower =
+            hex_to_field("0x0a00000000000000000000000000000000000000000000000000000000000000")
+                .unwrap();
+        let upper =
+            hex_to_field("0X0A00000000000000000000000000000000000000000000000000000000000000")
+                .unwrap();
+        assert_eq!(lower, upper);
+        assert_eq!(upper, Bn254Fr::from(10u64));
+    }
 }
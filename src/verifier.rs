@@ -0,0 +1,680 @@
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use ark_bn254::{Bn254, Fr as Bn254Fr, G1Affine, G1Projective, G2Affine};
+use ark_ec::pairing::{Pairing, PairingOutput};
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{UniformRand, Zero};
+use ark_groth16::{Groth16, PreparedVerifyingKey, Proof as ArkProof, VerifyingKey};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_snark::SNARK;
+use ark_std::rand::{CryptoRng, RngCore};
+use sha2::{Digest, Sha256};
+
+use crate::circuit_type::CircuitType;
+use crate::commitment::{vk_fingerprint, witness_commitment};
+use crate::curve::{strip_proof_tag, strip_vk_tag};
+use crate::error::ProofError;
+use crate::field::from_hex_le;
+use crate::prover::check_ic_len;
+
+fn parse_proof(proof_bytes: &[u8]) -> Result<ArkProof<Bn254>, ProofError> {
+    ArkProof::<Bn254>::deserialize_compressed(strip_proof_tag(proof_bytes)?)
+        .map_err(|e| ProofError::ProofParse(e.to_string()))
+}
+
+fn parse_signals(public_signals: &[String]) -> Result<Vec<Bn254Fr>, ProofError> {
+    public_signals
+        .iter()
+        .map(|s| from_hex_le(s))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(ProofError::PublicSignalConversion)
+}
+
+/// Reject a signal count that doesn't match the key's IC points, before any
+/// decoding, so the error names both counts instead of surfacing from arkworks.
+fn check_signal_count(ic: &[G1Affine], num_signals: usize) -> Result<(), ProofError> {
+    if num_signals + 1 != ic.len() {
+        return Err(ProofError::Verification(format!(
+            "expected {} public signals, got {num_signals}",
+            ic.len().saturating_sub(1),
+        )));
+    }
+    Ok(())
+}
+
+/// `IC[0] + Σ inputs[i] · IC[i + 1]` — the public-input term of the Groth16 equation.
+fn linear_combination(ic: &[G1Affine], inputs: &[Bn254Fr]) -> Result<G1Projective, ProofError> {
+    check_signal_count(ic, inputs.len())?;
+    Ok(inputs
+        .iter()
+        .zip(ic.iter().skip(1))
+        .fold(ic[0].into_group(), |acc, (x, p)| acc + *p * x))
+}
+
+/// `vk_x = IC[0] + Σ signal_i · IC[i]` for hex-LE `signals`, as `0x` hex of the
+/// compressed G1 point.
+///
+/// Exposes the public-input term independently of the pairing check, to diagnose
+/// signal-ordering or encoding issues.
+pub fn compute_vk_x(vk: &VerifyingKey<Bn254>, signals: &[String]) -> Result<String, String> {
+    let inputs = parse_signals(signals).map_err(|e| e.to_string())?;
+    let vk_x = linear_combination(&vk.gamma_abc_g1, &inputs)
+        .map_err(|e| e.to_string())?
+        .into_affine();
+    let mut bytes = Vec::new();
+    vk_x.serialize_compressed(&mut bytes)
+        .map_err(|e| e.to_string())?;
+    Ok(format!("0x{}", hex::encode(bytes)))
+}
+
+/// Verify a compressed Groth16 proof against `vk` and hex-LE public signals.
+///
+/// Returns `Ok(false)` for a well-formed proof that does not verify; malformed
+/// inputs surface as errors.
+pub fn verify(
+    proof_bytes: &[u8],
+    vk: &VerifyingKey<Bn254>,
+    public_signals: &[String],
+) -> Result<bool, ProofError> {
+    verify_borrowed(vk, proof_bytes, public_signals)
+}
+
+/// [`verify`] that only ever borrows `vk`.
+///
+/// `Groth16::verify` prepares the key first, which copies the whole verifying key
+/// (every IC point) into a `PreparedVerifyingKey` for a single check. This runs the
+/// pairing equation straight from the borrowed key, so peak memory is the
+/// deserialized key plus the proof. [`verify`] delegates here.
+pub fn verify_borrowed(
+    vk: &VerifyingKey<Bn254>,
+    proof_bytes: &[u8],
+    public_signals: &[String],
+) -> Result<bool, ProofError> {
+    check_signal_count(&vk.gamma_abc_g1, public_signals.len())?;
+    let proof = parse_proof(proof_bytes)?;
+    let inputs = parse_signals(public_signals)?;
+    let vk_x = linear_combination(&vk.gamma_abc_g1, &inputs)?;
+    let check = Bn254::multi_pairing(
+        [
+            proof.a,
+            (-vk.alpha_g1.into_group()).into_affine(),
+            (-vk_x).into_affine(),
+            (-proof.c.into_group()).into_affine(),
+        ],
+        [proof.b, vk.beta_g2, vk.gamma_g2, vk.delta_g2],
+    );
+    Ok(check.is_zero())
+}
+
+/// [`verify`] for protocols that publish a hash of the public inputs instead of the
+/// inputs themselves.
+///
+/// `signals` must first hash to `expected_commitment` under
+/// [`witness_commitment`](crate::witness_commitment) (SHA-256 over the 32-byte LE
+/// words, `0x` hex, compared case-insensitively); a mismatch is an error and the
+/// proof is never checked. Otherwise returns the verification result.
+pub fn verify_with_commitment(
+    proof_bytes: &[u8],
+    vk: &VerifyingKey<Bn254>,
+    signals: &[String],
+    expected_commitment: &str,
+) -> Result<bool, String> {
+    let inputs = parse_signals(signals).map_err(|e| e.to_string())?;
+    let commitment = witness_commitment(&inputs);
+    let expected = expected_commitment.trim_start_matches("0x");
+    if !commitment[2..].eq_ignore_ascii_case(expected) {
+        return Err(format!(
+            "public signals commit to {commitment}, expected {expected_commitment}"
+        ));
+    }
+    verify(proof_bytes, vk, signals).map_err(|e| e.to_string())
+}
+
+/// [`verify`] plus its wall-clock duration in microseconds, including proof and
+/// signal decoding, for services that track verification latency.
+pub fn verify_timed(
+    proof_bytes: &[u8],
+    vk: &VerifyingKey<Bn254>,
+    public_signals: &[String],
+) -> Result<(bool, u128), String> {
+    let start = Instant::now();
+    let valid = verify(proof_bytes, vk, public_signals).map_err(|e| e.to_string())?;
+    Ok((valid, start.elapsed().as_micros()))
+}
+
+/// Audit record of one verification, from [`verify_report`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct VerifyReport {
+    pub valid: bool,
+    /// [`vk_fingerprint`] of the key the proof was checked against.
+    pub vk_fingerprint: String,
+    pub public_signals: Vec<String>,
+    /// `0x` SHA-256 of the proof bytes as given.
+    pub proof_sha256: String,
+    /// Seconds since the Unix epoch when verification finished.
+    pub verified_at: u64,
+}
+
+impl VerifyReport {
+    /// Pretty-printed JSON, one field per line.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("VerifyReport serializes to JSON")
+    }
+}
+
+/// [`verify`] against the compressed (optionally curve-tagged) VK at `vk_path`,
+/// returning a [`VerifyReport`] for audit trails instead of a bare bool.
+///
+/// An invalid proof still yields a report with `valid: false`; only unreadable or
+/// malformed inputs are errors.
+pub fn verify_report(
+    proof_bytes: &[u8],
+    vk_path: &str,
+    signals: &[String],
+) -> Result<VerifyReport, String> {
+    let vk_bytes = std::fs::read(vk_path)
+        .map_err(|e| ProofError::VerifyingKeyIo(e.to_string()).to_string())?;
+    let vk = VerifyingKey::<Bn254>::deserialize_compressed(
+        strip_vk_tag(&vk_bytes).map_err(|e| e.to_string())?,
+    )
+    .map_err(|e| ProofError::VerifyingKeyParse(e.to_string()).to_string())?;
+    let valid = verify(proof_bytes, &vk, signals).map_err(|e| e.to_string())?;
+    let verified_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| e.to_string())?
+        .as_secs();
+    Ok(VerifyReport {
+        valid,
+        vk_fingerprint: vk_fingerprint(&vk),
+        public_signals: signals.to_vec(),
+        proof_sha256: format!("0x{}", hex::encode(Sha256::digest(proof_bytes))),
+        verified_at,
+    })
+}
+
+/// `Groth16::process_vk` without its clone: the key moves into the prepared form.
+fn prepare_owned(vk: VerifyingKey<Bn254>) -> PreparedVerifyingKey<Bn254> {
+    PreparedVerifyingKey {
+        alpha_g1_beta_g2: Bn254::pairing(vk.alpha_g1, vk.beta_g2).0,
+        gamma_g2_neg_pc: (-vk.gamma_g2.into_group()).into_affine().into(),
+        delta_g2_neg_pc: (-vk.delta_g2.into_group()).into_affine().into(),
+        vk,
+    }
+}
+
+/// Check the raw Groth16 equation from individual components:
+///
+/// `e(a, b) == alpha_beta · e(IC[0] + Σ signals[i]·IC[i+1], gamma) · e(c, delta)`
+///
+/// `alpha_beta` is the precomputed `e(vk.alpha_g1, vk.beta_g2)`, and `ic` is the
+/// verifying key's `gamma_abc_g1`, which may be sourced separately from the rest
+/// of the key. Prefer [`verify`] unless building a custom verification flow.
+#[allow(clippy::too_many_arguments)]
+pub fn verify_with_components(
+    a: G1Affine,
+    b: G2Affine,
+    c: G1Affine,
+    alpha_beta: PairingOutput<Bn254>,
+    gamma: G2Affine,
+    delta: G2Affine,
+    ic: &[G1Affine],
+    signals: &[Bn254Fr],
+) -> Result<bool, ProofError> {
+    let vk_x = linear_combination(ic, signals)?;
+    let lhs = Bn254::multi_pairing(
+        [a, (-vk_x).into_affine(), (-c.into_group()).into_affine()],
+        [b, gamma, delta],
+    );
+    Ok(lhs == alpha_beta)
+}
+
+/// Reusable verifier holding a prepared (pairing-precomputed) verifying key.
+pub struct Verifier {
+    pvk: PreparedVerifyingKey<Bn254>,
+}
+
+impl Verifier {
+    /// Load from arkworks compressed verifying key bytes, optionally curve-tagged
+    /// (see [`Curve`](crate::Curve)).
+    ///
+    /// Fails if the key's IC points do not cover exactly `num_public_signals` inputs.
+    pub fn from_bytes(vk_bytes: &[u8], num_public_signals: usize) -> Result<Self, ProofError> {
+        let vk = VerifyingKey::<Bn254>::deserialize_compressed(strip_vk_tag(vk_bytes)?)
+            .map_err(|e| ProofError::VerifyingKeyParse(e.to_string()))?;
+        check_ic_len(vk.gamma_abc_g1.len(), num_public_signals)?;
+        Ok(Self {
+            pvk: prepare_owned(vk),
+        })
+    }
+
+    /// Load from a compressed verifying key file (e.g. `convert-vk` output).
+    pub fn from_file(path: &str, num_public_signals: usize) -> Result<Self, ProofError> {
+        let vk_bytes =
+            std::fs::read(path).map_err(|e| ProofError::VerifyingKeyIo(e.to_string()))?;
+        Self::from_bytes(&vk_bytes, num_public_signals)
+    }
+
+    /// Verify a compressed proof against hex-LE public signals.
+    pub fn verify(
+        &self,
+        proof_bytes: &[u8],
+        public_signals: &[String],
+    ) -> Result<bool, ProofError> {
+        check_signal_count(&self.pvk.vk.gamma_abc_g1, public_signals.len())?;
+        let proof = parse_proof(proof_bytes)?;
+        let inputs = parse_signals(public_signals)?;
+        Groth16::<Bn254>::verify_with_processed_vk(&self.pvk, &inputs, &proof)
+            .map_err(|e| ProofError::Verification(e.to_string()))
+    }
+}
+
+/// Verify many `(proof, public_signals)` pairs against one `vk` with a single
+/// multi-pairing.
+///
+/// Each proof equation is scaled by a fresh random scalar and the results summed,
+/// so `n` proofs cost `n + 3` Miller loops and one final exponentiation instead of
+/// `n` full pairing checks. The result is all-or-nothing: `Ok(false)` means at
+/// least one proof is invalid, without saying which. An empty batch is valid.
+///
+/// The scalars must be unpredictable, or a forger can make invalid proofs cancel
+/// in the sum; hence the `CryptoRng` bound. Pass `OsRng` outside tests.
+pub fn batch_verify<R: RngCore + CryptoRng>(
+    proofs: &[(Vec<u8>, Vec<String>)],
+    vk: &VerifyingKey<Bn254>,
+    rng: &mut R,
+) -> Result<bool, ProofError> {
+    if proofs.is_empty() {
+        return Ok(true);
+    }
+
+    let mut g1: Vec<G1Affine> = Vec::with_capacity(proofs.len() + 3);
+    let mut g2: Vec<G2Affine> = Vec::with_capacity(proofs.len() + 3);
+    let mut r_sum = Bn254Fr::zero();
+    let mut acc_vk_x = G1Projective::zero();
+    let mut acc_c = G1Projective::zero();
+
+    for (proof_bytes, signals) in proofs {
+        check_signal_count(&vk.gamma_abc_g1, signals.len())?;
+        let proof = parse_proof(proof_bytes)?;
+        let vk_x = linear_combination(&vk.gamma_abc_g1, &parse_signals(signals)?)?;
+        let r = Bn254Fr::rand(rng);
+        g1.push((proof.a * r).into_affine());
+        g2.push(proof.b);
+        r_sum += r;
+        acc_vk_x += vk_x * r;
+        acc_c += proof.c * r;
+    }
+
+    // Σ r_i·e(A_i, B_i) − e(Σ r_i·α, β) − e(Σ r_i·vk_x_i, γ) − e(Σ r_i·C_i, δ) == 0
+    g1.push((-(vk.alpha_g1 * r_sum)).into_affine());
+    g2.push(vk.beta_g2);
+    g1.push((-acc_vk_x).into_affine());
+    g2.push(vk.gamma_g2);
+    g1.push((-acc_c).into_affine());
+    g2.push(vk.delta_g2);
+
+    Ok(Bn254::multi_pairing(g1, g2).is_zero())
+}
+
+/// [`verify`] after cross-checking `vk` and `signals` against `circuit`.
+///
+/// Structural problems are errors: a key whose public-input count is not
+/// `circuit.num_public_signals()` (usually the wrong circuit's key), a signal
+/// count that differs from it, or a proof that does not decode. Only the pairing
+/// result comes back as `Ok(bool)`.
+pub fn verify_full(
+    circuit: CircuitType,
+    proof_bytes: &[u8],
+    vk: &VerifyingKey<Bn254>,
+    signals: &[String],
+) -> Result<bool, String> {
+    let expected = circuit.num_public_signals();
+    let vk_inputs = vk.gamma_abc_g1.len().saturating_sub(1);
+    if vk_inputs != expected {
+        return Err(format!(
+            "{circuit} expects {expected} public inputs, but the verifying key has {vk_inputs}"
+        ));
+    }
+    if signals.len() != expected {
+        return Err(format!(
+            "{circuit} expects {expected} public signals, got {}",
+            signals.len()
+        ));
+    }
+    verify(proof_bytes, vk, signals).map_err(|e| e.to_string())
+}
+
+/// Return the first circuit type whose verifying key accepts the proof.
+///
+/// Candidates that error (e.g. wrong public-signal arity) are treated as non-matching.
+pub fn identify_circuit(
+    proof_bytes: &[u8],
+    public_signals: &[String],
+    vks: &[(CircuitType, VerifyingKey<Bn254>)],
+) -> Option<CircuitType> {
+    vks.iter()
+        .find(|(_, vk)| verify(proof_bytes, vk, public_signals).unwrap_or(false))
+        .map(|(ct, _)| *ct)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{prove, setup};
+    use ark_std::rand::SeedableRng;
+
+    #[test]
+    fn test_verify_valid_proof() {
+        let (pk, vk) = setup(1);
+        let (proof, signals) = prove(&pk, 3, 5);
+        assert!(verify(&proof, &vk, &signals).unwrap());
+    }
+
+    #[test]
+    fn test_verify_wrong_signal_fails() {
+        let (pk, vk) = setup(1);
+        let (proof, _) = prove(&pk, 3, 5);
+        let wrong = vec![crate::test_utils::to_hex_le(Bn254Fr::from(16u64))];
+        assert!(!verify(&proof, &vk, &wrong).unwrap());
+    }
+
+    #[test]
+    fn test_verify_borrowed_matches_groth16() {
+        let (pk, vk) = setup(1);
+        let (proof, signals) = prove(&pk, 3, 5);
+        assert!(verify_borrowed(&vk, &proof, &signals).unwrap());
+        let wrong = vec![crate::test_utils::to_hex_le(Bn254Fr::from(16u64))];
+        assert!(!verify_borrowed(&vk, &proof, &wrong).unwrap());
+        assert!(verify_borrowed(&vk, &proof, &[]).is_err());
+
+        let parsed = parse_proof(&proof).unwrap();
+        let inputs = parse_signals(&signals).unwrap();
+        assert!(Groth16::<Bn254>::verify(&vk, &inputs, &parsed).unwrap());
+    }
+
+    #[test]
+    fn test_verify_timed_reports_elapsed() {
+        let (pk, vk) = setup(1);
+        let (proof, signals) = prove(&pk, 3, 5);
+        let (valid, micros) = verify_timed(&proof, &vk, &signals).unwrap();
+        assert!(valid);
+        assert!(micros > 0);
+        assert!(verify_timed(&proof, &vk, &[]).is_err());
+    }
+
+    #[test]
+    fn test_verify_with_commitment() {
+        let (pk, vk) = setup(1);
+        let (proof, signals) = prove(&pk, 3, 5);
+        let commitment = witness_commitment(&[Bn254Fr::from(15u64)]);
+        assert!(verify_with_commitment(&proof, &vk, &signals, &commitment).unwrap());
+        assert!(
+            verify_with_commitment(&proof, &vk, &signals, &commitment.to_uppercase()[2..]).unwrap()
+        );
+
+        let other = witness_commitment(&[Bn254Fr::from(16u64)]);
+        let err = verify_with_commitment(&proof, &vk, &signals, &other).unwrap_err();
+        assert!(err.contains("expected"), "{err}");
+    }
+
+    #[test]
+    fn test_verify_report_fields() {
+        let (pk, vk) = setup(1);
+        let (proof, signals) = prove(&pk, 3, 5);
+        let path = std::env::temp_dir().join(format!("orbinum-report-vk-{}", std::process::id()));
+        let mut vk_bytes = Vec::new();
+        vk.serialize_compressed(&mut vk_bytes).unwrap();
+        std::fs::write(&path, &vk_bytes).unwrap();
+        let vk_path = path.to_str().unwrap();
+
+        let report = verify_report(&proof, vk_path, &signals).unwrap();
+        assert!(report.valid);
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(json["valid"], true);
+        assert_eq!(json["vk_fingerprint"], vk_fingerprint(&vk));
+        assert_eq!(json["public_signals"], serde_json::json!(signals));
+        assert_eq!(
+            json["proof_sha256"],
+            format!("0x{}", hex::encode(Sha256::digest(&proof)))
+        );
+        assert!(json["verified_at"].as_u64().unwrap() > 1_600_000_000);
+
+        let (_, other_signals) = prove(&pk, 3, 6);
+        assert!(
+            !verify_report(&proof, vk_path, &other_signals)
+                .unwrap()
+                .valid
+        );
+        std::fs::remove_file(&path).unwrap();
+        assert!(verify_report(&proof, vk_path, &signals).is_err());
+    }
+
+    #[test]
+    fn test_compute_vk_x_tracks_signals() {
+        let (_, vk) = setup(1);
+        let to_hex = |v: u64| vec![crate::test_utils::to_hex_le(Bn254Fr::from(v))];
+        let x15 = compute_vk_x(&vk, &to_hex(15)).unwrap();
+        assert_eq!(x15.len(), 2 + 64);
+        assert_eq!(x15, compute_vk_x(&vk, &to_hex(15)).unwrap());
+        assert_ne!(x15, compute_vk_x(&vk, &to_hex(16)).unwrap());
+
+        // Zero signals leave only IC[0].
+        let mut ic0 = Vec::new();
+        vk.gamma_abc_g1[0].serialize_compressed(&mut ic0).unwrap();
+        assert_eq!(
+            compute_vk_x(&vk, &to_hex(0)).unwrap(),
+            format!("0x{}", hex::encode(ic0))
+        );
+
+        let err = compute_vk_x(&vk, &[]).unwrap_err();
+        assert!(err.contains("expected 1 public signals, got 0"));
+    }
+
+    #[test]
+    fn test_verify_rejects_malformed_proof() {
+        let (_, vk) = setup(1);
+        let signals = vec![crate::test_utils::to_hex_le(Bn254Fr::from(15u64))];
+        let err = verify(b"garbage", &vk, &signals).unwrap_err();
+        assert!(matches!(err, ProofError::ProofParse(_)));
+    }
+
+    #[test]
+    fn test_signal_count_checked_before_decoding() {
+        let (pk, vk) = setup(1);
+        let (proof, mut signals) = prove(&pk, 3, 5);
+        signals.push(signals[0].clone());
+
+        let err = verify(&proof, &vk, &signals).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            ProofError::Verification("expected 1 public signals, got 2".into()).to_string()
+        );
+        // The count is checked before the (malformed) proof is decoded.
+        let err = verify(b"garbage", &vk, &signals).unwrap_err();
+        assert!(err.to_string().contains("expected 1 public signals, got 2"));
+
+        let verifier = Verifier::from_bytes(&vk_bytes(&vk), 1).unwrap();
+        let err = verifier.verify(&proof, &signals).unwrap_err();
+        assert!(err.to_string().contains("expected 1 public signals, got 2"));
+    }
+
+    fn vk_bytes(vk: &VerifyingKey<Bn254>) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        vk.serialize_compressed(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_verifier_verifies_valid_proof() {
+        let (pk, vk) = setup(1);
+        let (proof, signals) = prove(&pk, 3, 5);
+        let verifier = Verifier::from_bytes(&vk_bytes(&vk), 1).unwrap();
+        assert!(verifier.verify(&proof, &signals).unwrap());
+    }
+
+    #[test]
+    fn test_verifier_rejects_ic_mismatch() {
+        let (_, vk) = setup(1);
+        let err = Verifier::from_bytes(&vk_bytes(&vk), 5).err().unwrap();
+        assert!(err
+            .to_string()
+            .contains("key supports 1 public inputs, 5 requested"));
+    }
+
+    #[test]
+    fn test_verifier_rejects_invalid_vk_bytes() {
+        let err = Verifier::from_bytes(b"not a vk", 1).err().unwrap();
+        assert!(matches!(err, ProofError::VerifyingKeyParse(_)));
+    }
+
+    fn verify_parts(
+        proof_bytes: &[u8],
+        vk: &VerifyingKey<Bn254>,
+        signal: u64,
+    ) -> Result<bool, ProofError> {
+        let proof = parse_proof(proof_bytes).unwrap();
+        verify_with_components(
+            proof.a,
+            proof.b,
+            proof.c,
+            Bn254::pairing(vk.alpha_g1, vk.beta_g2),
+            vk.gamma_g2,
+            vk.delta_g2,
+            &vk.gamma_abc_g1,
+            &[Bn254Fr::from(signal)],
+        )
+    }
+
+    #[test]
+    fn test_verify_with_components_matches_verify() {
+        let (pk, vk) = setup(1);
+        let (proof, signals) = prove(&pk, 3, 5);
+        assert!(verify(&proof, &vk, &signals).unwrap());
+        assert!(verify_parts(&proof, &vk, 15).unwrap());
+        assert!(!verify_parts(&proof, &vk, 16).unwrap());
+    }
+
+    #[test]
+    fn test_verify_with_components_rejects_ic_mismatch() {
+        let (pk, vk) = setup(1);
+        let (proof, _) = prove(&pk, 3, 5);
+        let proof = parse_proof(&proof).unwrap();
+        let err = verify_with_components(
+            proof.a,
+            proof.b,
+            proof.c,
+            Bn254::pairing(vk.alpha_g1, vk.beta_g2),
+            vk.gamma_g2,
+            vk.delta_g2,
+            &vk.gamma_abc_g1[..1],
+            &[Bn254Fr::from(15u64)],
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("expected 0 public signals, got 1"));
+    }
+
+    #[test]
+    fn test_batch_verify_all_valid() {
+        let (pk, vk) = setup(1);
+        let batch: Vec<_> = [(2, 3), (4, 5), (6, 7)]
+            .iter()
+            .map(|&(a, b)| prove(&pk, a, b))
+            .collect();
+        let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(7);
+        assert!(batch_verify(&batch, &vk, &mut rng).unwrap());
+    }
+
+    #[test]
+    fn test_batch_verify_one_tampered() {
+        let (pk, vk) = setup(1);
+        let mut batch: Vec<_> = [(2, 3), (4, 5), (6, 7)]
+            .iter()
+            .map(|&(a, b)| prove(&pk, a, b))
+            .collect();
+        batch[1].1 = vec![crate::test_utils::to_hex_le(Bn254Fr::from(21u64))];
+        let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(7);
+        assert!(!batch_verify(&batch, &vk, &mut rng).unwrap());
+    }
+
+    #[test]
+    fn test_batch_verify_empty_is_valid() {
+        let (_, vk) = setup(1);
+        let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(7);
+        assert!(batch_verify(&[], &vk, &mut rng).unwrap());
+    }
+
+    #[test]
+    fn test_batch_verify_rejects_wrong_arity() {
+        let (pk, vk) = setup(1);
+        let (proof, _) = prove(&pk, 2, 3);
+        let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(7);
+        let err = batch_verify(&[(proof, vec![])], &vk, &mut rng).unwrap_err();
+        assert!(err.to_string().contains("expected 1 public signals, got 0"));
+    }
+
+    #[test]
+    fn test_identify_circuit_among_candidates() {
+        let (_, vk_unshield) = setup(1);
+        let (pk_transfer, vk_transfer) = setup(2);
+        let (_, vk_disclosure) = setup(3);
+        let (proof, signals) = prove(&pk_transfer, 6, 7);
+
+        let candidates = vec![
+            (CircuitType::Unshield, vk_unshield),
+            (CircuitType::Transfer, vk_transfer),
+            (CircuitType::Disclosure, vk_disclosure),
+        ];
+        assert_eq!(
+            identify_circuit(&proof, &signals, &candidates),
+            Some(CircuitType::Transfer)
+        );
+    }
+
+    #[test]
+    fn test_identify_circuit_no_match() {
+        let (pk, _) = setup(1);
+        let (_, other_vk) = setup(2);
+        let (proof, signals) = prove(&pk, 6, 7);
+        let candidates = vec![(CircuitType::Unshield, other_vk)];
+        assert_eq!(identify_circuit(&proof, &signals, &candidates), None);
+    }
+
+    #[test]
+    fn test_verify_full() {
+        // Keys for a disclosure-shaped layout: four public signals.
+        let layout = crate::WitnessCircuit {
+            witness: vec![Bn254Fr::zero(); 6],
+            num_public_signals: 4,
+        };
+        let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(9);
+        let (pk, vk) = Groth16::<Bn254>::circuit_specific_setup(layout, &mut rng).unwrap();
+        let witness: Vec<Bn254Fr> = [1u64, 10, 11, 12, 13, 99].map(Bn254Fr::from).to_vec();
+        let signals = CircuitType::Disclosure.public_signals(&witness).unwrap();
+        let circuit = crate::WitnessCircuit {
+            witness,
+            num_public_signals: 4,
+        };
+        let mut proof = Vec::new();
+        Groth16::<Bn254>::prove(&pk, circuit, &mut rng)
+            .unwrap()
+            .serialize_compressed(&mut proof)
+            .unwrap();
+
+        assert!(verify_full(CircuitType::Disclosure, &proof, &vk, &signals).unwrap());
+
+        // A well-formed but wrong signal is a pairing failure, not an error.
+        let mut wrong = signals.clone();
+        wrong[0] = crate::test_utils::to_hex_le(Bn254Fr::from(16u64));
+        assert!(!verify_full(CircuitType::Disclosure, &proof, &vk, &wrong).unwrap());
+
+        // Structural mismatches are errors.
+        let err = verify_full(CircuitType::Transfer, &proof, &vk, &signals).unwrap_err();
+        assert_eq!(
+            err,
+            "transfer expects 5 public inputs, but the verifying key has 4"
+        );
+        let err = verify_full(CircuitType::Disclosure, &proof, &vk, &signals[..3]).unwrap_err();
+        assert_eq!(err, "disclosure expects 4 public signals, got 3");
+        assert!(verify_full(CircuitType::Disclosure, &proof[..64], &vk, &signals).is_err());
+    }
+}
@@ -2,10 +2,17 @@ use ark_bn254::Fr as Bn254Fr;
 use ark_ff::{BigInteger, PrimeField};
 use wasm_bindgen::prelude::*;
 
-use crate::field::from_decimal_str;
 use crate::prover::prove_from_witness;
+use crate::witness::parse_decimal_witness_json;
 
+mod convert_proof;
+mod registry;
 mod snarkjs_proof;
+pub use convert_proof::convert_proof_wasm;
+pub use registry::{
+    generate_proof_with_handle_wasm, load_proving_key_wasm, release_proving_key_wasm,
+    set_key_registry_capacity,
+};
 pub use snarkjs_proof::compress_snarkjs_proof_wasm;
 
 #[cfg(target_arch = "wasm32")]
@@ -23,52 +30,96 @@ pub fn generate_proof_from_decimal_wasm(
     witness_json: &str,
     proving_key_bytes: &[u8],
 ) -> Result<String, JsValue> {
-    let witness_strings: Vec<String> = serde_json::from_str(witness_json)
-        .map_err(|e| JsValue::from_str(&format!("Failed to parse witness JSON: {e}")))?;
+    generate_proof_from_decimal(num_public_signals, witness_json, proving_key_bytes)
+        .map_err(|e| JsValue::from_str(&e))
+}
 
-    let witness: Vec<Bn254Fr> = witness_strings
-        .iter()
-        .map(|s| from_decimal_str::<Bn254Fr>(s))
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| JsValue::from_str(&e))?;
+/// [`generate_proof_from_decimal_wasm`] as a `Promise` resolving to the same JSON
+/// string (or rejecting with the error message).
+///
+/// Proving starts once the promise is polled, after the calling task finishes, so
+/// the caller can `await` it alongside other work. WASM is single-threaded here:
+/// the proof itself still occupies the thread while it runs, so run it in a web
+/// worker to keep the page fully responsive.
+#[wasm_bindgen]
+pub fn generate_proof_wasm_async(
+    num_public_signals: usize,
+    witness_json: String,
+    proving_key_bytes: Vec<u8>,
+) -> js_sys::Promise {
+    wasm_bindgen_futures::future_to_promise(async move {
+        generate_proof_async(num_public_signals, witness_json, proving_key_bytes)
+            .await
+            .map(|output| JsValue::from_str(&output))
+            .map_err(|e| JsValue::from_str(&e))
+    })
+}
+
+async fn generate_proof_async(
+    num_public_signals: usize,
+    witness_json: String,
+    proving_key_bytes: Vec<u8>,
+) -> Result<String, String> {
+    generate_proof_from_decimal(num_public_signals, &witness_json, &proving_key_bytes)
+}
 
+fn generate_proof_from_decimal(
+    num_public_signals: usize,
+    witness_json: &str,
+    proving_key_bytes: &[u8],
+) -> Result<String, String> {
+    let witness = parse_decimal_witness(witness_json)?;
+    let public_signals = extract_public_signals(&witness, num_public_signals)?;
+
+    let proof_bytes = prove_from_witness(proving_key_bytes, witness, num_public_signals)
+        .map_err(|e| e.to_string())?;
+
+    render_proof_output(&proof_bytes, &public_signals)
+}
+
+/// Decimal strings or raw JSON numbers, see [`parse_decimal_witness_json`].
+fn parse_decimal_witness(witness_json: &str) -> Result<Vec<Bn254Fr>, String> {
+    parse_decimal_witness_json(witness_json).map_err(|e| e.to_string())
+}
+
+/// Hex-LE public signals (indices `1..=num_public_signals`), extracted before the
+/// witness is moved into the prover.
+fn extract_public_signals(
+    witness: &[Bn254Fr],
+    num_public_signals: usize,
+) -> Result<Vec<String>, String> {
     if num_public_signals == 0 {
-        return Err(JsValue::from_str(
-            "num_public_signals must be greater than 0",
-        ));
+        return Err("num_public_signals must be greater than 0".into());
     }
     if num_public_signals >= witness.len() {
-        return Err(JsValue::from_str(&format!(
+        return Err(format!(
             "num_public_signals ({num_public_signals}) exceeds witness length ({})",
             witness.len()
-        )));
+        ));
     }
-
-    // Extract public signals before moving witness into the prover.
-    let public_signals: Vec<String> = witness[1..=num_public_signals]
+    Ok(witness[1..=num_public_signals]
         .iter()
         .map(|f| {
             let mut bytes = f.into_bigint().to_bytes_le();
             bytes.resize(32, 0u8);
             format!("0x{}", hex::encode(&bytes))
         })
-        .collect();
-
-    let proof_bytes = prove_from_witness(proving_key_bytes, witness, num_public_signals)
-        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        .collect())
+}
 
+fn render_proof_output(proof_bytes: &[u8], public_signals: &[String]) -> Result<String, String> {
     let output = serde_json::json!({
-        "proof": format!("0x{}", hex::encode(&proof_bytes)),
+        "proof": format!("0x{}", hex::encode(proof_bytes)),
         "publicSignals": public_signals,
     });
 
-    serde_json::to_string(&output)
-        .map_err(|e| JsValue::from_str(&format!("Failed to serialize output: {e}")))
+    serde_json::to_string(&output).map_err(|e| format!("Failed to serialize output: {e}"))
 }
 
 #[cfg(all(test, not(target_arch = "wasm32")))]
 mod tests {
     use super::*;
+    use crate::field::from_decimal_str;
 
     #[test]
     fn test_decimal_witness_parse_and_convert() {
@@ -91,6 +142,27 @@ mod tests {
         assert_eq!(hex.len(), 66); // "0x" + 64 hex chars
     }
 
+    #[test]
+    fn test_async_proof_matches_sync_output_shape() {
+        use std::future::Future;
+        use std::task::{Context, Poll, Waker};
+
+        let (pk, _) = crate::test_utils::setup(1);
+        let mut pk_bytes = Vec::new();
+        ark_serialize::CanonicalSerialize::serialize_compressed(&pk, &mut pk_bytes).unwrap();
+        let future = generate_proof_async(1, r#"["1","15","3","5"]"#.into(), pk_bytes);
+        let Poll::Ready(output) =
+            std::pin::pin!(future).poll(&mut Context::from_waker(Waker::noop()))
+        else {
+            panic!("proving future should complete on first poll");
+        };
+        let output: serde_json::Value = serde_json::from_str(&output.unwrap()).unwrap();
+        assert_eq!(
+            output["publicSignals"][0],
+            format!("0x0f{}", "00".repeat(31))
+        );
+    }
+
     #[test]
     fn test_output_json_has_required_fields() {
         let output = serde_json::json!({
@@ -0,0 +1,86 @@
+use base64::Engine;
+use wasm_bindgen::prelude::*;
+
+use crate::export::{proof_points_calldata, proof_to_uint256_words, CoordinateEndian, G2Order};
+use crate::field::strip_hex_prefix;
+
+/// Convert a `0x`-hex compressed proof into another encoding.
+///
+/// `target`:
+/// - `"calldata"`     — Solidity calldata proof points `[a],[[b]],[c]` (append `,[inputs]`)
+/// - `"uint256words"` — JSON array of eight big-endian `0x` words
+/// - `"base64"`       — standard base64 of the compressed bytes
+#[wasm_bindgen]
+pub fn convert_proof_wasm(proof_hex: &str, target: &str) -> Result<String, JsValue> {
+    convert_proof(proof_hex, target).map_err(|e| JsValue::from_str(&e))
+}
+
+fn convert_proof(proof_hex: &str, target: &str) -> Result<String, String> {
+    let bytes = hex::decode(strip_hex_prefix(proof_hex))
+        .map_err(|e| format!("Failed to decode proof hex: {e}"))?;
+    match target {
+        "calldata" => {
+            proof_points_calldata(&bytes, G2Order::default(), CoordinateEndian::default())
+                .map_err(|e| e.to_string())
+        }
+        "uint256words" => {
+            let words =
+                proof_to_uint256_words(&bytes, G2Order::default(), CoordinateEndian::default())
+                    .map_err(|e| e.to_string())?;
+            serde_json::to_string(&words).map_err(|e| e.to_string())
+        }
+        "base64" => Ok(base64::engine::general_purpose::STANDARD.encode(&bytes)),
+        other => Err(format!(
+            "Unknown target encoding: {other} (expected calldata, uint256words or base64)"
+        )),
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use ark_bn254::{Bn254, G1Projective, G2Projective};
+    use ark_ec::{CurveGroup, PrimeGroup};
+    use ark_groth16::Proof as ArkProof;
+    use ark_serialize::CanonicalSerialize;
+
+    fn generator_proof_hex() -> String {
+        let proof = ArkProof::<Bn254> {
+            a: G1Projective::generator().into_affine(),
+            b: G2Projective::generator().into_affine(),
+            c: G1Projective::generator().into_affine(),
+        };
+        let mut bytes = Vec::new();
+        proof.serialize_compressed(&mut bytes).unwrap();
+        format!("0x{}", hex::encode(bytes))
+    }
+
+    #[test]
+    fn test_convert_to_calldata() {
+        let calldata = convert_proof_wasm(&generator_proof_hex(), "calldata").unwrap();
+        assert!(calldata.starts_with(&format!("[\"0x{:064x}\",\"0x{:064x}\"]", 1, 2)));
+        assert_eq!(calldata.matches("0x").count(), 8);
+    }
+
+    #[test]
+    fn test_convert_to_uint256_words() {
+        let json = convert_proof_wasm(&generator_proof_hex(), "uint256words").unwrap();
+        let words: Vec<String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(words.len(), 8);
+    }
+
+    #[test]
+    fn test_convert_to_base64() {
+        let b64 = convert_proof_wasm(&generator_proof_hex(), "base64").unwrap();
+        let decoded = base64::engine::general_purpose::STANDARD
+            .decode(b64)
+            .unwrap();
+        assert_eq!(decoded.len(), 128);
+    }
+
+    #[test]
+    fn test_convert_unknown_target() {
+        let err = convert_proof(&generator_proof_hex(), "rlp").unwrap_err();
+        assert!(err.contains("Unknown target encoding"));
+    }
+}
@@ -0,0 +1,227 @@
+//! Handle-based proving over preloaded keys.
+//!
+//! The registry is thread-local, so each module instance — the main thread or any web
+//! worker that instantiates the `.wasm` — owns an independent registry. Handles are
+//! only meaningful inside the instance that issued them: a worker should load its own
+//! key and prove with its own handle.
+
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+
+use wasm_bindgen::prelude::*;
+
+use super::{extract_public_signals, parse_decimal_witness, render_proof_output};
+use crate::prover::ProofGenerator;
+
+/// Default number of proving keys kept alive at once.
+const DEFAULT_CAPACITY: usize = 4;
+
+/// Bounded LRU registry of deserialized proving keys, addressed by handle.
+///
+/// Handles are never reused, so a lookup for an evicted or released handle
+/// reports that instead of silently hitting a different key.
+pub(crate) struct KeyRegistry {
+    capacity: usize,
+    next_handle: u32,
+    keys: HashMap<u32, ProofGenerator>,
+    /// Least recently used at the front.
+    lru: VecDeque<u32>,
+}
+
+impl KeyRegistry {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            next_handle: 1,
+            keys: HashMap::new(),
+            lru: VecDeque::new(),
+        }
+    }
+
+    pub(crate) fn insert(&mut self, generator: ProofGenerator) -> u32 {
+        while self.keys.len() >= self.capacity {
+            self.evict_lru();
+        }
+        let handle = self.next_handle;
+        self.next_handle += 1;
+        self.keys.insert(handle, generator);
+        self.lru.push_back(handle);
+        handle
+    }
+
+    /// Look up `handle`, marking it most recently used.
+    pub(crate) fn get(&mut self, handle: u32) -> Result<&ProofGenerator, String> {
+        if !self.keys.contains_key(&handle) {
+            return Err(if handle != 0 && handle < self.next_handle {
+                format!("Proving key handle {handle} was evicted or released; load it again")
+            } else {
+                format!("Unknown proving key handle {handle}")
+            });
+        }
+        self.lru.retain(|&h| h != handle);
+        self.lru.push_back(handle);
+        Ok(&self.keys[&handle])
+    }
+
+    pub(crate) fn remove(&mut self, handle: u32) -> bool {
+        self.lru.retain(|&h| h != handle);
+        self.keys.remove(&handle).is_some()
+    }
+
+    pub(crate) fn set_capacity(&mut self, capacity: usize) -> Result<(), String> {
+        if capacity == 0 {
+            return Err("Key registry capacity must be greater than 0".into());
+        }
+        self.capacity = capacity;
+        while self.keys.len() > self.capacity {
+            self.evict_lru();
+        }
+        Ok(())
+    }
+
+    fn evict_lru(&mut self) {
+        if let Some(handle) = self.lru.pop_front() {
+            self.keys.remove(&handle);
+        }
+    }
+}
+
+thread_local! {
+    static REGISTRY: RefCell<KeyRegistry> = RefCell::new(KeyRegistry::new(DEFAULT_CAPACITY));
+}
+
+/// Deserialize a proving key once and return a handle for repeated proving.
+///
+/// When the registry is full the least recently used key is evicted.
+#[wasm_bindgen]
+pub fn load_proving_key_wasm(
+    proving_key_bytes: &[u8],
+    num_public_signals: usize,
+) -> Result<u32, JsValue> {
+    let generator = ProofGenerator::from_bytes(proving_key_bytes, num_public_signals)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(REGISTRY.with(|r| r.borrow_mut().insert(generator)))
+}
+
+/// Same input and output as `generate_proof_from_decimal_wasm`, using a preloaded key.
+#[wasm_bindgen]
+pub fn generate_proof_with_handle_wasm(handle: u32, witness_json: &str) -> Result<String, JsValue> {
+    generate_proof_with_handle(handle, witness_json).map_err(|e| JsValue::from_str(&e))
+}
+
+fn generate_proof_with_handle(handle: u32, witness_json: &str) -> Result<String, String> {
+    let witness = parse_decimal_witness(witness_json)?;
+    REGISTRY.with(|r| {
+        let mut registry = r.borrow_mut();
+        let generator = registry.get(handle)?;
+        let public_signals = extract_public_signals(&witness, generator.num_public_signals())?;
+        let proof_bytes = generator.prove(witness).map_err(|e| e.to_string())?;
+        render_proof_output(&proof_bytes, &public_signals)
+    })
+}
+
+/// Drop a preloaded key. Returns `false` if the handle was not loaded.
+#[wasm_bindgen]
+pub fn release_proving_key_wasm(handle: u32) -> bool {
+    REGISTRY.with(|r| r.borrow_mut().remove(handle))
+}
+
+/// Bound the number of preloaded keys, evicting least recently used keys if needed.
+#[wasm_bindgen]
+pub fn set_key_registry_capacity(capacity: usize) -> Result<(), JsValue> {
+    REGISTRY
+        .with(|r| r.borrow_mut().set_capacity(capacity))
+        .map_err(|e| JsValue::from_str(&e))
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use ark_serialize::CanonicalSerialize;
+
+    fn generator() -> ProofGenerator {
+        let (pk, _) = crate::test_utils::setup(1);
+        let mut bytes = Vec::new();
+        pk.serialize_compressed(&mut bytes).unwrap();
+        ProofGenerator::from_bytes(&bytes, 1).unwrap()
+    }
+
+    #[test]
+    fn test_registry_evicts_least_recently_used() {
+        let mut registry = KeyRegistry::new(2);
+        let a = registry.insert(generator());
+        let b = registry.insert(generator());
+        registry.get(a).unwrap(); // b is now least recently used
+        let c = registry.insert(generator());
+
+        assert!(registry.get(a).is_ok());
+        assert!(registry.get(c).is_ok());
+        let err = registry.get(b).err().unwrap();
+        assert!(err.contains("evicted"));
+    }
+
+    #[test]
+    fn test_registry_shrinking_capacity_evicts() {
+        let mut registry = KeyRegistry::new(3);
+        let handles: Vec<u32> = (0..3).map(|_| registry.insert(generator())).collect();
+        registry.set_capacity(1).unwrap();
+        assert!(registry.get(handles[0]).is_err());
+        assert!(registry.get(handles[1]).is_err());
+        assert!(registry.get(handles[2]).is_ok());
+    }
+
+    #[test]
+    fn test_registry_rejects_zero_capacity() {
+        assert!(KeyRegistry::new(1).set_capacity(0).is_err());
+    }
+
+    #[test]
+    fn test_registry_unknown_and_released_handles() {
+        let mut registry = KeyRegistry::new(2);
+        assert!(registry.get(7).err().unwrap().contains("Unknown"));
+        let h = registry.insert(generator());
+        assert!(registry.remove(h));
+        assert!(!registry.remove(h));
+        assert!(registry.get(h).err().unwrap().contains("released"));
+    }
+
+    #[test]
+    fn test_fresh_instance_has_its_own_registry() {
+        let mut bytes = Vec::new();
+        crate::test_utils::setup(1)
+            .0
+            .serialize_compressed(&mut bytes)
+            .unwrap();
+        let main_handle = load_proving_key_wasm(&bytes, 1).unwrap();
+
+        // Native supplement to tests/wasm_registry.rs: a new OS thread gets its own
+        // thread-local registry, as each module instance does on wasm32.
+        let worker = std::thread::spawn(move || {
+            let foreign = generate_proof_with_handle(main_handle, r#"["1","15","3","5"]"#);
+            let handle = load_proving_key_wasm(&bytes, 1).unwrap();
+            let output = generate_proof_with_handle(handle, r#"["1","15","3","5"]"#).unwrap();
+            (foreign, output)
+        });
+        let (foreign, output) = worker.join().unwrap();
+
+        assert!(foreign.unwrap_err().contains("Unknown proving key handle"));
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(json["proof"].as_str().unwrap().len(), 2 + 256);
+        assert!(release_proving_key_wasm(main_handle));
+    }
+
+    #[test]
+    fn test_generate_with_handle() {
+        let mut bytes = Vec::new();
+        crate::test_utils::setup(1)
+            .0
+            .serialize_compressed(&mut bytes)
+            .unwrap();
+        let handle = load_proving_key_wasm(&bytes, 1).unwrap();
+        let output = generate_proof_with_handle(handle, r#"["1", "15", "3", "5"]"#).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(json["proof"].as_str().unwrap().len(), 2 + 256);
+        assert_eq!(json["publicSignals"].as_array().unwrap().len(), 1);
+        assert!(release_proving_key_wasm(handle));
+    }
+}
@@ -0,0 +1,710 @@
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::io::{BufReader, Bytes, Read};
+
+use ark_bn254::Fr as Bn254Fr;
+use ark_ff::Zero;
+use serde_json::value::RawValue;
+
+use crate::error::ProofError;
+use crate::field::{from_hex_le, to_le_bytes_32};
+use crate::utils::{decimal_to_field, field_from_limbs};
+
+/// Sparse witness: `len` total entries, all zero except the listed indices.
+#[derive(serde::Deserialize)]
+struct SparseWitness {
+    len: usize,
+    nonzero: BTreeMap<String, String>,
+}
+
+/// Largest `len` [`parse_sparse_witness`] will materialize: 2^26 entries, 2 GiB of
+/// field elements, above any circuit this crate proves.
+pub const MAX_SPARSE_WITNESS_LEN: usize = 1 << 26;
+
+/// Materialize a sparse witness spec into a dense vector.
+///
+/// Input shape: `{"len": 1000, "nonzero": {"1": "0x0a", "5": "0x14"}}` where keys are
+/// witness indices and values are hex-LE field elements. Unlisted indices are zero,
+/// including index 0 — list `"0": "0x01"` explicitly for the Circom constant.
+///
+/// `len` is checked against [`MAX_SPARSE_WITNESS_LEN`] before anything is allocated;
+/// use [`parse_sparse_witness_with_max_len`] to bound it by the key's witness length.
+pub fn parse_sparse_witness(json: &str) -> Result<Vec<Bn254Fr>, ProofError> {
+    parse_sparse_witness_with_max_len(json, MAX_SPARSE_WITNESS_LEN)
+}
+
+/// [`parse_sparse_witness`] that rejects a `len` above `max_len`.
+pub fn parse_sparse_witness_with_max_len(
+    json: &str,
+    max_len: usize,
+) -> Result<Vec<Bn254Fr>, ProofError> {
+    let sparse: SparseWitness =
+        serde_json::from_str(json).map_err(|e| ProofError::WitnessJsonParse(e.to_string()))?;
+    if sparse.len > max_len {
+        return Err(ProofError::WitnessJsonParse(format!(
+            "len {} exceeds the maximum of {max_len}",
+            sparse.len
+        )));
+    }
+    let mut dense = vec![Bn254Fr::zero(); sparse.len];
+    for (key, value) in &sparse.nonzero {
+        let index: usize = key
+            .parse()
+            .map_err(|_| ProofError::WitnessJsonParse(format!("invalid index key: {key}")))?;
+        let slot = dense.get_mut(index).ok_or_else(|| {
+            ProofError::WitnessJsonParse(format!(
+                "index {index} out of range for len {}",
+                sparse.len
+            ))
+        })?;
+        *slot = from_hex_le(value).map_err(ProofError::WitnessConversion)?;
+    }
+    Ok(dense)
+}
+
+/// One witness entry in JSON: a hex-LE string or a `[u64; 4]` little-endian limb array.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[serde(untagged)]
+pub enum WitnessEntry {
+    Hex(String),
+    Limbs([u64; 4]),
+}
+
+impl WitnessEntry {
+    pub fn to_field(&self) -> Result<Bn254Fr, String> {
+        match self {
+            WitnessEntry::Hex(hex) => from_hex_le(hex),
+            WitnessEntry::Limbs(limbs) => field_from_limbs(*limbs),
+        }
+    }
+
+    /// Hex-LE form: hex entries are returned unchanged, limb arrays are encoded as
+    /// `0x` 32-byte LE hex.
+    pub fn into_hex(self) -> Result<String, String> {
+        match self {
+            WitnessEntry::Hex(hex) => Ok(hex),
+            WitnessEntry::Limbs(limbs) => Ok(format!(
+                "0x{}",
+                hex::encode(to_le_bytes_32(&field_from_limbs(limbs)?))
+            )),
+        }
+    }
+}
+
+/// Parse a JSON array whose entries are hex-LE strings or `[u64; 4]` limb arrays,
+/// in any mix, e.g. `["0x01", [123, 456, 0, 0]]`.
+pub fn parse_witness_entries(json: &str) -> Result<Vec<Bn254Fr>, ProofError> {
+    parse_witness_entries_labeled(json, None)
+}
+
+/// [`parse_witness_entries`] with errors that name where the bad entry came from:
+/// `"witness.json[17]: Failed to decode hex: …"` for `source_label =
+/// Some("witness.json")`, `"witness[17]: …"` without a label.
+pub fn parse_witness_entries_labeled(
+    json: &str,
+    source_label: Option<&str>,
+) -> Result<Vec<Bn254Fr>, ProofError> {
+    let entries: Vec<WitnessEntry> = serde_json::from_str(json).map_err(|e| {
+        ProofError::WitnessJsonParse(format!("{}: {e}", source_label.unwrap_or("witness")))
+    })?;
+    entries
+        .iter()
+        .enumerate()
+        .map(|(i, e)| {
+            e.to_field()
+                .map_err(|e| ProofError::WitnessConversion(at_index(source_label, i, e)))
+        })
+        .collect()
+}
+
+/// Parse a decimal witness JSON array whose entries are strings (snarkjs
+/// `witness.json`, `["1", "5"]`) or raw JSON numbers (`[1, 5]`), in any mix.
+///
+/// Numbers are read from their literal text, not through `f64`, so values beyond
+/// 2^53 (any real field element) survive intact. Both forms go through
+/// [`decimal_to_field`](crate::decimal_to_field); fractions, exponents and negative
+/// numbers are rejected.
+pub fn parse_decimal_witness_json(json: &str) -> Result<Vec<Bn254Fr>, ProofError> {
+    let entries: Vec<&RawValue> = serde_json::from_str(json)
+        .map_err(|e| ProofError::WitnessJsonParse(format!("witness: {e}")))?;
+    entries
+        .iter()
+        .enumerate()
+        .map(|(i, raw)| {
+            decimal_entry_to_field(raw.get())
+                .map_err(|e| ProofError::WitnessConversion(at_index(None, i, e)))
+        })
+        .collect()
+}
+
+fn decimal_entry_to_field(raw: &str) -> Result<Bn254Fr, String> {
+    if raw.starts_with('"') {
+        let text: String = serde_json::from_str(raw).map_err(|e| e.to_string())?;
+        decimal_to_field(&text)
+    } else if !raw.is_empty() && raw.bytes().all(|b| b.is_ascii_digit()) {
+        decimal_to_field(raw)
+    } else {
+        Err(format!(
+            "expected a decimal string or non-negative integer, got {raw}"
+        ))
+    }
+}
+
+/// Prefix a witness entry error with its location, `label[index]: `.
+pub(crate) fn at_index(
+    source_label: Option<&str>,
+    index: usize,
+    error: impl fmt::Display,
+) -> String {
+    format!("{}[{index}]: {error}", source_label.unwrap_or("witness"))
+}
+
+/// Re-encode a witness JSON array in one stable form: a compact array of `0x` +
+/// 64 lowercase hex-digit LE field elements.
+///
+/// Entries may be in any form [`parse_witness_entries`] accepts (hex of any case or
+/// length, or limb arrays), so two witnesses with the same field elements produce
+/// the same bytes — suitable as a content-addressed cache key. Values at or above
+/// the modulus are reduced, as they would be when proving.
+pub fn canonicalize_witness_json(raw: &str) -> Result<String, String> {
+    let witness = parse_witness_entries(raw).map_err(|e| e.to_string())?;
+    let canonical: Vec<String> = witness
+        .iter()
+        .map(|f| format!("0x{}", hex::encode(to_le_bytes_32(f))))
+        .collect();
+    serde_json::to_string(&canonical).map_err(|e| e.to_string())
+}
+
+/// Check a witness against the variable count a proving key expects.
+///
+/// A short witness is zero-padded when `pad` is set — valid for circuits whose
+/// trailing private signals are zero — and rejected otherwise. A witness longer than
+/// `expected_len` is always rejected, since dropping values would silently prove
+/// something else.
+pub fn fit_witness_to_key(
+    mut witness: Vec<Bn254Fr>,
+    expected_len: usize,
+    pad: bool,
+) -> Result<Vec<Bn254Fr>, String> {
+    if witness.len() > expected_len || (witness.len() < expected_len && !pad) {
+        return Err(format!(
+            "witness has {} elements, key expects {expected_len}",
+            witness.len()
+        ));
+    }
+    witness.resize(expected_len, Bn254Fr::zero());
+    Ok(witness)
+}
+
+/// Indices at which two witnesses differ, with both values as `0x` 32-byte LE hex.
+///
+/// When the lengths differ, every index past the end of the shorter witness is
+/// reported, with an empty string on the side that has no entry.
+pub fn witness_diff(a: &[Bn254Fr], b: &[Bn254Fr]) -> Vec<(usize, String, String)> {
+    let hex = |w: &[Bn254Fr], i: usize| {
+        w.get(i)
+            .map(|f| format!("0x{}", hex::encode(to_le_bytes_32(f))))
+            .unwrap_or_default()
+    };
+    (0..a.len().max(b.len()))
+        .filter(|&i| a.get(i) != b.get(i))
+        .map(|i| (i, hex(a, i), hex(b, i)))
+        .collect()
+}
+
+/// Pairs of `indices` whose witness values are equal, for slots that a circuit
+/// convention requires to be distinct (e.g. several nullifiers).
+///
+/// Each repeat is paired with the first index holding that value, in `indices`
+/// order: three equal slots `a, b, c` give `(a, b)` and `(a, c)`. Indices past the
+/// end of the witness, and an index listed twice, are ignored.
+pub fn find_duplicate_fields(witness: &[Bn254Fr], indices: &[usize]) -> Vec<(usize, usize)> {
+    let mut first_seen: HashMap<Bn254Fr, usize> = HashMap::new();
+    let mut duplicates = Vec::new();
+    for &index in indices {
+        let Some(value) = witness.get(index) else {
+            continue;
+        };
+        match first_seen.entry(*value) {
+            Entry::Occupied(first) if *first.get() != index => {
+                duplicates.push((*first.get(), index))
+            }
+            Entry::Occupied(_) => {}
+            Entry::Vacant(slot) => {
+                slot.insert(index);
+            }
+        }
+    }
+    duplicates
+}
+
+/// Check that witness indices `1..=num_public` hold the given public signals.
+///
+/// `signals` are `0x` hex-LE strings, as returned alongside proofs. Run this before
+/// proving to catch a witness that would prove different public inputs than the ones
+/// about to be published. The first mismatching index is reported.
+pub fn check_witness_matches_signals(
+    witness: &[Bn254Fr],
+    signals: &[String],
+    num_public: usize,
+) -> Result<(), String> {
+    if signals.len() != num_public {
+        return Err(format!(
+            "expected {num_public} public signals, got {}",
+            signals.len()
+        ));
+    }
+    if witness.len() <= num_public {
+        return Err(format!(
+            "witness has {} elements, too short for {num_public} public signals",
+            witness.len()
+        ));
+    }
+    for (i, signal) in signals.iter().enumerate() {
+        let expected = from_hex_le(signal).map_err(|e| format!("public signal {i}: {e}"))?;
+        let index = i + 1;
+        if witness[index] != expected {
+            return Err(format!(
+                "witness index {index} is 0x{}, public signal {i} is {signal}",
+                hex::encode(to_le_bytes_32(&witness[index]))
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Lazily decode whitespace- or comma-separated hex-LE values (`0x` optional) from
+/// `reader`, one field element at a time.
+///
+/// Only the token being decoded is buffered, so no intermediate `Vec<String>` is built.
+/// A token longer than [`MAX_HEX_TOKEN_LEN`] or a non-ASCII byte stops the stream
+/// with an error, which bounds that buffer. Read errors are yielded once and end the
+/// iteration.
+pub fn hex_fields_from_reader<R: Read>(reader: R) -> impl Iterator<Item = Result<Bn254Fr, String>> {
+    HexFields {
+        bytes: BufReader::new(reader).bytes(),
+        token: String::new(),
+        done: false,
+    }
+}
+
+/// Longest token [`hex_fields_from_reader`] buffers: `0x` plus 64 hex digits.
+pub const MAX_HEX_TOKEN_LEN: usize = 66;
+
+struct HexFields<R: Read> {
+    bytes: Bytes<BufReader<R>>,
+    token: String,
+    done: bool,
+}
+
+impl<R: Read> Iterator for HexFields<R> {
+    type Item = Result<Bn254Fr, String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            match self.bytes.next() {
+                Some(Ok(b)) if b.is_ascii_whitespace() || b == b',' => {
+                    if !self.token.is_empty() {
+                        return Some(from_hex_le(&std::mem::take(&mut self.token)));
+                    }
+                }
+                Some(Ok(b)) if !b.is_ascii() => {
+                    self.done = true;
+                    self.token.clear();
+                    return Some(Err(format!("Non-ASCII byte 0x{b:02x} in hex stream")));
+                }
+                Some(Ok(_)) if self.token.len() == MAX_HEX_TOKEN_LEN => {
+                    self.done = true;
+                    self.token.clear();
+                    return Some(Err(format!(
+                        "Hex token exceeds {MAX_HEX_TOKEN_LEN} characters"
+                    )));
+                }
+                Some(Ok(b)) => self.token.push(b as char),
+                Some(Err(e)) => {
+                    self.done = true;
+                    self.token.clear();
+                    return Some(Err(format!("Failed to read hex stream: {e}")));
+                }
+                None => self.done = true,
+            }
+        }
+        (!self.token.is_empty()).then(|| from_hex_le(&std::mem::take(&mut self.token)))
+    }
+}
+
+/// Read a witness from a JSON stream, converting each element as it is read.
+///
+/// Accepts the same shapes as the CLI: a bare array of hex-LE strings, or an object
+/// whose `"witness"` key holds that array (other keys are skipped). Only the field
+/// vector is kept in memory, never the JSON text, which lowers peak memory for huge
+/// circuits.
+#[cfg(feature = "streaming")]
+pub fn read_witness_streaming<R: std::io::Read>(reader: R) -> Result<Vec<Bn254Fr>, ProofError> {
+    use struson::reader::{JsonReader, JsonStreamReader, ValueType};
+
+    let parse_err = |e: struson::reader::ReaderError| ProofError::WitnessJsonParse(e.to_string());
+    let mut json = JsonStreamReader::new(reader);
+
+    let mut witness = None;
+    if json.peek().map_err(parse_err)? == ValueType::Object {
+        json.begin_object().map_err(parse_err)?;
+        while json.has_next().map_err(parse_err)? {
+            if json.next_name().map_err(parse_err)? == "witness" {
+                witness = Some(read_hex_array(&mut json)?);
+            } else {
+                json.skip_value().map_err(parse_err)?;
+            }
+        }
+        json.end_object().map_err(parse_err)?;
+    } else {
+        witness = Some(read_hex_array(&mut json)?);
+    }
+    json.consume_trailing_whitespace().map_err(parse_err)?;
+
+    witness.ok_or_else(|| ProofError::WitnessJsonParse("missing \"witness\" array".into()))
+}
+
+#[cfg(feature = "streaming")]
+fn read_hex_array<J: struson::reader::JsonReader>(
+    json: &mut J,
+) -> Result<Vec<Bn254Fr>, ProofError> {
+    let parse_err = |e: struson::reader::ReaderError| ProofError::WitnessJsonParse(e.to_string());
+    let mut witness = Vec::new();
+    json.begin_array().map_err(parse_err)?;
+    while json.has_next().map_err(parse_err)? {
+        let hex = json.next_str().map_err(parse_err)?;
+        witness.push(from_hex_le(hex).map_err(ProofError::WitnessConversion)?);
+    }
+    json.end_array().map_err(parse_err)?;
+    Ok(witness)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decimal_witness_accepts_numbers_and_strings() {
+        // r - 1, far beyond f64 precision, as a bare JSON number.
+        let json = r#"[1, "5", 12345,
+            21888242871839275222246405745257275088548364400416034343698204186575808495616]"#;
+        let witness = parse_decimal_witness_json(json).unwrap();
+        assert_eq!(
+            witness,
+            vec![
+                Bn254Fr::from(1u64),
+                Bn254Fr::from(5u64),
+                Bn254Fr::from(12345u64),
+                -Bn254Fr::from(1u64),
+            ]
+        );
+
+        for bad in ["[1, 2.5]", "[1, -3]", "[1, 1e3]", "[1, null]"] {
+            let err = parse_decimal_witness_json(bad).unwrap_err().to_string();
+            assert!(
+                err.contains("witness[1]: expected a decimal"),
+                "{bad}: {err}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_find_duplicate_fields() {
+        let witness = [1u64, 7, 9, 7, 4, 7].map(Bn254Fr::from);
+        assert_eq!(find_duplicate_fields(&witness, &[1, 2, 3]), vec![(1, 3)]);
+        assert_eq!(
+            find_duplicate_fields(&witness, &[5, 1, 3, 2]),
+            vec![(5, 1), (5, 3)]
+        );
+        assert!(find_duplicate_fields(&witness, &[1, 2, 4, 1, 99]).is_empty());
+    }
+
+    #[test]
+    fn test_witness_diff_reports_single_index() {
+        let a = [1u64, 33, 3, 11].map(Bn254Fr::from).to_vec();
+        let mut b = a.clone();
+        b[2] = Bn254Fr::from(4u64);
+        assert_eq!(
+            witness_diff(&a, &b),
+            vec![(
+                2,
+                format!("0x03{}", "00".repeat(31)),
+                format!("0x04{}", "00".repeat(31))
+            )]
+        );
+        assert!(witness_diff(&a, &a).is_empty());
+    }
+
+    #[test]
+    fn test_witness_diff_reports_length_mismatch() {
+        let a = [1u64, 2].map(Bn254Fr::from).to_vec();
+        let diff = witness_diff(&a, &a[..1]);
+        assert_eq!(
+            diff,
+            vec![(1, format!("0x02{}", "00".repeat(31)), String::new())]
+        );
+    }
+
+    #[test]
+    fn test_check_witness_matches_signals_reports_index() {
+        let witness = [1u64, 33, 3, 11].map(Bn254Fr::from).to_vec();
+        let signal = |v: u8| format!("0x{v:02x}{}", "00".repeat(31));
+        assert!(check_witness_matches_signals(&witness, &[signal(33), signal(3)], 2).is_ok());
+
+        let err = check_witness_matches_signals(&witness, &[signal(33), signal(4)], 2).unwrap_err();
+        assert!(err.contains("witness index 2"), "{err}");
+        assert!(check_witness_matches_signals(&witness, &[signal(33)], 2).is_err());
+        assert!(check_witness_matches_signals(&witness[..2], &[signal(33), signal(3)], 2).is_err());
+    }
+
+    #[test]
+    fn test_canonicalize_witness_json_is_stable() {
+        let a = r#"["0x1", "0X0A", [3, 0, 0, 0]]"#;
+        let b = format!(
+            "[\n  \"0x01{0}\",\n  \"0x0a{0}\",\n  \"0x03\"\n]",
+            "00".repeat(31)
+        );
+        let canonical = canonicalize_witness_json(a).unwrap();
+        assert_eq!(canonical, canonicalize_witness_json(&b).unwrap());
+        assert_eq!(
+            canonical,
+            format!(r#"["0x01{0}","0x0a{0}","0x03{0}"]"#, "00".repeat(31))
+        );
+        assert_eq!(canonicalize_witness_json(&canonical).unwrap(), canonical);
+        assert!(canonicalize_witness_json(r#"["0xzz"]"#).is_err());
+    }
+
+    #[test]
+    fn test_entry_errors_name_source_and_index() {
+        let mut entries = vec!["\"0x01\""; 17];
+        entries.push("\"0xzz\"");
+        let json = format!("[{}]", entries.join(","));
+
+        let err = parse_witness_entries_labeled(&json, Some("witness.json"))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("witness.json[17]: Failed to decode hex"),
+            "{err}"
+        );
+        let err = parse_witness_entries(&json).unwrap_err().to_string();
+        assert!(err.contains("witness[17]: "), "{err}");
+        let err = parse_witness_entries_labeled("[", Some("witness.json"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("witness.json: "), "{err}");
+    }
+
+    #[test]
+    fn test_sparse_witness_materializes_dense() {
+        let w = parse_sparse_witness(r#"{"len":8,"nonzero":{"0":"0x01","1":"0x0a","5":"0x14"}}"#)
+            .unwrap();
+        assert_eq!(w.len(), 8);
+        assert_eq!(w[0], Bn254Fr::from(1u64));
+        assert_eq!(w[1], Bn254Fr::from(10u64));
+        assert_eq!(w[5], Bn254Fr::from(20u64));
+        for i in [2, 3, 4, 6, 7] {
+            assert!(w[i].is_zero());
+        }
+    }
+
+    #[test]
+    fn test_sparse_witness_empty_nonzero() {
+        let w = parse_sparse_witness(r#"{"len":3,"nonzero":{}}"#).unwrap();
+        assert_eq!(w, vec![Bn254Fr::zero(); 3]);
+    }
+
+    #[test]
+    fn test_sparse_witness_index_out_of_range() {
+        let err = parse_sparse_witness(r#"{"len":3,"nonzero":{"3":"0x01"}}"#).unwrap_err();
+        assert!(err.to_string().contains("out of range"));
+    }
+
+    #[test]
+    fn test_sparse_witness_oversized_len_is_rejected() {
+        // Rejected before allocating, instead of aborting the process.
+        let err = parse_sparse_witness(r#"{"len":1000000000000,"nonzero":{}}"#).unwrap_err();
+        assert!(matches!(err, ProofError::WitnessJsonParse(_)));
+        assert!(err.to_string().contains("exceeds the maximum"), "{err}");
+
+        let json = r#"{"len":8,"nonzero":{"0":"0x01"}}"#;
+        assert!(parse_sparse_witness_with_max_len(json, 7).is_err());
+        assert_eq!(parse_sparse_witness_with_max_len(json, 8).unwrap().len(), 8);
+    }
+
+    #[test]
+    fn test_sparse_witness_invalid_index_key() {
+        let err = parse_sparse_witness(r#"{"len":3,"nonzero":{"x":"0x01"}}"#).unwrap_err();
+        assert!(matches!(err, ProofError::WitnessJsonParse(_)));
+    }
+
+    #[test]
+    fn test_sparse_witness_invalid_value() {
+        let err = parse_sparse_witness(r#"{"len":3,"nonzero":{"1":"0xZZ"}}"#).unwrap_err();
+        assert!(matches!(err, ProofError::WitnessConversion(_)));
+    }
+
+    #[test]
+    fn test_limb_entries_match_hex_entries() {
+        let limbs = parse_witness_entries("[[1,0,0,0],[123,456,0,0],[0,0,0,1]]").unwrap();
+        let mut second = vec![0u8; 32];
+        second[..8].copy_from_slice(&123u64.to_le_bytes());
+        second[8..16].copy_from_slice(&456u64.to_le_bytes());
+        let mut third = vec![0u8; 32];
+        third[24] = 1;
+        let hex = parse_witness_entries(&format!(
+            r#"["0x01","0x{}","0x{}"]"#,
+            hex::encode(second),
+            hex::encode(third)
+        ))
+        .unwrap();
+        assert_eq!(limbs, hex);
+
+        let mixed = parse_witness_entries(r#"["0x01",[123,456,0,0]]"#).unwrap();
+        assert_eq!(mixed[1], hex[1]);
+    }
+
+    #[test]
+    fn test_limb_entry_errors() {
+        assert!(matches!(
+            parse_witness_entries("[[1,2,3]]"),
+            Err(ProofError::WitnessJsonParse(_))
+        ));
+        let over = format!("[{:?}]", [u64::MAX; 4]);
+        assert!(matches!(
+            parse_witness_entries(&over),
+            Err(ProofError::WitnessConversion(_))
+        ));
+    }
+
+    #[test]
+    fn test_limb_entry_into_hex() {
+        let hex = WitnessEntry::Limbs([10, 0, 0, 0]).into_hex().unwrap();
+        assert_eq!(hex, format!("0x0a{}", "00".repeat(31)));
+        let unchanged = WitnessEntry::Hex("0x0A".into()).into_hex().unwrap();
+        assert_eq!(unchanged, "0x0A");
+    }
+
+    #[test]
+    fn test_hex_fields_from_reader() {
+        let stream = "0x01\n0x0a, 0X0B\n\n  ff\n0x01000000";
+        let fields: Vec<Bn254Fr> = hex_fields_from_reader(stream.as_bytes())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(fields, [1u64, 10, 11, 255, 1].map(Bn254Fr::from).to_vec());
+    }
+
+    #[test]
+    fn test_hex_fields_from_reader_reports_bad_token() {
+        let results: Vec<_> = hex_fields_from_reader("0x01 0xZZ 0x02".as_bytes()).collect();
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+        assert_eq!(results[2], Ok(Bn254Fr::from(2u64)));
+        assert_eq!(hex_fields_from_reader(&b""[..]).count(), 0);
+    }
+
+    #[test]
+    fn test_hex_fields_from_reader_bounds_tokens() {
+        let max = format!("0x{}", "0".repeat(64));
+        assert_eq!(max.len(), MAX_HEX_TOKEN_LEN);
+        let fields: Vec<_> = hex_fields_from_reader(max.as_bytes()).collect();
+        assert_eq!(fields, [Ok(Bn254Fr::from(0u64))]);
+
+        // An endless token stops at the limit instead of growing.
+        let endless = std::io::repeat(b'0');
+        let results: Vec<_> = hex_fields_from_reader(endless).collect();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].as_ref().unwrap_err().contains("exceeds 66"));
+
+        let results: Vec<_> = hex_fields_from_reader("0x01 0x\u{e9}0".as_bytes()).collect();
+        assert_eq!(results.len(), 2);
+        assert!(results[1]
+            .as_ref()
+            .unwrap_err()
+            .contains("Non-ASCII byte 0xc3"));
+    }
+
+    #[test]
+    fn test_fit_witness_pads_short() {
+        let w = vec![Bn254Fr::from(1u64), Bn254Fr::from(2u64)];
+        let fitted = fit_witness_to_key(w, 4, true).unwrap();
+        assert_eq!(fitted.len(), 4);
+        assert_eq!(fitted[1], Bn254Fr::from(2u64));
+        assert!(fitted[2..].iter().all(|f| f.is_zero()));
+    }
+
+    #[test]
+    fn test_fit_witness_short_without_pad_errors() {
+        let err = fit_witness_to_key(vec![Bn254Fr::from(1u64)], 4, false).unwrap_err();
+        assert_eq!(err, "witness has 1 elements, key expects 4");
+    }
+
+    #[test]
+    fn test_fit_witness_exact_is_unchanged() {
+        let w: Vec<Bn254Fr> = (1u64..=4).map(Bn254Fr::from).collect();
+        assert_eq!(fit_witness_to_key(w.clone(), 4, false).unwrap(), w);
+    }
+
+    #[test]
+    fn test_fit_witness_over_length_errors() {
+        let w = vec![Bn254Fr::from(1u64); 5];
+        assert!(fit_witness_to_key(w, 4, true).is_err());
+    }
+
+    #[cfg(feature = "streaming")]
+    #[test]
+    fn test_streaming_matches_in_memory_parse() {
+        use std::fmt::Write;
+
+        #[derive(serde::Deserialize)]
+        struct WitnessInput {
+            witness: Vec<WitnessEntry>,
+        }
+
+        let n = 20_000u64;
+        let mut json = String::from(r#"{"num_public_signals": 5, "witness": ["#);
+        for i in 0..n {
+            let sep = if i == 0 { "" } else { ", " };
+            let hex = hex::encode(to_le_bytes_32(&Bn254Fr::from(i * 7)));
+            write!(json, r#"{sep}"0x{hex}""#).unwrap();
+        }
+        json.push_str("]}");
+
+        // What the CLI does with the whole text in memory.
+        let input: WitnessInput = serde_json::from_str(&json).unwrap();
+        let in_memory: Vec<Bn254Fr> = input
+            .witness
+            .iter()
+            .map(|entry| entry.to_field().unwrap())
+            .collect();
+
+        let reader = std::io::BufReader::with_capacity(64, json.as_bytes());
+        let streamed = read_witness_streaming(reader).unwrap();
+        assert_eq!(streamed.len(), n as usize);
+        assert_eq!(streamed, in_memory);
+
+        let array = &json[json.find('[').unwrap()..json.len() - 1];
+        assert_eq!(read_witness_streaming(array.as_bytes()).unwrap(), in_memory);
+        assert_eq!(parse_witness_entries(array).unwrap(), in_memory);
+    }
+
+    #[cfg(feature = "streaming")]
+    #[test]
+    fn test_streaming_bare_array_and_errors() {
+        let w = read_witness_streaming(r#"["0x01", "0x0a"]"#.as_bytes()).unwrap();
+        assert_eq!(w, vec![Bn254Fr::from(1u64), Bn254Fr::from(10u64)]);
+
+        assert!(matches!(
+            read_witness_streaming(r#"{"num_public_signals": 1}"#.as_bytes()),
+            Err(ProofError::WitnessJsonParse(_))
+        ));
+        assert!(matches!(
+            read_witness_streaming(r#"["0xZZ"]"#.as_bytes()),
+            Err(ProofError::WitnessConversion(_))
+        ));
+        assert!(read_witness_streaming(r#"["0x01""#.as_bytes()).is_err());
+    }
+}
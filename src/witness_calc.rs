@@ -0,0 +1,256 @@
+use ark_bn254::Fr as Bn254Fr;
+use ark_ff::PrimeField;
+use num_bigint::{BigInt, BigUint, Sign};
+use wasmi::{Caller, Engine, Error, Instance, Linker, Module, Store, TypedFunc};
+
+use crate::prover::ProofGenerator;
+use crate::result::ProofResult;
+
+/// Circom runtime error codes passed to `runtime.exceptionHandler`.
+fn exception_message(code: i32) -> String {
+    let reason = match code {
+        1 => "signal not found",
+        2 => "too many values set for an input signal",
+        3 => "signal already set",
+        4 => "assert failed",
+        5 => "not enough memory",
+        6 => "input signal array access exceeds the size",
+        _ => "unknown error",
+    };
+    format!("circom runtime exception {code}: {reason}")
+}
+
+/// FNV-1a 64-bit hash circom uses to address input signals by name.
+fn fnv1a(name: &str) -> u64 {
+    name.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+/// Flatten a JSON input value (number, decimal string, or nested array) into field
+/// integers, reduced into `[0, prime)` like snarkjs does.
+fn flatten_input(
+    name: &str,
+    value: &serde_json::Value,
+    prime: &BigInt,
+    out: &mut Vec<BigUint>,
+) -> Result<(), String> {
+    let integer = match value {
+        serde_json::Value::Array(items) => {
+            return items
+                .iter()
+                .try_for_each(|item| flatten_input(name, item, prime, out));
+        }
+        serde_json::Value::Number(n) if n.is_i64() || n.is_u64() => n.to_string(),
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Bool(b) => u8::from(*b).to_string(),
+        other => return Err(format!("input {name}: unsupported value {other}")),
+    };
+    let integer: BigInt = integer
+        .parse()
+        .map_err(|_| format!("input {name}: not an integer: {integer}"))?;
+    let reduced = ((integer % prime) + prime) % prime;
+    out.push(reduced.to_biguint().expect("reduced value is non-negative"));
+    Ok(())
+}
+
+struct Calculator {
+    store: Store<()>,
+    instance: Instance,
+    n32: u32,
+}
+
+impl Calculator {
+    fn new(circuit_wasm: &[u8]) -> Result<Self, String> {
+        let engine = Engine::default();
+        let module =
+            Module::new(&engine, circuit_wasm).map_err(|e| format!("Invalid circuit wasm: {e}"))?;
+        let mut store = Store::new(&engine, ());
+        let mut linker = <Linker<()>>::new(&engine);
+        linker
+            .func_wrap(
+                "runtime",
+                "exceptionHandler",
+                |_: Caller<'_, ()>, code: i32| -> Result<(), Error> {
+                    Err(Error::new(exception_message(code)))
+                },
+            )
+            .and_then(|l| l.func_wrap("runtime", "printErrorMessage", || {}))
+            .and_then(|l| l.func_wrap("runtime", "writeBufferMessage", || {}))
+            .and_then(|l| l.func_wrap("runtime", "showSharedRWMemory", || {}))
+            .map_err(|e| e.to_string())?;
+        let instance = linker
+            .instantiate_and_start(&mut store, &module)
+            .map_err(|e| format!("Failed to instantiate circuit wasm: {e}"))?;
+        let mut calculator = Self {
+            store,
+            instance,
+            n32: 0,
+        };
+        calculator.n32 = calculator
+            .func::<(), u32>("getFieldNumLen32")?
+            .call(&mut calculator.store, ())
+            .map_err(|e| e.to_string())?;
+        Ok(calculator)
+    }
+
+    fn func<P: wasmi::WasmParams, R: wasmi::WasmResults>(
+        &self,
+        name: &str,
+    ) -> Result<TypedFunc<P, R>, String> {
+        self.instance
+            .get_typed_func::<P, R>(&self.store, name)
+            .map_err(|e| format!("Circuit wasm export {name}: {e}"))
+    }
+
+    /// Read the field element in the shared buffer, stored as little-endian u32 limbs.
+    fn read_shared(&mut self) -> Result<BigUint, String> {
+        let read = self.func::<u32, u32>("readSharedRWMemory")?;
+        let limbs = (0..self.n32)
+            .map(|j| read.call(&mut self.store, j))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+        Ok(BigUint::from_slice(&limbs))
+    }
+
+    fn write_shared(&mut self, value: &BigUint) -> Result<(), String> {
+        let write = self.func::<(u32, u32), ()>("writeSharedRWMemory")?;
+        let mut limbs = value.to_u32_digits();
+        limbs.resize(self.n32 as usize, 0);
+        for (j, limb) in limbs.into_iter().enumerate() {
+            write
+                .call(&mut self.store, (j as u32, limb))
+                .map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+
+    fn call<P: wasmi::WasmParams, R: wasmi::WasmResults>(
+        &mut self,
+        name: &str,
+        params: P,
+    ) -> Result<R, String> {
+        self.func::<P, R>(name)?
+            .call(&mut self.store, params)
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Run a circom-compiled circuit `.wasm` (circom 2) on snarkjs-style JSON inputs and
+/// return the full witness.
+///
+/// Inputs map each input signal name to a number, a decimal string, or a (nested)
+/// array for array signals, exactly as `snarkjs wtns calculate` takes them. Every
+/// input signal must be provided, and circuit assertions are enforced.
+pub fn calculate_witness(circuit_wasm: &[u8], inputs_json: &str) -> Result<Vec<Bn254Fr>, String> {
+    let inputs: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_str(inputs_json).map_err(|e| format!("Invalid inputs JSON: {e}"))?;
+    let mut calc = Calculator::new(circuit_wasm)?;
+
+    calc.call::<(), ()>("getRawPrime", ())?;
+    let prime = calc.read_shared()?;
+    if prime != Bn254Fr::MODULUS.into() {
+        return Err(format!(
+            "Circuit is compiled for prime {prime}, expected BN254"
+        ));
+    }
+    let prime = BigInt::from_biguint(Sign::Plus, prime);
+
+    calc.call::<u32, ()>("init", 1)?;
+    let mut set = 0;
+    for (name, value) in &inputs {
+        let hash = fnv1a(name);
+        let (msb, lsb) = ((hash >> 32) as u32, hash as u32);
+        let size = calc.call::<(u32, u32), i32>("getInputSignalSize", (msb, lsb))?;
+        // circom reports unknown names as size 0 (older builds as negative).
+        if size <= 0 {
+            return Err(format!("Circuit has no input signal {name}"));
+        }
+        let mut values = Vec::new();
+        flatten_input(name, value, &prime, &mut values)?;
+        if values.len() != size as usize {
+            return Err(format!(
+                "Input {name} has {} values, circuit expects {size}",
+                values.len()
+            ));
+        }
+        for (i, v) in values.iter().enumerate() {
+            calc.write_shared(v)?;
+            calc.call::<(u32, u32, u32), ()>("setInputSignal", (msb, lsb, i as u32))?;
+        }
+        set += values.len();
+    }
+    let expected = calc.call::<(), u32>("getInputSize", ())? as usize;
+    if set < expected {
+        return Err(format!(
+            "Only {set} of {expected} input values were provided"
+        ));
+    }
+
+    let size = calc.call::<(), u32>("getWitnessSize", ())?;
+    (0..size)
+        .map(|i| {
+            calc.call::<u32, ()>("getWitness", i)?;
+            Ok(Bn254Fr::from(calc.read_shared()?))
+        })
+        .collect()
+}
+
+/// Compute the witness for `inputs_json` with [`calculate_witness`], then prove.
+///
+/// `pk_bytes` is a snarkjs `.zkey` (detected by its magic) or an arkworks compressed
+/// proving key. Proofs from a `.zkey` verify against its snarkjs verification key.
+pub fn prove_from_inputs(
+    circuit_wasm: &[u8],
+    inputs_json: &str,
+    pk_bytes: &[u8],
+) -> Result<ProofResult, String> {
+    let witness = calculate_witness(circuit_wasm, inputs_json)?;
+    let generator = if pk_bytes.starts_with(b"zkey") {
+        ProofGenerator::from_zkey_bytes(pk_bytes)
+    } else {
+        ProofGenerator::from_key_bytes(pk_bytes)
+    }
+    .map_err(|e| e.to_string())?;
+    generator
+        .prove_with_public_signals(witness)
+        .map(ProofResult::from)
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WASM: &[u8] = include_bytes!("../tests/fixtures/multiplier.wasm");
+    const ZKEY: &[u8] = include_bytes!("../tests/fixtures/multiplier.zkey");
+
+    #[test]
+    fn test_calculate_witness_matches_fixture() {
+        let witness = calculate_witness(WASM, r#"{"a": 3, "b": "11"}"#).unwrap();
+        assert_eq!(witness, [1u64, 33, 3, 11].map(Bn254Fr::from));
+    }
+
+    #[test]
+    fn test_prove_from_inputs_verifies() {
+        let result = prove_from_inputs(WASM, r#"{"a": 3, "b": 11}"#, ZKEY).unwrap();
+        let vk = crate::vk_from_snarkjs_json(include_str!("../tests/fixtures/multiplier_vk.json"))
+            .unwrap();
+        assert_eq!(result.public_signals, [format!("0x21{}", "00".repeat(31))]);
+        assert!(crate::verify(&result.proof, &vk, &result.public_signals).unwrap());
+    }
+
+    #[test]
+    fn test_calculate_witness_rejects_bad_inputs() {
+        assert!(calculate_witness(WASM, r#"{"a": 3}"#)
+            .unwrap_err()
+            .contains("1 of 2 input values"));
+        assert!(calculate_witness(WASM, r#"{"a": 3, "b": 11, "z": 1}"#)
+            .unwrap_err()
+            .contains("no input signal z"));
+        assert!(calculate_witness(WASM, r#"{"a": [3, 4], "b": 11}"#)
+            .unwrap_err()
+            .contains("circuit expects 1"));
+        assert!(calculate_witness(b"not wasm", "{}").is_err());
+    }
+}
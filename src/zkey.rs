@@ -0,0 +1,534 @@
+//! snarkjs `.zkey` proving key import.
+//!
+//! A `.zkey` is `"zkey"`, a `u32` version, a `u32` section count, then sections of
+//! `u32` id + `u64` length + payload (all little-endian):
+//!
+//! | id | section |
+//! |----|---------|
+//! | 1  | header: prover type (`1` = Groth16) |
+//! | 2  | Groth16 header: `n8q`, `q`, `n8r`, `r`, `nVars`, `nPublic`, `domainSize`, α₁, β₁, β₂, γ₂, δ₁, δ₂ |
+//! | 3  | IC (`nPublic + 1` G1 points) |
+//! | 4  | A/B matrix coefficients |
+//! | 5–9 | A, B₁ (G1), B₂ (G2), C, H point queries |
+//!
+//! Base-field coordinates are stored in Montgomery form and matrix coefficients in
+//! Montgomery form twice over (`x·R²`). snarkjs also uses a different QAP witness
+//! map than arkworks, so imported keys prove through [`CircomReduction`].
+//!
+//! [`proving_key_to_zkey`] writes the same layout back out.
+
+use std::collections::HashMap;
+
+use ark_bn254::{Bn254, Fq, Fq2, Fr as Bn254Fr, G1Affine, G2Affine};
+use ark_ec::short_weierstrass::{Affine, SWCurveConfig};
+use ark_ec::AffineRepr;
+use ark_ff::{BigInt, PrimeField, Zero};
+use ark_groth16::r1cs_to_qap::{evaluate_constraint, LibsnarkReduction, R1CSToQAP};
+use ark_groth16::{ProvingKey, VerifyingKey};
+use ark_poly::EvaluationDomain;
+use ark_relations::r1cs::{ConstraintMatrices, ConstraintSystemRef, SynthesisError};
+
+const ZKEY_MAGIC: &[u8; 4] = b"zkey";
+const ZKEY_VERSION: u32 = 1;
+const GROTH16_PROVER_TYPE: u32 = 1;
+
+/// Parse a snarkjs `.zkey` into an arkworks proving key and the A/B constraint
+/// matrices its witness map needs (`c` is left empty; snarkjs derives it as `A·B`).
+///
+/// Every point is checked to be on the curve and in the prime-order subgroup.
+pub fn read_zkey(bytes: &[u8]) -> Result<(ProvingKey<Bn254>, ConstraintMatrices<Bn254Fr>), String> {
+    let sections = Sections::parse(bytes)?;
+
+    let mut header = sections.get(1)?;
+    let prover_type = header.u32()?;
+    if prover_type != GROTH16_PROVER_TYPE {
+        return Err(format!(
+            "Unsupported zkey prover type {prover_type} (only Groth16 is supported)"
+        ));
+    }
+
+    let mut groth = sections.get(2)?;
+    check_modulus(&mut groth, "q", &Fq::MODULUS)?;
+    check_modulus(&mut groth, "r", &Bn254Fr::MODULUS)?;
+    let n_vars = groth.u32()? as usize;
+    let n_public = groth.u32()? as usize;
+    let domain_size = groth.u32()? as usize;
+    if n_public >= n_vars {
+        return Err(format!(
+            "zkey has {n_public} public inputs but {n_vars} variables"
+        ));
+    }
+    let alpha_g1 = groth.g1()?;
+    let beta_g1 = groth.g1()?;
+    let beta_g2 = groth.g2()?;
+    let gamma_g2 = groth.g2()?;
+    let delta_g1 = groth.g1()?;
+    let delta_g2 = groth.g2()?;
+
+    let pk = ProvingKey {
+        vk: VerifyingKey {
+            alpha_g1,
+            beta_g2,
+            gamma_g2,
+            delta_g2,
+            gamma_abc_g1: sections.get(3)?.g1_vec(n_public + 1)?,
+        },
+        beta_g1,
+        delta_g1,
+        a_query: sections.get(5)?.g1_vec(n_vars)?,
+        b_g1_query: sections.get(6)?.g1_vec(n_vars)?,
+        b_g2_query: sections.get(7)?.g2_vec(n_vars)?,
+        l_query: sections.get(8)?.g1_vec(n_vars - n_public - 1)?,
+        h_query: sections.get(9)?.g1_vec(domain_size)?,
+    };
+    let matrices = read_matrices(sections.get(4)?, n_vars, n_public, domain_size)?;
+    Ok((pk, matrices))
+}
+
+fn read_matrices(
+    mut coefs: Cursor<'_>,
+    n_vars: usize,
+    n_public: usize,
+    domain_size: usize,
+) -> Result<ConstraintMatrices<Bn254Fr>, String> {
+    let num_coefs = coefs.u32()?;
+    let mut rows = [vec![Vec::new(); domain_size], vec![Vec::new(); domain_size]];
+    let mut max_constraint = 0;
+    for _ in 0..num_coefs {
+        let matrix = coefs.u32()? as usize;
+        let constraint = coefs.u32()? as usize;
+        let signal = coefs.u32()? as usize;
+        let value = coefs.fr_double_montgomery()?;
+        if matrix > 1 || constraint >= domain_size || signal >= n_vars {
+            return Err(format!(
+                "zkey coefficient out of range (matrix {matrix}, constraint {constraint}, signal {signal})"
+            ));
+        }
+        max_constraint = max_constraint.max(constraint);
+        rows[matrix][constraint].push((value, signal));
+    }
+
+    // snarkjs appends one `A` row per instance variable (`1` and the public inputs);
+    // arkworks' witness map adds those itself.
+    let num_constraints = max_constraint
+        .checked_sub(n_public)
+        .ok_or("zkey coefficients are missing the public-input constraints")?;
+    let [mut a, mut b] = rows;
+    a.truncate(num_constraints);
+    b.truncate(num_constraints);
+    Ok(ConstraintMatrices {
+        num_instance_variables: n_public + 1,
+        num_witness_variables: n_vars - n_public - 1,
+        num_constraints,
+        a_num_non_zero: a.iter().map(Vec::len).sum(),
+        b_num_non_zero: b.iter().map(Vec::len).sum(),
+        c_num_non_zero: 0,
+        a,
+        b,
+        c: Vec::new(),
+    })
+}
+
+/// Write a proving key and its A/B constraint matrices as a snarkjs `.zkey`.
+///
+/// The inverse of [`read_zkey`]: re-importing the file yields the same key and
+/// matrices. A `.zkey` carries the circuit's coefficients, so the matrices are
+/// required alongside the key. The contributions section is written empty.
+///
+/// snarkjs provers expect the H query in snarkjs' own basis. Keys that came from
+/// [`read_zkey`] have it; keys from an arkworks setup do not, and snarkjs cannot
+/// produce valid proofs with them.
+pub fn proving_key_to_zkey(
+    pk: &ProvingKey<Bn254>,
+    matrices: &ConstraintMatrices<Bn254Fr>,
+    out_path: &str,
+) -> Result<(), String> {
+    let bytes = write_zkey(pk, matrices)?;
+    std::fs::write(out_path, bytes).map_err(|e| format!("Cannot write {out_path}: {e}"))
+}
+
+fn write_zkey(
+    pk: &ProvingKey<Bn254>,
+    matrices: &ConstraintMatrices<Bn254Fr>,
+) -> Result<Vec<u8>, String> {
+    let n_public = pk
+        .vk
+        .gamma_abc_g1
+        .len()
+        .checked_sub(1)
+        .ok_or("key has no IC points")?;
+    let n_vars = pk.a_query.len();
+    if matrices.num_instance_variables != n_public + 1
+        || matrices.num_instance_variables + matrices.num_witness_variables != n_vars
+    {
+        return Err(format!(
+            "matrices ({} instance + {} witness variables) do not match the key ({n_public} public inputs, {n_vars} variables)",
+            matrices.num_instance_variables, matrices.num_witness_variables
+        ));
+    }
+
+    let mut groth = Vec::new();
+    for modulus in [Fq::MODULUS, Bn254Fr::MODULUS] {
+        put_u32(&mut groth, 32);
+        put_bigint(&mut groth, &modulus);
+    }
+    for n in [n_vars, n_public, pk.h_query.len()] {
+        put_u32(&mut groth, n as u32);
+    }
+    put_g1(&mut groth, &pk.vk.alpha_g1);
+    put_g1(&mut groth, &pk.beta_g1);
+    put_g2(&mut groth, &pk.vk.beta_g2);
+    put_g2(&mut groth, &pk.vk.gamma_g2);
+    put_g1(&mut groth, &pk.delta_g1);
+    put_g2(&mut groth, &pk.vk.delta_g2);
+
+    // snarkjs appends an `A` row selecting each instance variable.
+    let input_rows =
+        (0..=n_public).map(|i| (0u32, matrices.num_constraints + i, i, Bn254Fr::from(1u64)));
+    let coefs: Vec<_> = [&matrices.a, &matrices.b]
+        .into_iter()
+        .enumerate()
+        .flat_map(|(m, rows)| {
+            rows.iter().enumerate().flat_map(move |(constraint, row)| {
+                row.iter()
+                    .map(move |&(value, signal)| (m as u32, constraint, signal, value))
+            })
+        })
+        .chain(input_rows)
+        .collect();
+    let mut coef_section = Vec::new();
+    put_u32(&mut coef_section, coefs.len() as u32);
+    for (matrix, constraint, signal, value) in coefs {
+        put_u32(&mut coef_section, matrix);
+        put_u32(&mut coef_section, constraint as u32);
+        put_u32(&mut coef_section, signal as u32);
+        // Stored as `x·R²`: the Montgomery form of `x·R`.
+        put_bigint(&mut coef_section, &Bn254Fr::from_bigint(value.0).unwrap().0);
+    }
+
+    let g1s = |points: &[G1Affine]| {
+        let mut out = Vec::new();
+        points.iter().for_each(|p| put_g1(&mut out, p));
+        out
+    };
+    let mut b2 = Vec::new();
+    pk.b_g2_query.iter().for_each(|p| put_g2(&mut b2, p));
+    // Contributions: empty circuit hash and no contributions.
+    let mut contributions = vec![0u8; 64];
+    put_u32(&mut contributions, 0);
+
+    // Same section order as `snarkjs zkey new`.
+    let sections = [
+        (1, GROTH16_PROVER_TYPE.to_le_bytes().to_vec()),
+        (2, groth),
+        (4, coef_section),
+        (3, g1s(&pk.vk.gamma_abc_g1)),
+        (9, g1s(&pk.h_query)),
+        (8, g1s(&pk.l_query)),
+        (5, g1s(&pk.a_query)),
+        (6, g1s(&pk.b_g1_query)),
+        (7, b2),
+        (10, contributions),
+    ];
+    let mut out = ZKEY_MAGIC.to_vec();
+    put_u32(&mut out, ZKEY_VERSION);
+    put_u32(&mut out, sections.len() as u32);
+    for (id, payload) in sections {
+        put_u32(&mut out, id);
+        out.extend((payload.len() as u64).to_le_bytes());
+        out.extend(payload);
+    }
+    Ok(out)
+}
+
+fn put_u32(out: &mut Vec<u8>, v: u32) {
+    out.extend(v.to_le_bytes());
+}
+
+fn put_bigint(out: &mut Vec<u8>, v: &BigInt<4>) {
+    v.0.iter().for_each(|limb| out.extend(limb.to_le_bytes()));
+}
+
+/// Coordinates in Montgomery form; the point at infinity is all zeros.
+fn put_g1(out: &mut Vec<u8>, p: &G1Affine) {
+    let (x, y) = p.xy().unwrap_or_default();
+    put_bigint(out, &x.0);
+    put_bigint(out, &y.0);
+}
+
+fn put_g2(out: &mut Vec<u8>, p: &G2Affine) {
+    let (x, y) = p.xy().unwrap_or_default();
+    for c in [x.c0, x.c1, y.c0, y.c1] {
+        put_bigint(out, &c.0);
+    }
+}
+
+fn check_modulus(cursor: &mut Cursor<'_>, name: &str, expected: &BigInt<4>) -> Result<(), String> {
+    let n8 = cursor.u32()?;
+    if n8 != 32 || cursor.bigint()? != *expected {
+        return Err(format!("zkey {name} is not the BN254 modulus"));
+    }
+    Ok(())
+}
+
+/// The snarkjs QAP witness map.
+///
+/// arkworks computes `H` as `(AB - C) / Z` over the evaluation domain; snarkjs keys
+/// instead carry Lagrange bases over a domain twice as large, so `H` is the odd-index
+/// evaluations of `AB - C` on the coset. Ported from `ark-circom` (MIT/Apache-2.0).
+pub(crate) struct CircomReduction;
+
+impl R1CSToQAP for CircomReduction {
+    #[allow(clippy::type_complexity)]
+    fn instance_map_with_evaluation<F: PrimeField, D: EvaluationDomain<F>>(
+        cs: ConstraintSystemRef<F>,
+        t: &F,
+    ) -> Result<(Vec<F>, Vec<F>, Vec<F>, F, usize, usize), SynthesisError> {
+        LibsnarkReduction::instance_map_with_evaluation::<F, D>(cs, t)
+    }
+
+    fn witness_map_from_matrices<F: PrimeField, D: EvaluationDomain<F>>(
+        matrices: &ConstraintMatrices<F>,
+        num_inputs: usize,
+        num_constraints: usize,
+        full_assignment: &[F],
+    ) -> Result<Vec<F>, SynthesisError> {
+        let domain =
+            D::new(num_constraints + num_inputs).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let domain_size = domain.size();
+
+        let mut a = vec![F::zero(); domain_size];
+        let mut b = vec![F::zero(); domain_size];
+        for (i, (at_i, bt_i)) in matrices.a.iter().zip(&matrices.b).enumerate() {
+            a[i] = evaluate_constraint(at_i, full_assignment);
+            b[i] = evaluate_constraint(bt_i, full_assignment);
+        }
+        a[num_constraints..num_constraints + num_inputs]
+            .clone_from_slice(&full_assignment[..num_inputs]);
+
+        let mut c = vec![F::zero(); domain_size];
+        for i in 0..num_constraints {
+            c[i] = a[i] * b[i];
+        }
+
+        let coset_generator = D::new(2 * domain_size)
+            .ok_or(SynthesisError::PolynomialDegreeTooLarge)?
+            .element(1);
+        for poly in [&mut a, &mut b, &mut c] {
+            domain.ifft_in_place(poly);
+            D::distribute_powers_and_mul_by_const(poly, coset_generator, F::one());
+            domain.fft_in_place(poly);
+        }
+
+        let mut ab = domain.mul_polynomials_in_evaluation_domain(&a, &b);
+        for (ab_i, c_i) in ab.iter_mut().zip(c) {
+            *ab_i -= c_i;
+        }
+        Ok(ab)
+    }
+
+    fn h_query_scalars<F: PrimeField, D: EvaluationDomain<F>>(
+        max_power: usize,
+        t: F,
+        _: F,
+        delta_inverse: F,
+    ) -> Result<Vec<F>, SynthesisError> {
+        // H·Z has 2·domain - 1 powers; keep the odd Lagrange coefficients.
+        let mut scalars: Vec<F> = (0..2 * max_power + 1)
+            .map(|i| delta_inverse * t.pow([i as u64]))
+            .collect();
+        let domain = D::new(scalars.len()).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        domain.ifft_in_place(&mut scalars);
+        Ok(scalars.into_iter().skip(1).step_by(2).collect())
+    }
+}
+
+/// Section payloads by id; the first occurrence wins.
+struct Sections<'a>(HashMap<u32, &'a [u8]>);
+
+impl<'a> Sections<'a> {
+    fn parse(bytes: &'a [u8]) -> Result<Self, String> {
+        let mut cursor = Cursor(bytes);
+        if cursor.take(4)? != ZKEY_MAGIC {
+            return Err("Not a zkey file (bad magic)".into());
+        }
+        let _version = cursor.u32()?;
+        let num_sections = cursor.u32()?;
+        let mut sections = HashMap::new();
+        for _ in 0..num_sections {
+            let id = cursor.u32()?;
+            let len = usize::try_from(cursor.u64()?).map_err(|_| "zkey section too large")?;
+            let payload = cursor.take(len)?;
+            sections.entry(id).or_insert(payload);
+        }
+        Ok(Self(sections))
+    }
+
+    fn get(&self, id: u32) -> Result<Cursor<'a>, String> {
+        self.0
+            .get(&id)
+            .map(|payload| Cursor(payload))
+            .ok_or_else(|| format!("zkey is missing section {id}"))
+    }
+}
+
+struct Cursor<'a>(&'a [u8]);
+
+impl<'a> Cursor<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], String> {
+        if self.0.len() < n {
+            return Err("Truncated zkey".into());
+        }
+        let (head, rest) = self.0.split_at(n);
+        self.0 = rest;
+        Ok(head)
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, String> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn bigint(&mut self) -> Result<BigInt<4>, String> {
+        let bytes = self.take(32)?;
+        let mut limbs = [0u64; 4];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+            *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Ok(BigInt(limbs))
+    }
+
+    /// A base-field element stored in Montgomery form.
+    fn fq(&mut self) -> Result<Fq, String> {
+        let raw = self.bigint()?;
+        if raw >= Fq::MODULUS {
+            return Err("zkey coordinate is not reduced".into());
+        }
+        Ok(Fq::new_unchecked(raw))
+    }
+
+    /// A scalar stored as `x·R²`: strip one Montgomery factor, then read as Montgomery.
+    fn fr_double_montgomery(&mut self) -> Result<Bn254Fr, String> {
+        let raw = self.bigint()?;
+        if raw >= Bn254Fr::MODULUS {
+            return Err("zkey coefficient is not reduced".into());
+        }
+        Ok(Bn254Fr::new_unchecked(
+            Bn254Fr::new_unchecked(raw).into_bigint(),
+        ))
+    }
+
+    fn g1(&mut self) -> Result<G1Affine, String> {
+        let (x, y) = (self.fq()?, self.fq()?);
+        if x.is_zero() && y.is_zero() {
+            return Ok(G1Affine::zero());
+        }
+        checked_point(G1Affine::new_unchecked(x, y))
+    }
+
+    fn g2(&mut self) -> Result<G2Affine, String> {
+        let x = Fq2::new(self.fq()?, self.fq()?);
+        let y = Fq2::new(self.fq()?, self.fq()?);
+        if x.is_zero() && y.is_zero() {
+            return Ok(G2Affine::zero());
+        }
+        checked_point(G2Affine::new_unchecked(x, y))
+    }
+
+    fn g1_vec(&mut self, n: usize) -> Result<Vec<G1Affine>, String> {
+        (0..n).map(|_| self.g1()).collect()
+    }
+
+    fn g2_vec(&mut self, n: usize) -> Result<Vec<G2Affine>, String> {
+        (0..n).map(|_| self.g2()).collect()
+    }
+}
+
+fn checked_point<P: SWCurveConfig>(point: Affine<P>) -> Result<Affine<P>, String> {
+    if point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve() {
+        Ok(point)
+    } else {
+        Err("zkey point is not on the curve or not in the subgroup".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::from_decimal_str;
+
+    const FIXTURE: &[u8] = include_bytes!("../tests/fixtures/multiplier.zkey");
+
+    #[test]
+    fn test_reads_multiplier_zkey() {
+        let (pk, matrices) = read_zkey(FIXTURE).unwrap();
+        // `c <== a * b`: witness [1, c, a, b], one public output.
+        assert_eq!(pk.vk.gamma_abc_g1.len(), 2);
+        assert_eq!(pk.a_query.len(), 4);
+        assert_eq!(pk.l_query.len(), 2);
+        assert_eq!(matrices.num_instance_variables, 2);
+        assert_eq!(matrices.num_witness_variables, 2);
+        assert_eq!(matrices.num_constraints, 1);
+    }
+
+    #[test]
+    fn test_montgomery_decoding_matches_snarkjs_vk() {
+        let (pk, _) = read_zkey(FIXTURE).unwrap();
+        let vk_json: serde_json::Value =
+            serde_json::from_str(include_str!("../tests/fixtures/multiplier_vk.json")).unwrap();
+        let coord = |i: usize| from_decimal_str::<Fq>(vk_json["vk_alpha_1"][i].as_str().unwrap());
+        assert_eq!(pk.vk.alpha_g1.x, coord(0).unwrap());
+        assert_eq!(pk.vk.alpha_g1.y, coord(1).unwrap());
+    }
+
+    #[test]
+    fn test_rejects_malformed_zkeys() {
+        assert!(read_zkey(b"nope").unwrap_err().contains("magic"));
+        assert!(read_zkey(&FIXTURE[..FIXTURE.len() - 1])
+            .unwrap_err()
+            .contains("Truncated"));
+
+        // Corrupt α₁.y (Groth16 header: 12-byte file header, 12-byte section 1,
+        // 12-byte section 2 header, then n8q/q/n8r/r/nVars/nPublic/domainSize).
+        let mut corrupt = FIXTURE.to_vec();
+        let alpha_y = 12 + 16 + 12 + 4 + 32 + 4 + 32 + 12 + 32;
+        corrupt[alpha_y] ^= 1;
+        assert!(read_zkey(&corrupt)
+            .unwrap_err()
+            .contains("not on the curve"));
+    }
+
+    #[test]
+    fn test_zkey_export_round_trip() {
+        let (pk, matrices) = read_zkey(FIXTURE).unwrap();
+        let path = "/tmp/test_zkey_export_round_trip.zkey";
+        proving_key_to_zkey(&pk, &matrices, path).unwrap();
+        let exported = std::fs::read(path).unwrap();
+        let _ = std::fs::remove_file(path);
+
+        let (pk2, matrices2) = read_zkey(&exported).unwrap();
+        assert_eq!(pk2, pk);
+        assert_eq!(matrices2.a, matrices.a);
+        assert_eq!(matrices2.b, matrices.b);
+        assert_eq!(matrices2.num_constraints, matrices.num_constraints);
+        assert_eq!(write_zkey(&pk2, &matrices2).unwrap(), exported);
+
+        // Byte-identical to snarkjs' output up to the contributions section
+        // (64-byte circuit hash + `u32` count), which is written empty.
+        let body = FIXTURE.len() - 68;
+        assert_eq!(exported.len(), FIXTURE.len());
+        assert_eq!(exported[..body], FIXTURE[..body]);
+    }
+
+    #[test]
+    fn test_zkey_export_rejects_mismatched_matrices() {
+        let (pk, mut matrices) = read_zkey(FIXTURE).unwrap();
+        matrices.num_witness_variables += 1;
+        assert!(write_zkey(&pk, &matrices)
+            .unwrap_err()
+            .contains("do not match"));
+    }
+}
@@ -0,0 +1,133 @@
+mod common;
+
+use std::process::Command;
+
+use groth16_proofs::test_utils;
+
+#[test]
+fn split_by_circuit_writes_one_file_per_circuit() {
+    let dir = common::temp_dir("cli_batch_split");
+    // unshield exposes 5 public signals, disclosure 4; the witness adds the
+    // constant one and a single private value.
+    let (unshield_pk, unshield_vk) = common::setup_witness_layout(7, 5, 1);
+    let (disclosure_pk, disclosure_vk) = common::setup_witness_layout(6, 4, 2);
+    let unshield_pk_path = dir.join("unshield.ark");
+    let disclosure_pk_path = dir.join("disclosure.ark");
+    std::fs::write(&unshield_pk_path, common::pk_bytes(&unshield_pk)).unwrap();
+    std::fs::write(&disclosure_pk_path, common::pk_bytes(&disclosure_pk)).unwrap();
+
+    // Witness layout: [1, x_1..x_n, w].
+    let job = |circuit: &str, n: u64| {
+        let mut witness = vec![1u64];
+        witness.extend(10..10 + n);
+        witness.push(3);
+        let witness: Vec<String> = witness
+            .into_iter()
+            .map(|v| test_utils::to_hex_le(v.into()))
+            .collect();
+        serde_json::json!({ "circuit": circuit, "witness": witness }).to_string()
+    };
+    let jobs = [job("unshield", 5), job("disclosure", 4), job("unshield", 5)].join("\n");
+    let jobs_path = dir.join("jobs.jsonl");
+    std::fs::write(&jobs_path, jobs).unwrap();
+
+    let out_dir = dir.join("out");
+    let output = Command::new(env!("CARGO_BIN_EXE_generate-proof-from-witness"))
+        .arg("batch")
+        .arg(&jobs_path)
+        .arg("--key")
+        .arg(format!("unshield={}", unshield_pk_path.display()))
+        .arg("--key")
+        .arg(format!("disclosure={}", disclosure_pk_path.display()))
+        .arg("--split-by-circuit")
+        .arg(&out_dir)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(output.stdout.is_empty());
+
+    let mut files: Vec<String> = std::fs::read_dir(&out_dir)
+        .unwrap()
+        .map(|e| e.unwrap().file_name().into_string().unwrap())
+        .collect();
+    files.sort();
+    assert_eq!(files, ["disclosure.jsonl", "unshield.jsonl"]);
+
+    for (circuit, lines, n, vk) in [
+        ("unshield", 2, 5u64, &unshield_vk),
+        ("disclosure", 1, 4, &disclosure_vk),
+    ] {
+        let contents = std::fs::read_to_string(out_dir.join(format!("{circuit}.jsonl"))).unwrap();
+        let proofs: Vec<serde_json::Value> = contents
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(proofs.len(), lines);
+        let expected: Vec<String> = (10..10 + n)
+            .map(|v| test_utils::to_hex_le(v.into()))
+            .collect();
+        for proof in proofs {
+            assert_eq!(proof["circuit"], circuit);
+            let signals: Vec<String> =
+                serde_json::from_value(proof["public_signals"].clone()).unwrap();
+            assert_eq!(signals, expected);
+            let bytes =
+                hex::decode(proof["proof"].as_str().unwrap().trim_start_matches("0x")).unwrap();
+            assert!(groth16_proofs::verify(&bytes, vk, &signals).unwrap());
+        }
+    }
+}
+
+#[cfg(feature = "zstd")]
+#[test]
+fn compressed_batch_output_round_trips() {
+    let dir = common::temp_dir("cli_batch_compress");
+    let (pk, vk) = common::setup_witness_layout(6, 4, 3);
+    let pk_path = dir.join("disclosure.ark");
+    std::fs::write(&pk_path, common::pk_bytes(&pk)).unwrap();
+
+    let jobs: Vec<String> = [[1u64, 10, 11, 12, 13, 99], [1, 20, 21, 22, 23, 98]]
+        .iter()
+        .map(|values| {
+            let witness: Vec<String> = values
+                .iter()
+                .map(|&v| test_utils::to_hex_le(v.into()))
+                .collect();
+            serde_json::json!({ "circuit": "disclosure", "witness": witness }).to_string()
+        })
+        .collect();
+    let jobs_path = dir.join("jobs.jsonl");
+    std::fs::write(&jobs_path, jobs.join("\n")).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_generate-proof-from-witness"))
+        .arg("batch")
+        .arg(&jobs_path)
+        .arg("--key")
+        .arg(format!("disclosure={}", pk_path.display()))
+        .args(["--compress", "zstd"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let decoded = String::from_utf8(zstd::stream::decode_all(&output.stdout[..]).unwrap()).unwrap();
+    let lines: Vec<serde_json::Value> = decoded
+        .lines()
+        .map(|l| serde_json::from_str(l).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 2);
+    for (line, first) in lines.iter().zip([10u64, 20]) {
+        assert_eq!(line["circuit"], "disclosure");
+        let signals: Vec<String> = serde_json::from_value(line["public_signals"].clone()).unwrap();
+        assert_eq!(signals[0], test_utils::to_hex_le(first.into()));
+        let proof = hex::decode(line["proof"].as_str().unwrap().trim_start_matches("0x")).unwrap();
+        assert!(groth16_proofs::verify(&proof, &vk, &signals).unwrap());
+    }
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "config")]
+
+mod common;
+
+use std::process::Command;
+
+use groth16_proofs::test_utils;
+
+#[test]
+fn circuit_flag_proves_with_configured_key() {
+    let dir = common::temp_dir("cli_config");
+    std::fs::create_dir_all(dir.join("keys")).unwrap();
+    let (pk, _) = test_utils::setup(1);
+    std::fs::write(dir.join("keys/transfer_pk.ark"), common::pk_bytes(&pk)).unwrap();
+    std::fs::write(
+        dir.join("orbinum.toml"),
+        "[keys]\ntransfer = \"keys/transfer_pk.ark\"\n\n[limits]\nmax_witness_bytes = 4096\n",
+    )
+    .unwrap();
+
+    // `test_utils::TestCircuit` witness layout: [1, c, a, b] with c = a * b.
+    let witness: Vec<String> = [1u64, 15, 3, 5]
+        .into_iter()
+        .map(|v| test_utils::to_hex_le(v.into()))
+        .collect();
+    let witness_path = dir.join("witness.json");
+    std::fs::write(
+        &witness_path,
+        serde_json::json!({ "witness": witness }).to_string(),
+    )
+    .unwrap();
+
+    let run = || {
+        Command::new(env!("CARGO_BIN_EXE_generate-proof-from-witness"))
+            .arg("--quiet")
+            .arg("--config")
+            .arg(dir.join("orbinum.toml"))
+            .args(["--circuit", "transfer"])
+            .arg(&witness_path)
+            .arg("1")
+            .output()
+            .unwrap()
+    };
+
+    let output = run();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(result["proof"].as_str().unwrap().starts_with("0x"));
+    assert_eq!(result["public_signals"][0], witness[1]);
+
+    // The key comes from the config alone: without that file, proving fails.
+    std::fs::remove_file(dir.join("keys/transfer_pk.ark")).unwrap();
+    let output = run();
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("Failed to read proving key"),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
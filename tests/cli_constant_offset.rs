@@ -0,0 +1,57 @@
+mod common;
+
+use std::process::Command;
+
+use groth16_proofs::test_utils;
+
+/// Prove `witness` with `--constant-offset offset`, returning the emitted proof
+/// bytes and public signals.
+fn prove(dir: &std::path::Path, witness: &[u64], offset: &str) -> (Vec<u8>, Vec<String>) {
+    let witness: Vec<String> = witness
+        .iter()
+        .map(|&v| test_utils::to_hex_le(v.into()))
+        .collect();
+    let witness_path = dir.join(format!("witness_{offset}.json"));
+    std::fs::write(
+        &witness_path,
+        serde_json::json!({ "witness": witness }).to_string(),
+    )
+    .unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_generate-proof-from-witness"))
+        .args(["--quiet", "--constant-offset", offset])
+        .arg(&witness_path)
+        .arg(dir.join("pk.ark"))
+        .arg("1")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let output: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let proof = hex::decode(output["proof"].as_str().unwrap().trim_start_matches("0x")).unwrap();
+    let signals = serde_json::from_value(output["public_signals"].clone()).unwrap();
+    (proof, signals)
+}
+
+#[test]
+fn emitted_proof_verifies_against_emitted_signals_for_both_offsets() {
+    let dir = common::temp_dir("cli_constant_offset");
+    let (pk, vk) = common::setup_witness_layout(4, 1, 7);
+    std::fs::write(dir.join("pk.ark"), common::pk_bytes(&pk)).unwrap();
+
+    // The same assignment, exported with and without the constant wire.
+    for (witness, offset) in [(&[1u64, 15, 3, 5][..], "1"), (&[15, 3, 5][..], "0")] {
+        let (proof, signals) = prove(&dir, witness, offset);
+        assert_eq!(
+            signals,
+            [test_utils::to_hex_le(15u64.into())],
+            "offset {offset}"
+        );
+        assert!(
+            groth16_proofs::verify(&proof, &vk, &signals).unwrap(),
+            "offset {offset}"
+        );
+    }
+}
@@ -0,0 +1,83 @@
+mod common;
+
+use std::process::Command;
+
+use groth16_proofs::test_utils;
+
+#[test]
+fn export_bundle_writes_snarkjs_files() {
+    let dir = common::temp_dir("cli_export_bundle");
+    let (pk, vk) = test_utils::setup(1);
+    let (proof, signals) = test_utils::prove(&pk, 3, 5);
+    let proof_path = dir.join("output.json");
+    let vk_path = dir.join("vk.bin");
+    std::fs::write(
+        &proof_path,
+        serde_json::json!({
+            "proof": format!("0x{}", hex::encode(&proof)),
+            "public_signals": signals,
+        })
+        .to_string(),
+    )
+    .unwrap();
+    std::fs::write(&vk_path, common::vk_bytes(&vk)).unwrap();
+
+    let out_dir = dir.join("bundle");
+    let output = Command::new(env!("CARGO_BIN_EXE_generate-proof-from-witness"))
+        .arg("export-bundle")
+        .arg(&out_dir)
+        .arg("--proof")
+        .arg(&proof_path)
+        .arg("--vk")
+        .arg(&vk_path)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let read = |name: &str| std::fs::read_to_string(out_dir.join(name)).unwrap();
+    let (proof_json, public_json, vk_json) = (
+        read("proof.json"),
+        read("public.json"),
+        read("verification_key.json"),
+    );
+    let parsed: serde_json::Value = serde_json::from_str(&proof_json).unwrap();
+    assert_eq!(parsed["protocol"], "groth16");
+    assert_eq!(public_json.trim(), "[\n \"15\"\n]");
+    let parsed: serde_json::Value = serde_json::from_str(&vk_json).unwrap();
+    assert_eq!(parsed["nPublic"], 1);
+    assert!(groth16_proofs::verify_all_snarkjs(&proof_json, &public_json, &vk_json).unwrap());
+}
+
+#[test]
+fn export_bundle_without_vk_skips_verification_key() {
+    let dir = common::temp_dir("cli_export_bundle_no_vk");
+    let (pk, _) = test_utils::setup(1);
+    let (proof, signals) = test_utils::prove(&pk, 3, 5);
+    let proof_path = dir.join("output.json");
+    std::fs::write(
+        &proof_path,
+        serde_json::json!({
+            "proof": format!("0x{}", hex::encode(&proof)),
+            "public_signals": signals,
+        })
+        .to_string(),
+    )
+    .unwrap();
+
+    let out_dir = dir.join("bundle");
+    let status = Command::new(env!("CARGO_BIN_EXE_generate-proof-from-witness"))
+        .arg("export-bundle")
+        .arg(&out_dir)
+        .arg("--proof")
+        .arg(&proof_path)
+        .status()
+        .unwrap();
+    assert!(status.success());
+    assert!(out_dir.join("proof.json").exists());
+    assert!(out_dir.join("public.json").exists());
+    assert!(!out_dir.join("verification_key.json").exists());
+}
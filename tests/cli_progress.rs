@@ -0,0 +1,102 @@
+mod common;
+
+use std::process::Command;
+
+use groth16_proofs::test_utils;
+
+#[test]
+fn quiet_prints_only_the_result() {
+    let dir = common::temp_dir("cli_progress_quiet");
+    let (pk, _) = test_utils::setup(1);
+    let pk_path = dir.join("pk.ark");
+    std::fs::write(&pk_path, common::pk_bytes(&pk)).unwrap();
+
+    // `test_utils::TestCircuit` witness layout: [1, c, a, b] with c = a * b.
+    let witness: Vec<String> = [1u64, 15, 3, 5]
+        .into_iter()
+        .map(|v| test_utils::to_hex_le(v.into()))
+        .collect();
+    let witness_path = dir.join("witness.json");
+    std::fs::write(
+        &witness_path,
+        serde_json::json!({ "witness": witness }).to_string(),
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_generate-proof-from-witness"))
+        .arg("--quiet")
+        .arg(&witness_path)
+        .arg(&pk_path)
+        .arg("1")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert!(
+        output.stderr.is_empty(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(result["proof"].as_str().unwrap().starts_with("0x"));
+    assert_eq!(result["public_signals"][0], witness[1]);
+}
+
+/// Every stderr line as JSON, failing on anything that is not.
+fn json_lines(stderr: &[u8]) -> Vec<serde_json::Value> {
+    String::from_utf8_lossy(stderr)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap_or_else(|e| panic!("{line}: {e}")))
+        .collect()
+}
+
+#[test]
+fn subcommands_log_json() {
+    let dir = common::temp_dir("cli_progress_subcommand");
+    let witness_path = dir.join("witness.json");
+    std::fs::write(&witness_path, r#"{"witness": ["0x01", "0x02"]}"#).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_generate-proof-from-witness"))
+        .arg("diff-witness")
+        .args(["--log-format", "json"])
+        .arg(&witness_path)
+        .arg(&witness_path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let lines = json_lines(&output.stderr);
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0]["level"], "info");
+    assert_eq!(lines[0]["event"], "witnesses_identical");
+}
+
+#[test]
+fn failures_log_json() {
+    let dir = common::temp_dir("cli_progress_failure");
+    let missing = dir.join("missing.json");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_generate-proof-from-witness"))
+        .args(["--log-format", "json"])
+        .arg(&missing)
+        .arg(dir.join("pk.ark"))
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let lines = json_lines(&output.stderr);
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0]["level"], "error");
+    assert_eq!(lines[0]["event"], "witness_read_failed");
+
+    // Subcommand errors too, even with --quiet.
+    let output = Command::new(env!("CARGO_BIN_EXE_generate-proof-from-witness"))
+        .args(["verify", "--quiet", "--log-format", "json"])
+        .arg(&missing)
+        .arg(dir.join("vk.bin"))
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let lines = json_lines(&output.stderr);
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0]["level"], "error");
+    assert_eq!(lines[0]["event"], "verify_failed");
+}
@@ -0,0 +1,99 @@
+mod common;
+
+use std::process::Command;
+
+use groth16_proofs::test_utils;
+
+#[test]
+fn prove_dir_proves_every_witness_file() {
+    let dir = common::temp_dir("cli_prove_dir");
+    let (pk, vk) = common::setup_witness_layout(4, 1, 7);
+    let pk_path = dir.join("pk.ark");
+    std::fs::write(&pk_path, common::pk_bytes(&pk)).unwrap();
+
+    let witness_dir = dir.join("witnesses");
+    std::fs::create_dir_all(&witness_dir).unwrap();
+    for (name, values) in [("tx1.json", [1u64, 15, 3, 5]), ("tx2.json", [1, 42, 6, 7])] {
+        let witness: Vec<String> = values
+            .into_iter()
+            .map(|v| test_utils::to_hex_le(v.into()))
+            .collect();
+        std::fs::write(
+            witness_dir.join(name),
+            serde_json::json!({ "witness": witness }).to_string(),
+        )
+        .unwrap();
+    }
+    std::fs::write(witness_dir.join("notes.txt"), "not a witness").unwrap();
+
+    let out_dir = dir.join("proofs");
+    let output = Command::new(env!("CARGO_BIN_EXE_generate-proof-from-witness"))
+        .arg("prove-dir")
+        .arg(&witness_dir)
+        .arg(&pk_path)
+        .arg(&out_dir)
+        .output()
+        .unwrap();
+    assert_eq!(
+        output.status.code(),
+        Some(0),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let summary: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        summary,
+        serde_json::json!({ "proved": ["tx1.json", "tx2.json"], "failed": [] })
+    );
+
+    for (name, signal) in [("tx1.json", 15u64), ("tx2.json", 42)] {
+        let proof: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(out_dir.join(name)).unwrap()).unwrap();
+        let signals: Vec<String> = serde_json::from_value(proof["public_signals"].clone()).unwrap();
+        assert_eq!(signals, [test_utils::to_hex_le(signal.into())]);
+        let bytes = hex::decode(proof["proof"].as_str().unwrap().trim_start_matches("0x")).unwrap();
+        assert!(groth16_proofs::verify(&bytes, &vk, &signals).unwrap());
+    }
+
+    // A bad witness is reported without stopping the others.
+    std::fs::write(witness_dir.join("tx0.json"), r#"{"witness": ["0xZZ"]}"#).unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_generate-proof-from-witness"))
+        .arg("prove-dir")
+        .arg(&witness_dir)
+        .arg(&pk_path)
+        .arg(&out_dir)
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    let summary: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        summary["proved"],
+        serde_json::json!(["tx1.json", "tx2.json"])
+    );
+    assert_eq!(summary["failed"][0]["file"], "tx0.json");
+    assert!(summary["failed"][0]["error"]
+        .as_str()
+        .unwrap()
+        .starts_with("witness[0]:"));
+
+    // Proving into the witness directory itself, even through another path, is
+    // rejected before anything is written.
+    for out_dir in [
+        witness_dir.clone(),
+        witness_dir.join("..").join("witnesses"),
+    ] {
+        let output = Command::new(env!("CARGO_BIN_EXE_generate-proof-from-witness"))
+            .arg("prove-dir")
+            .arg(&witness_dir)
+            .arg(&pk_path)
+            .arg(&out_dir)
+            .output()
+            .unwrap();
+        assert_eq!(output.status.code(), Some(1));
+        assert!(String::from_utf8_lossy(&output.stderr).contains("overwrite the witnesses"));
+    }
+    let witness: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(witness_dir.join("tx1.json")).unwrap())
+            .unwrap();
+    assert!(witness.get("proof").is_none());
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use std::process::Command;
+
+use groth16_proofs::test_utils;
+
+#[test]
+fn witness_file_named_like_a_subcommand_needs_a_path_prefix() {
+    let dir = common::temp_dir("cli_subcommand_file");
+    let (pk, vk) = common::setup_witness_layout(4, 1, 5);
+    std::fs::write(dir.join("pk.ark"), common::pk_bytes(&pk)).unwrap();
+    let witness: Vec<String> = [1u64, 15, 3, 5]
+        .iter()
+        .map(|&v| test_utils::to_hex_le(v.into()))
+        .collect();
+    std::fs::write(
+        dir.join("info"),
+        serde_json::json!({ "witness": witness }).to_string(),
+    )
+    .unwrap();
+
+    // A bare `info` is always the subcommand, even with such a file present.
+    let output = Command::new(env!("CARGO_BIN_EXE_generate-proof-from-witness"))
+        .current_dir(&dir)
+        .arg("info")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let info: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(info["version"], env!("CARGO_PKG_VERSION"));
+
+    // `./info` names the file.
+    let output = Command::new(env!("CARGO_BIN_EXE_generate-proof-from-witness"))
+        .current_dir(&dir)
+        .args(["--quiet", "./info", "pk.ark", "1"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let output: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let signals: Vec<String> = serde_json::from_value(output["public_signals"].clone()).unwrap();
+    assert_eq!(signals, [test_utils::to_hex_le(15u64.into())]);
+    let proof = hex::decode(output["proof"].as_str().unwrap().trim_start_matches("0x")).unwrap();
+    assert!(groth16_proofs::verify(&proof, &vk, &signals).unwrap());
+}
@@ -0,0 +1,232 @@
+mod common;
+
+use std::path::Path;
+use std::process::Command;
+
+use groth16_proofs::test_utils;
+
+/// Write `proof.json` (CLI output shape) and `vk.bin`, returning their paths.
+fn write_fixture(dir: &Path, proof: &[u8], signals: &[String]) -> (String, String) {
+    let (_, vk) = test_utils::setup(1);
+    let proof_path = dir.join("proof.json");
+    let vk_path = dir.join("vk.bin");
+    let output = serde_json::json!({
+        "proof": format!("0x{}", hex::encode(proof)),
+        "public_signals": signals,
+    });
+    std::fs::write(&proof_path, output.to_string()).unwrap();
+    std::fs::write(&vk_path, common::vk_bytes(&vk)).unwrap();
+    (
+        proof_path.to_string_lossy().into_owned(),
+        vk_path.to_string_lossy().into_owned(),
+    )
+}
+
+fn verify(args: &[&str]) -> i32 {
+    Command::new(env!("CARGO_BIN_EXE_generate-proof-from-witness"))
+        .arg("verify")
+        .args(args)
+        .output()
+        .unwrap()
+        .status
+        .code()
+        .unwrap()
+}
+
+#[test]
+fn verify_valid_proof_with_expected_signals() {
+    let dir = common::temp_dir("cli_verify_valid");
+    let (pk, _) = test_utils::setup(1);
+    let (proof, signals) = test_utils::prove(&pk, 3, 5);
+    let (proof_path, vk_path) = write_fixture(&dir, &proof, &signals);
+
+    let expect = dir.join("public.json");
+    std::fs::write(&expect, r#"["15"]"#).unwrap();
+    let expect = expect.to_str().unwrap();
+
+    assert_eq!(verify(&[&proof_path, &vk_path]), 0);
+    assert_eq!(verify(&[&proof_path, &vk_path, "--expect", expect]), 0);
+}
+
+#[test]
+fn verify_invalid_proof_exit_code() {
+    let dir = common::temp_dir("cli_verify_invalid");
+    let (pk, _) = test_utils::setup(1);
+    let (proof, _) = test_utils::prove(&pk, 3, 5);
+    // Valid proof for c = 15, claimed for c = 16.
+    let wrong = vec![test_utils::to_hex_le(16u64.into())];
+    let (proof_path, vk_path) = write_fixture(&dir, &proof, &wrong);
+
+    assert_eq!(verify(&[&proof_path, &vk_path]), 2);
+}
+
+#[test]
+fn verify_signal_mismatch_exit_code() {
+    let dir = common::temp_dir("cli_verify_mismatch");
+    let (pk, _) = test_utils::setup(1);
+    let (proof, signals) = test_utils::prove(&pk, 3, 5);
+    let (proof_path, vk_path) = write_fixture(&dir, &proof, &signals);
+
+    let expect = dir.join("public.json");
+    std::fs::write(&expect, r#"["16"]"#).unwrap();
+
+    assert_eq!(
+        verify(&[&proof_path, &vk_path, "--expect", expect.to_str().unwrap()]),
+        3
+    );
+}
+
+#[test]
+fn verify_missing_files_is_usage_error() {
+    assert_eq!(
+        verify(&["/nonexistent/proof.json", "/nonexistent/vk.bin"]),
+        1
+    );
+}
+
+#[test]
+fn generate_output_pipes_into_verify() {
+    use std::io::Write;
+    use std::process::Stdio;
+
+    let dir = common::temp_dir("cli_verify_pipe");
+    let (pk, vk) = common::setup_witness_layout(4, 1, 7);
+    let pk_path = dir.join("pk.ark");
+    let vk_path = dir.join("vk.bin");
+    std::fs::write(&pk_path, common::pk_bytes(&pk)).unwrap();
+    std::fs::write(&vk_path, common::vk_bytes(&vk)).unwrap();
+
+    let witness: Vec<String> = [1u64, 15, 3, 5]
+        .into_iter()
+        .map(|v| test_utils::to_hex_le(v.into()))
+        .collect();
+    let witness_path = dir.join("witness.json");
+    std::fs::write(
+        &witness_path,
+        serde_json::json!({ "witness": witness }).to_string(),
+    )
+    .unwrap();
+
+    let generated = Command::new(env!("CARGO_BIN_EXE_generate-proof-from-witness"))
+        .arg("--quiet")
+        .arg(&witness_path)
+        .arg(&pk_path)
+        .arg("1")
+        .output()
+        .unwrap();
+    assert!(generated.status.success());
+
+    let mut verifier = Command::new(env!("CARGO_BIN_EXE_generate-proof-from-witness"))
+        .args(["verify", "--vk"])
+        .arg(&vk_path)
+        .arg("-")
+        .stdin(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    verifier
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(&generated.stdout)
+        .unwrap();
+    let verified = verifier.wait_with_output().unwrap();
+    assert_eq!(
+        verified.status.code(),
+        Some(0),
+        "{}",
+        String::from_utf8_lossy(&verified.stderr)
+    );
+
+    // A tampered signal through the same pipe is rejected.
+    let mut output: serde_json::Value = serde_json::from_slice(&generated.stdout).unwrap();
+    output["public_signals"][0] = test_utils::to_hex_le(16u64.into()).into();
+    let mut verifier = Command::new(env!("CARGO_BIN_EXE_generate-proof-from-witness"))
+        .args(["verify", "--vk"])
+        .arg(&vk_path)
+        .arg("-")
+        .stdin(Stdio::piped())
+        .spawn()
+        .unwrap();
+    verifier
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(output.to_string().as_bytes())
+        .unwrap();
+    assert_eq!(verifier.wait().unwrap().code(), Some(2));
+}
+
+/// Run `verify --request` with `request` on stdin: (exit code, stdout JSON).
+fn verify_request(request: &serde_json::Value) -> (i32, serde_json::Value) {
+    use std::io::Write;
+    use std::process::Stdio;
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_generate-proof-from-witness"))
+        .args(["verify", "--request"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(request.to_string().as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    let response = serde_json::from_slice(&output.stdout).unwrap();
+    (output.status.code().unwrap(), response)
+}
+
+#[test]
+fn verify_request_over_stdin() {
+    let dir = common::temp_dir("cli_verify_request");
+    let (pk, _) = test_utils::setup(1);
+    let (proof, signals) = test_utils::prove(&pk, 3, 5);
+    let (_, vk_path) = write_fixture(&dir, &proof, &signals);
+    let mut request = serde_json::json!({
+        "proof": format!("0x{}", hex::encode(&proof)),
+        "public_signals": signals,
+        "vk": vk_path,
+    });
+
+    let (code, response) = verify_request(&request);
+    assert_eq!(code, 0);
+    assert_eq!(response, serde_json::json!({ "valid": true }));
+
+    request["public_signals"][0] = test_utils::to_hex_le(16u64.into()).into();
+    let (code, response) = verify_request(&request);
+    assert_eq!(code, 2);
+    assert_eq!(response, serde_json::json!({ "valid": false }));
+
+    // A proof that doesn't decode is invalid; a malformed signal is an error.
+    let mut truncated = request.clone();
+    truncated["proof"] = format!("0x{}", hex::encode(&proof[1..])).into();
+    let (code, response) = verify_request(&truncated);
+    assert_eq!(code, 2);
+    assert_eq!(response, serde_json::json!({ "valid": false }));
+
+    let mut bad_signal = request.clone();
+    bad_signal["public_signals"][0] = "0xZZ".into();
+    let (code, response) = verify_request(&bad_signal);
+    assert_eq!(code, 1);
+    assert_eq!(response["valid"], false);
+    assert!(response["error"]
+        .as_str()
+        .unwrap()
+        .contains("Public signal conversion failed"));
+
+    request["vk"] = "/nonexistent/vk.bin".into();
+    let (code, response) = verify_request(&request);
+    assert_eq!(code, 1);
+    assert_eq!(response["valid"], false);
+    assert!(response["error"].as_str().is_some());
+
+    let (code, response) = verify_request(&serde_json::json!({ "proof": "0x00" }));
+    assert_eq!(code, 1);
+    assert!(response["error"]
+        .as_str()
+        .unwrap()
+        .contains("Failed to parse verify request"));
+}
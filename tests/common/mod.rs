@@ -0,0 +1,47 @@
+// Shared fixtures for CLI integration tests. The constrained `a * b = c` circuit
+// comes from `groth16_proofs::test_utils`; this adds key layouts and scratch dirs.
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+
+use ark_bn254::{Bn254, Fr as Bn254Fr};
+use ark_groth16::{Groth16, ProvingKey, VerifyingKey};
+use ark_serialize::CanonicalSerialize;
+use ark_snark::SNARK;
+use ark_std::rand::rngs::StdRng;
+use ark_std::rand::SeedableRng;
+
+/// Keys for the CLI's own prover: `WitnessCircuit` only allocates variables, so
+/// its proofs verify against keys set up from the same layout.
+pub fn setup_witness_layout(
+    witness_len: usize,
+    num_public_signals: usize,
+    seed: u64,
+) -> (ProvingKey<Bn254>, VerifyingKey<Bn254>) {
+    let layout = groth16_proofs::WitnessCircuit {
+        witness: vec![Bn254Fr::from(0u64); witness_len],
+        num_public_signals,
+    };
+    let mut rng = StdRng::seed_from_u64(seed);
+    Groth16::<Bn254>::circuit_specific_setup(layout, &mut rng).unwrap()
+}
+
+pub fn pk_bytes(pk: &ProvingKey<Bn254>) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    pk.serialize_compressed(&mut bytes).unwrap();
+    bytes
+}
+
+pub fn vk_bytes(vk: &VerifyingKey<Bn254>) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    vk.serialize_compressed(&mut bytes).unwrap();
+    bytes
+}
+
+/// Fresh, empty per-test scratch directory under the target dir.
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
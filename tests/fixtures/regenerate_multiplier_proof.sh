@@ -0,0 +1,15 @@
+#!/usr/bin/env bash
+# Regenerate multiplier_proof.json / multiplier_public.json with snarkjs, so that
+# `verify_all_snarkjs` is checked against a proof this crate did not produce.
+#
+# Requires snarkjs 0.7 (`npm install -g snarkjs`). Run from this directory.
+set -euo pipefail
+cd "$(dirname "$0")"
+
+tmp="$(mktemp -d)"
+trap 'rm -rf "$tmp"' EXIT
+
+echo '{"a": 3, "b": 11}' > "$tmp/input.json"
+snarkjs wtns calculate multiplier.wasm "$tmp/input.json" "$tmp/witness.wtns"
+snarkjs groth16 prove multiplier.zkey "$tmp/witness.wtns" multiplier_proof.json multiplier_public.json
+snarkjs groth16 verify multiplier_vk.json multiplier_public.json multiplier_proof.json
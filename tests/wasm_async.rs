@@ -0,0 +1,32 @@
+//! Browser-side check of the promise-based prover. Run with
+//! `wasm-pack test --headless --chrome -- --features wasm`.
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+mod common;
+
+use groth16_proofs::generate_proof_wasm_async;
+use groth16_proofs::test_utils;
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn test_generate_proof_wasm_async_resolves() {
+    let (pk, _) = test_utils::setup(1);
+    let promise =
+        generate_proof_wasm_async(1, r#"["1","15","3","5"]"#.into(), common::pk_bytes(&pk));
+    let output = JsFuture::from(promise).await.unwrap().as_string().unwrap();
+    let output: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert!(output["proof"].as_str().unwrap().starts_with("0x"));
+    assert_eq!(
+        output["publicSignals"][0],
+        format!("0x0f{}", "00".repeat(31))
+    );
+}
+
+#[wasm_bindgen_test]
+async fn test_generate_proof_wasm_async_rejects_bad_witness() {
+    let promise = generate_proof_wasm_async(1, "not json".into(), Vec::new());
+    assert!(JsFuture::from(promise).await.is_err());
+}
@@ -0,0 +1,33 @@
+//! `convert_proof_wasm` on wasm32. Run with
+//! `wasm-pack test --headless --chrome -- --features wasm`.
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+mod common;
+
+use groth16_proofs::convert_proof_wasm;
+use groth16_proofs::test_utils;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn proof_hex() -> String {
+    let (pk, _) = test_utils::setup(1);
+    let (proof, _) = test_utils::prove(&pk, 3, 5);
+    format!("0x{}", hex::encode(proof))
+}
+
+#[wasm_bindgen_test]
+fn test_convert_proof_to_calldata() {
+    let calldata = convert_proof_wasm(&proof_hex(), "calldata").unwrap();
+    // [a], [[b]], [c]: eight 32-byte words.
+    assert!(calldata.starts_with("[\"0x"));
+    assert_eq!(calldata.matches("0x").count(), 8);
+    for word in calldata.split('"').filter(|s| s.starts_with("0x")) {
+        assert_eq!(word.len(), 2 + 64);
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_convert_proof_rejects_unknown_target() {
+    assert!(convert_proof_wasm(&proof_hex(), "rlp").is_err());
+}
@@ -0,0 +1,65 @@
+//! The thread-local proving key registry on wasm32. Run with
+//! `wasm-pack test --headless --chrome -- --features wasm`.
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+mod common;
+
+use groth16_proofs::{
+    generate_proof_with_handle_wasm, load_proving_key_wasm, set_key_registry_capacity, test_utils,
+};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const WITNESS: &str = r#"["1","15","3","5"]"#;
+
+fn key_bytes() -> Vec<u8> {
+    common::pk_bytes(&test_utils::setup(1).0)
+}
+
+fn prove_error(handle: u32) -> String {
+    generate_proof_with_handle_wasm(handle, WITNESS)
+        .unwrap_err()
+        .as_string()
+        .unwrap()
+}
+
+#[wasm_bindgen_test]
+fn test_registry_evicts_least_recently_used() {
+    let bytes = key_bytes();
+    set_key_registry_capacity(2).unwrap();
+    let a = load_proving_key_wasm(&bytes, 1).unwrap();
+    let b = load_proving_key_wasm(&bytes, 1).unwrap();
+    generate_proof_with_handle_wasm(a, WITNESS).unwrap(); // b is now least recently used
+    let c = load_proving_key_wasm(&bytes, 1).unwrap();
+
+    assert!(generate_proof_with_handle_wasm(a, WITNESS).is_ok());
+    assert!(generate_proof_with_handle_wasm(c, WITNESS).is_ok());
+    assert!(prove_error(b).contains("evicted"));
+}
+
+/// The flow a web worker runs after instantiating the module: its registry holds
+/// only keys it loaded itself, so it loads its own key and proves by that handle.
+#[wasm_bindgen_test]
+fn test_worker_loads_its_own_key_and_proves_by_handle() {
+    let bytes = key_bytes();
+    // Re-initialise: one slot, so every key loaded by earlier tests is evicted.
+    set_key_registry_capacity(1).unwrap();
+    let stale = load_proving_key_wasm(&bytes, 1).unwrap();
+    let handle = load_proving_key_wasm(&bytes, 1).unwrap();
+    assert_ne!(handle, stale);
+    assert!(prove_error(stale).contains("evicted"));
+
+    // A handle issued by another instance is unknown here.
+    assert!(prove_error(u32::MAX).contains("Unknown proving key handle"));
+
+    let output = generate_proof_with_handle_wasm(handle, WITNESS).unwrap();
+    let output: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(output["proof"].as_str().unwrap().len(), 2 + 256);
+    assert_eq!(
+        output["publicSignals"][0],
+        format!("0x0f{}", "00".repeat(31))
+    );
+    assert!(groth16_proofs::release_proving_key_wasm(handle));
+    assert!(prove_error(handle).contains("released"));
+}